@@ -15,7 +15,24 @@ mod msig_court {
 	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
 	pub enum Proposal {
 		LLMForceTransfer(LLMForceTransferArguments),
-		SetGovernance { threshold: u32, judges: Vec<AccountId> },
+		SetGovernance {
+			threshold: u32,
+			judges: Vec<AccountId>,
+		},
+		/// SCALE-encoded `RuntimeCall`, dispatched by the court contract. Runtime
+		/// only allows calls passing its court call filter.
+		RuntimeCall(Vec<u8>),
+	}
+
+	#[derive(Debug, Clone, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct ProposalInfo {
+		pub proposer: AccountId,
+		/// Last block at which proposal can be approved
+		pub deadline: Option<BlockNumber>,
+		/// Governance version at which approvals were collected
+		pub governance_version: u32,
 	}
 
 	#[derive(Debug, PartialEq, Eq)]
@@ -40,6 +57,14 @@ mod msig_court {
 		CallFailed,
 		/// Invalid parameters
 		InvalidParameters,
+		/// Proposal deadline passed
+		Expired,
+		/// Proposal deadline didn't pass yet
+		NotExpired,
+		/// Caller didn't approve this proposal
+		NotApproved,
+		/// Caller isn't the proposer
+		NotProposer,
 	}
 
 	impl From<liberland_extension::Error> for Error {
@@ -58,6 +83,8 @@ mod msig_court {
 		judges: Vec<AccountId>,
 		proposals: Mapping<PropKey, Proposal>,
 		approvals: Mapping<PropKey, Vec<AccountId>>,
+		proposals_info: Mapping<PropKey, ProposalInfo>,
+		governance_version: u32,
 	}
 
 	#[ink(event)]
@@ -83,6 +110,25 @@ mod msig_court {
 		result: Result<()>,
 	}
 
+	#[ink(event)]
+	pub struct Revoked {
+		#[ink(topic)]
+		approver: AccountId,
+		key: PropKey,
+	}
+
+	#[ink(event)]
+	pub struct Cancelled {
+		#[ink(topic)]
+		proposer: AccountId,
+		key: PropKey,
+	}
+
+	#[ink(event)]
+	pub struct Expired {
+		key: PropKey,
+	}
+
 	impl MsigCourt {
 		fn execute(&mut self, proposal: Proposal) -> Result<()> {
 			use Proposal::*;
@@ -91,11 +137,91 @@ mod msig_court {
 					self.env().extension().llm_force_transfer(args).map_err(|e| e.into())
 				},
 				SetGovernance { threshold, judges } => self.set_governance(threshold, judges),
+				RuntimeCall(call) => {
+					self.env().extension().court_call_runtime(call).map_err(|e| e.into())
+				},
+			}
+		}
+
+		fn is_expired(&self, info: &ProposalInfo) -> bool {
+			matches!(info.deadline, Some(deadline) if deadline < self.env().block_number())
+		}
+
+		/// Approvals collected before last `SetGovernance` are no longer valid.
+		fn current_approvals(&self, key: PropKey, info: &ProposalInfo) -> Vec<AccountId> {
+			if info.governance_version != self.governance_version {
+				Vec::new()
+			} else {
+				self.approvals.get(key).unwrap_or_default()
 			}
 		}
 
+		fn remove_proposal(&mut self, key: PropKey) {
+			self.proposals.remove(key);
+			self.approvals.remove(key);
+			self.proposals_info.remove(key);
+		}
+
+		fn do_propose(
+			&mut self,
+			proposal: Proposal,
+			deadline: Option<BlockNumber>,
+		) -> Result<(PropKey, ProposalState)> {
+			let caller = self.env().caller();
+			if !self.judges.contains(&caller) {
+				return Err(Error::Unauthorized);
+			}
+
+			if matches!(deadline, Some(deadline) if deadline < self.env().block_number()) {
+				return Err(Error::InvalidParameters);
+			}
+
+			let mut key =
+				<ink::env::hash::Blake2x256 as ink::env::hash::HashOutput>::Type::default();
+			ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&proposal, &mut key);
+
+			if let Some(info) = self.proposals_info.get(key) {
+				if !self.is_expired(&info) {
+					return Err(Error::AlreadyExists);
+				}
+				self.remove_proposal(key);
+				self.env().emit_event(Expired { key });
+			} else if self.proposals.contains(key) {
+				return Err(Error::AlreadyExists);
+			}
+
+			self.proposals.insert(key, &proposal);
+			self.approvals.insert(key, &Vec::<AccountId>::new());
+			self.proposals_info.insert(
+				key,
+				&ProposalInfo {
+					proposer: caller,
+					deadline,
+					governance_version: self.governance_version,
+				},
+			);
+			self.env().emit_event(Proposed { proposer: caller, key, proposal });
+			let state = self.do_approve(caller, key)?;
+			Ok((key, state))
+		}
+
 		fn do_approve(&mut self, approver: AccountId, key: PropKey) -> Result<ProposalState> {
 			let approvals = self.approvals.take(key).ok_or(Error::NotFound)?;
+			let approvals = match self.proposals_info.get(key) {
+				Some(mut info) => {
+					if self.is_expired(&info) {
+						return Err(Error::Expired);
+					}
+					if info.governance_version != self.governance_version {
+						info.governance_version = self.governance_version;
+						self.proposals_info.insert(key, &info);
+						Vec::new()
+					} else {
+						approvals
+					}
+				},
+				None => approvals,
+			};
 			if approvals.contains(&approver) {
 				return Err(Error::AlreadyApproved);
 			}
@@ -103,6 +229,7 @@ mod msig_court {
 			if approvals.len().saturating_add(1) >= self.threshold as usize {
 				let proposal =
 					self.proposals.take(key).expect("Approvals exist, so proposal must exist too");
+				self.proposals_info.remove(key);
 				let result = self.execute(proposal);
 				self.env().emit_event(Executed { approver, key, result: result.clone() });
 				Ok(ProposalState::Executed(result))
@@ -122,6 +249,7 @@ mod msig_court {
 
 			self.threshold = threshold;
 			self.judges = judges;
+			self.governance_version = self.governance_version.wrapping_add(1);
 			Ok(())
 		}
 	}
@@ -135,33 +263,65 @@ mod msig_court {
 
 		#[ink(message)]
 		pub fn propose(&mut self, proposal: Proposal) -> Result<(PropKey, ProposalState)> {
+			self.do_propose(proposal, None)
+		}
+
+		/// Same as `propose`, but proposal can't be approved after `deadline`
+		/// block.
+		#[ink(message)]
+		pub fn propose_with_deadline(
+			&mut self,
+			proposal: Proposal,
+			deadline: BlockNumber,
+		) -> Result<(PropKey, ProposalState)> {
+			self.do_propose(proposal, Some(deadline))
+		}
+
+		#[ink(message)]
+		pub fn approve(&mut self, key: PropKey) -> Result<ProposalState> {
 			let caller = self.env().caller();
 			if !self.judges.contains(&caller) {
 				return Err(Error::Unauthorized);
 			}
+			self.do_approve(caller, key)
+		}
 
-			let mut key =
-				<ink::env::hash::Blake2x256 as ink::env::hash::HashOutput>::Type::default();
-			ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&proposal, &mut key);
+		/// Withdraw caller's approval of a pending proposal.
+		#[ink(message)]
+		pub fn revoke(&mut self, key: PropKey) -> Result<()> {
+			let caller = self.env().caller();
+			let info = self.proposals_info.get(key).ok_or(Error::NotFound)?;
+			let mut approvals = self.current_approvals(key, &info);
+			let index = approvals.iter().position(|a| a == &caller).ok_or(Error::NotApproved)?;
+			approvals.swap_remove(index);
+			self.approvals.insert(key, &approvals);
+			self.env().emit_event(Revoked { approver: caller, key });
+			Ok(())
+		}
 
-			if self.proposals.contains(key) {
-				return Err(Error::AlreadyExists);
+		/// Remove a pending proposal. Only the proposer can cancel it.
+		#[ink(message)]
+		pub fn cancel(&mut self, key: PropKey) -> Result<()> {
+			let caller = self.env().caller();
+			let info = self.proposals_info.get(key).ok_or(Error::NotFound)?;
+			if info.proposer != caller {
+				return Err(Error::NotProposer);
 			}
-
-			self.proposals.insert(key, &proposal);
-			self.approvals.insert(key, &Vec::<AccountId>::new());
-			self.env().emit_event(Proposed { proposer: caller, key, proposal });
-			let state = self.do_approve(caller, key)?;
-			Ok((key, state))
+			self.remove_proposal(key);
+			self.env().emit_event(Cancelled { proposer: caller, key });
+			Ok(())
 		}
 
+		/// Remove proposal which deadline passed. Can be called by anyone.
 		#[ink(message)]
-		pub fn approve(&mut self, key: PropKey) -> Result<ProposalState> {
-			let caller = self.env().caller();
-			if !self.judges.contains(&caller) {
-				return Err(Error::Unauthorized);
+		pub fn remove_expired(&mut self, key: PropKey) -> Result<()> {
+			let info = self.proposals_info.get(key).ok_or(Error::NotFound)?;
+			if !self.is_expired(&info) {
+				return Err(Error::NotExpired);
 			}
-			self.do_approve(caller, key)
+			self.remove_proposal(key);
+			self.env().emit_event(Expired { key });
+			Ok(())
 		}
 
 		#[ink(message)]
@@ -176,7 +336,17 @@ mod msig_court {
 
 		#[ink(message)]
 		pub fn get_proposal(&self, key: PropKey) -> Option<(Proposal, Vec<AccountId>)> {
-			Some((self.proposals.get(key)?, self.approvals.get(key)?))
+			let proposal = self.proposals.get(key)?;
+			let approvals = match self.proposals_info.get(key) {
+				Some(info) => self.current_approvals(key, &info),
+				None => self.approvals.get(key)?,
+			};
+			Some((proposal, approvals))
+		}
+
+		#[ink(message)]
+		pub fn get_proposal_info(&self, key: PropKey) -> Option<ProposalInfo> {
+			self.proposals_info.get(key)
 		}
 	}
 
@@ -508,6 +678,151 @@ mod msig_court {
 			let key = <ink::env::hash::Blake2x256 as ink::env::hash::HashOutput>::Type::default();
 			assert_eq!(msig_court.get_proposal(key), None);
 		}
+
+		#[ink::test]
+		fn cant_approve_after_deadline() {
+			let mut msig_court = MsigCourt::new(2, vec![alice(), bob()]);
+			set_next_caller(alice());
+			let (key, _) = msig_court
+				.propose_with_deadline(
+					Proposal::SetGovernance { threshold: 1, judges: vec![alice()] },
+					1,
+				)
+				.expect("propose shouldnt fail");
+
+			ink::env::test::advance_block::<Environment>();
+			ink::env::test::advance_block::<Environment>();
+			set_next_caller(bob());
+			assert_eq!(msig_court.approve(key), Err(Error::Expired));
+		}
+
+		#[ink::test]
+		fn cant_propose_with_past_deadline() {
+			let mut msig_court = MsigCourt::new(2, vec![alice(), bob()]);
+			ink::env::test::advance_block::<Environment>();
+			set_next_caller(alice());
+			let res = msig_court.propose_with_deadline(
+				Proposal::SetGovernance { threshold: 1, judges: vec![alice()] },
+				0,
+			);
+			assert_eq!(res, Err(Error::InvalidParameters));
+		}
+
+		#[ink::test]
+		fn remove_expired_works() {
+			let mut msig_court = MsigCourt::new(2, vec![alice(), bob()]);
+			set_next_caller(alice());
+			let proposal = Proposal::SetGovernance { threshold: 1, judges: vec![alice()] };
+			let (key, _) = msig_court
+				.propose_with_deadline(proposal.clone(), 1)
+				.expect("propose shouldnt fail");
+
+			set_next_caller(charlie());
+			assert_eq!(msig_court.remove_expired(key), Err(Error::NotExpired));
+
+			ink::env::test::advance_block::<Environment>();
+			ink::env::test::advance_block::<Environment>();
+			assert_eq!(msig_court.remove_expired(key), Ok(()));
+			assert_eq!(msig_court.get_proposal(key), None);
+			assert_eq!(msig_court.get_proposal_info(key), None);
+		}
+
+		#[ink::test]
+		fn expired_proposal_can_be_proposed_again() {
+			let mut msig_court = MsigCourt::new(2, vec![alice(), bob()]);
+			set_next_caller(alice());
+			let proposal = Proposal::SetGovernance { threshold: 1, judges: vec![alice()] };
+			msig_court
+				.propose_with_deadline(proposal.clone(), 1)
+				.expect("propose shouldnt fail");
+
+			ink::env::test::advance_block::<Environment>();
+			ink::env::test::advance_block::<Environment>();
+			let (key, state) = msig_court.propose(proposal.clone()).expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::PendingApprovals);
+			assert_eq!(msig_court.get_proposal_info(key).unwrap().deadline, None);
+		}
+
+		#[ink::test]
+		fn revoke_works() {
+			let mut msig_court = MsigCourt::new(3, vec![alice(), bob(), charlie()]);
+			set_next_caller(alice());
+			let proposal = Proposal::SetGovernance { threshold: 1, judges: vec![alice()] };
+			let (key, _) = msig_court.propose(proposal.clone()).expect("propose shouldnt fail");
+
+			set_next_caller(bob());
+			assert_eq!(msig_court.revoke(key), Err(Error::NotApproved));
+			msig_court.approve(key).expect("approve shouldnt fail");
+			assert_eq!(msig_court.revoke(key), Ok(()));
+			assert_eq!(msig_court.get_proposal(key), Some((proposal, vec![alice()])));
+
+			set_next_caller(charlie());
+			assert_eq!(msig_court.approve(key), Ok(ProposalState::PendingApprovals));
+		}
+
+		#[ink::test]
+		fn only_proposer_can_cancel() {
+			let mut msig_court = MsigCourt::new(2, vec![alice(), bob()]);
+			set_next_caller(alice());
+			let (key, _) = msig_court
+				.propose(Proposal::SetGovernance { threshold: 1, judges: vec![alice()] })
+				.expect("propose shouldnt fail");
+
+			set_next_caller(bob());
+			assert_eq!(msig_court.cancel(key), Err(Error::NotProposer));
+
+			set_next_caller(alice());
+			assert_eq!(msig_court.cancel(key), Ok(()));
+			assert_eq!(msig_court.get_proposal(key), None);
+
+			set_next_caller(bob());
+			assert_eq!(msig_court.approve(key), Err(Error::NotFound));
+		}
+
+		#[ink::test]
+		fn set_governance_invalidates_approvals() {
+			let mut msig_court = MsigCourt::new(2, vec![alice(), bob(), charlie()]);
+			set_next_caller(alice());
+			let stale = Proposal::SetGovernance { threshold: 1, judges: vec![alice()] };
+			let (stale_key, _) = msig_court.propose(stale.clone()).expect("propose shouldnt fail");
+
+			let (key, _) = msig_court
+				.propose(Proposal::SetGovernance {
+					threshold: 2,
+					judges: vec![alice(), bob(), charlie()],
+				})
+				.expect("propose shouldnt fail");
+			set_next_caller(bob());
+			assert_eq!(msig_court.approve(key), Ok(ProposalState::Executed(Ok(()))));
+
+			assert_eq!(msig_court.get_proposal(stale_key), Some((stale, vec![])));
+			set_next_caller(charlie());
+			assert_eq!(msig_court.approve(stale_key), Ok(ProposalState::PendingApprovals));
+		}
+
+		#[ink::test]
+		fn runtime_call_works() {
+			ink::env::test::register_chain_extension(MockedLiberlandExtensionSuccess);
+
+			let mut msig_court = MsigCourt::new(1, vec![alice()]);
+			set_next_caller(alice());
+			let (_, state) = msig_court
+				.propose(Proposal::RuntimeCall(vec![1, 2, 3]))
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::Executed(Ok(())));
+		}
+
+		#[ink::test]
+		fn runtime_call_propagates_errors() {
+			ink::env::test::register_chain_extension(MockedLiberlandExtensionFail);
+
+			let mut msig_court = MsigCourt::new(1, vec![alice()]);
+			set_next_caller(alice());
+			let (_, state) = msig_court
+				.propose(Proposal::RuntimeCall(vec![1, 2, 3]))
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::Executed(Err(Error::CallFailed)));
+		}
 	}
 
 	#[cfg(all(test, feature = "e2e-tests"))]
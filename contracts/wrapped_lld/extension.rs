@@ -0,0 +1,59 @@
+use ink::env::{DefaultEnvironment, Environment};
+use ink::prelude::vec::Vec;
+
+/// Signature of a 32-byte public key. Encoded the same way as runtime's
+/// `MultiSignature`.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Signature {
+	Ed25519([u8; 64]),
+	Sr25519([u8; 64]),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct VerifySignatureArguments {
+	pub signature: Signature,
+	pub message: Vec<u8>,
+	pub signer: [u8; 32],
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ExtensionError {
+	Failed,
+}
+
+impl ink::env::chain_extension::FromStatusCode for ExtensionError {
+	fn from_status_code(status_code: u32) -> Result<(), Self> {
+		match status_code {
+			0 => Ok(()),
+			_ => Err(Self::Failed),
+		}
+	}
+}
+
+/// Subset of the Liberland chain extension used by wLLD
+#[ink::chain_extension]
+pub trait Liberland {
+	type ErrorCode = ExtensionError;
+
+	#[ink(extension = 20)]
+	fn verify_signature(args: VerifySignatureArguments) -> bool;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum WlldEnvironment {}
+
+impl Environment for WlldEnvironment {
+	const MAX_EVENT_TOPICS: usize = <DefaultEnvironment as Environment>::MAX_EVENT_TOPICS;
+
+	type AccountId = <DefaultEnvironment as Environment>::AccountId;
+	type Balance = <DefaultEnvironment as Environment>::Balance;
+	type Hash = <DefaultEnvironment as Environment>::Hash;
+	type BlockNumber = <DefaultEnvironment as Environment>::BlockNumber;
+	type Timestamp = <DefaultEnvironment as Environment>::Timestamp;
+
+	type ChainExtension = Liberland;
+}
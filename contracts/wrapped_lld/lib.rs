@@ -1,16 +1,20 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
+mod extension;
 mod traits;
 
 // Re-export of PSP22 stuff for convenience of cross-contract calls
 pub use psp22::{PSP22Error, PSP22};
 
-pub use traits::{WrappedLLD, BASTIAT, MAINNET};
+pub use extension::{Signature, WlldEnvironment};
+pub use traits::{PSP22Permit, WrappedLLD, BASTIAT, MAINNET};
 
-#[ink::contract]
+#[ink::contract(env = crate::WlldEnvironment)]
 mod wlld {
-	use crate::WrappedLLD;
+	use crate::extension::VerifySignatureArguments;
+	use crate::{PSP22Permit, Signature, WrappedLLD};
 	use ink::prelude::{string::String, vec::Vec};
+	use ink::storage::Mapping;
 	use psp22::{PSP22Data, PSP22Error, PSP22Event, PSP22Metadata, PSP22};
 
 	#[ink(event)]
@@ -35,6 +39,7 @@ mod wlld {
 	#[derive(Default)]
 	pub struct Wlld {
 		data: PSP22Data,
+		nonces: Mapping<AccountId, u64>,
 	}
 
 	impl Wlld {
@@ -60,23 +65,81 @@ mod wlld {
 	impl WrappedLLD for Wlld {
 		#[ink(message, payable)]
 		fn deposit(&mut self) -> Result<(), PSP22Error> {
-			let events = self.data.mint(self.env().caller(), self.env().transferred_value())?;
+			self.deposit_for(self.env().caller())
+		}
+
+		#[ink(message)]
+		fn withdraw(&mut self, value: u128) -> Result<(), PSP22Error> {
+			self.withdraw_to(self.env().caller(), value)
+		}
+
+		#[ink(message, payable)]
+		fn deposit_for(&mut self, to: AccountId) -> Result<(), PSP22Error> {
+			let events = self.data.mint(to, self.env().transferred_value())?;
 			self.emit_events(events);
 			Ok(())
 		}
 
 		#[ink(message)]
-		fn withdraw(&mut self, value: u128) -> Result<(), PSP22Error> {
-			let caller = self.env().caller();
-			let events = self.data.burn(caller, value)?;
+		fn withdraw_to(&mut self, to: AccountId, value: u128) -> Result<(), PSP22Error> {
+			let events = self.data.burn(self.env().caller(), value)?;
 			self.env()
-				.transfer(caller, value)
+				.transfer(to, value)
 				.map_err(|_| PSP22Error::Custom(String::from("Wrapped LLD: withdraw failed")))?;
 			self.emit_events(events);
 			Ok(())
 		}
 	}
 
+	impl PSP22Permit for Wlld {
+		#[ink(message)]
+		fn permit(
+			&mut self,
+			owner: AccountId,
+			spender: AccountId,
+			value: u128,
+			deadline: u64,
+			signature: Signature,
+		) -> Result<(), PSP22Error> {
+			if self.env().block_timestamp() > deadline {
+				return Err(PSP22Error::Custom(String::from("Wrapped LLD: permit expired")));
+			}
+
+			let nonce = self.nonces(owner);
+			let message = scale::Encode::encode(&(
+				b"wLLD/permit",
+				self.env().account_id(),
+				owner,
+				spender,
+				value,
+				nonce,
+				deadline,
+			));
+			let valid = self
+				.env()
+				.extension()
+				.verify_signature(VerifySignatureArguments {
+					signature,
+					message,
+					signer: *owner.as_ref(),
+				})
+				.unwrap_or(false);
+			if !valid {
+				return Err(PSP22Error::Custom(String::from("Wrapped LLD: invalid signature")));
+			}
+
+			self.nonces.insert(owner, &nonce.saturating_add(1));
+			let events = self.data.approve(owner, spender, value)?;
+			self.emit_events(events);
+			Ok(())
+		}
+
+		#[ink(message)]
+		fn nonces(&self, owner: AccountId) -> u64 {
+			self.nonces.get(owner).unwrap_or(0)
+		}
+	}
+
 	impl PSP22Metadata for Wlld {
 		#[ink(message)]
 		fn token_name(&self) -> Option<String> {
@@ -354,8 +417,131 @@ mod wlld {
 			assert_transfer(&events[3], Some(bob), None, b);
 		}
 
+		#[ink::test]
+		fn deposit_for_works() {
+			let mut contract = Wlld::new();
+			let amount = 100;
+			let acc = default_accounts::<E>();
+			set_caller::<E>(acc.alice);
+			set_value_transferred::<E>(amount);
+
+			assert!(contract.deposit_for(acc.bob).is_ok());
+
+			assert_eq!(contract.total_supply(), amount);
+			assert_eq!(contract.balance_of(acc.alice), 0);
+			assert_eq!(contract.balance_of(acc.bob), amount);
+
+			let events = decode_events();
+			assert_eq!(events.len(), 1);
+			assert_transfer(&events[0], None, Some(acc.bob), amount);
+		}
+
+		#[ink::test]
+		fn withdraw_to_works() {
+			let (supply, amount) = (1000, 100);
+			let acc = default_accounts::<E>();
+			set_caller::<E>(acc.alice);
+			let mut contract = init_psp22_supply(supply);
+
+			let old_alice = get_account_balance::<E>(acc.alice).unwrap();
+			let old_bob = get_account_balance::<E>(acc.bob).unwrap();
+			assert!(contract.withdraw_to(acc.bob, amount).is_ok());
+			let new_alice = get_account_balance::<E>(acc.alice).unwrap();
+			let new_bob = get_account_balance::<E>(acc.bob).unwrap();
+
+			assert_eq!(contract.total_supply(), supply - amount);
+			assert_eq!(contract.balance_of(acc.alice), supply - amount);
+			assert_eq!(new_alice, old_alice);
+			assert_eq!(new_bob - old_bob, amount);
+
+			let events = decode_events();
+			assert_eq!(events.len(), 2);
+			assert_transfer(&events[1], Some(acc.alice), None, amount);
+		}
+
+		#[ink::test]
+		fn withdraw_to_too_much_fails() {
+			let amount = 100;
+			let acc = default_accounts::<E>();
+			set_caller::<E>(acc.alice);
+			let mut contract = init_psp22_supply(amount);
+			assert_eq!(
+				contract.withdraw_to(acc.bob, amount + 1),
+				Err(PSP22Error::InsufficientBalance)
+			);
+		}
+
+		#[ink::test]
+		fn permit_works() {
+			let acc = default_accounts::<E>();
+			register_chain_extension(MockedVerifier { valid: true, signer: acc.alice });
+			let mut contract = Wlld::new();
+			set_caller::<E>(acc.charlie);
+
+			assert_eq!(contract.nonces(acc.alice), 0);
+			assert!(contract.permit(acc.alice, acc.bob, 100, 10, signature()).is_ok());
+			assert_eq!(contract.allowance(acc.alice, acc.bob), 100);
+			assert_eq!(contract.nonces(acc.alice), 1);
+
+			let events = decode_events();
+			assert_eq!(events.len(), 1);
+			assert!(matches!(
+				events[0],
+				Event::Approval(Approval { owner, spender, amount: 100 })
+					if owner == acc.alice && spender == acc.bob
+			));
+		}
+
+		#[ink::test]
+		fn permit_fails_with_invalid_signature() {
+			let acc = default_accounts::<E>();
+			register_chain_extension(MockedVerifier { valid: false, signer: acc.alice });
+			let mut contract = Wlld::new();
+
+			assert_eq!(
+				contract.permit(acc.alice, acc.bob, 100, 10, signature()),
+				Err(PSP22Error::Custom(String::from("Wrapped LLD: invalid signature")))
+			);
+		}
+
+		#[ink::test]
+		fn permit_fails_after_deadline() {
+			let acc = default_accounts::<E>();
+			register_chain_extension(MockedVerifier { valid: true, signer: acc.alice });
+			let mut contract = Wlld::new();
+			set_block_timestamp::<E>(11);
+
+			assert_eq!(
+				contract.permit(acc.alice, acc.bob, 100, 10, signature()),
+				Err(PSP22Error::Custom(String::from("Wrapped LLD: permit expired")))
+			);
+		}
+
 		// Unit tests helpers
 
+		struct MockedVerifier {
+			valid: bool,
+			signer: AccountId,
+		}
+
+		impl ChainExtension for MockedVerifier {
+			fn func_id(&self) -> u32 {
+				20
+			}
+
+			fn call(&mut self, input: &[u8], output: &mut Vec<u8>) -> u32 {
+				let args = <VerifySignatureArguments as scale::Decode>::decode(&mut &input[..])
+					.expect("invalid verify_signature arguments");
+				assert_eq!(&args.signer, self.signer.as_ref());
+				scale::Encode::encode_to(&self.valid, output);
+				0
+			}
+		}
+
+		fn signature() -> Signature {
+			Signature::Sr25519([1u8; 64])
+		}
+
 		type Event = <Wlld as ink::reflect::ContractEventBase>::Type;
 
 		// Creates a new contract with given total supply
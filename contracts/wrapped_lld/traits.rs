@@ -1,3 +1,5 @@
+use crate::extension::Signature;
+use ink::primitives::AccountId;
 use psp22::PSP22Error;
 
 #[ink::trait_definition]
@@ -30,6 +32,66 @@ pub trait WrappedLLD {
 	/// Reverts with `InsufficientBalance` if the `value` exceeds the caller's wLLD balance.
 	#[ink(message)]
 	fn withdraw(&mut self, value: u128) -> Result<(), PSP22Error>;
+
+	/// Deposits the transferred amount of LLD and mints that much wLLD to the `to` account.
+	///
+	/// # Events
+	///
+	/// On success a `Transfer` event is emitted for newly minted wLLD (with `from` being `None`).
+	///
+	/// No-op if the transferred value is zero, returns success and no events are emitted.
+	///
+	/// # Errors
+	///
+	/// Reverts with `Custom` error variant if minting new tokens would cause the total token supply
+	/// to exceed maximal `u128` value.
+	#[ink(message, payable)]
+	fn deposit_for(&mut self, to: AccountId) -> Result<(), PSP22Error>;
+
+	/// Burns `value` wLLD tokens from the callers account and transfers that much LLD to `to`.
+	///
+	/// # Events
+	///
+	/// On success a `Transfer` event is emitted for burned wLLD (with `to` being `None`).
+	///
+	/// No-op if the `value` is zero, returns success and no events are emitted.
+	///
+	/// # Errors
+	///
+	/// Reverts with `InsufficientBalance` if the `value` exceeds the caller's wLLD balance.
+	#[ink(message)]
+	fn withdraw_to(&mut self, to: AccountId, value: u128) -> Result<(), PSP22Error>;
+}
+
+#[ink::trait_definition]
+pub trait PSP22Permit {
+	/// Sets the allowance of `spender` over `owner`'s tokens to `value`, authorized by `owner`'s
+	/// sr25519 or ed25519 signature instead of a transaction.
+	///
+	/// Signed message is the SCALE encoding of `(b"wLLD/permit", contract, owner, spender, value,
+	/// nonce, deadline)`, where `contract` is the address of this contract, `nonce` is the current
+	/// value returned by `nonces(owner)` and `deadline` is a block timestamp in milliseconds.
+	///
+	/// # Events
+	///
+	/// On success an `Approval` event is emitted.
+	///
+	/// # Errors
+	///
+	/// Reverts with `Custom` error variant if `deadline` passed or the signature is invalid.
+	#[ink(message)]
+	fn permit(
+		&mut self,
+		owner: AccountId,
+		spender: AccountId,
+		value: u128,
+		deadline: u64,
+		signature: Signature,
+	) -> Result<(), PSP22Error>;
+
+	/// Returns the nonce that must be used in the next `permit` signed by `owner`.
+	#[ink(message)]
+	fn nonces(&self, owner: AccountId) -> u64;
 }
 
 /// Mainnet deployment address
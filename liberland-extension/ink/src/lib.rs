@@ -1,8 +1,11 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
 use ink::env::Environment;
+use ink::prelude::vec::Vec;
+mod psp;
 mod types;
 
+pub use psp::*;
 pub use types::*;
 
 #[ink::chain_extension(extension = 0)]
@@ -11,6 +14,68 @@ pub trait Liberland {
 
 	#[ink(function = 1)]
 	fn llm_force_transfer(args: LLMForceTransferArguments);
+
+	#[ink(function = 2)]
+	fn contracts_registry_create_contract(
+		args: ContractsRegistryCreateContractArguments,
+	) -> ContractIndex;
+
+	#[ink(function = 3)]
+	fn contracts_registry_party_sign_contract(contract_id: ContractIndex);
+
+	#[ink(function = 4)]
+	fn contracts_registry_is_fully_signed(contract_id: ContractIndex) -> bool;
+
+	#[ink(function = 5)]
+	fn is_citizen(account: AccountId) -> bool;
+
+	#[ink(function = 6)]
+	fn ensure_stocks_allowed(account: AccountId);
+
+	#[ink(function = 7)]
+	fn get_llm_politics(account: AccountId) -> Balance;
+
+	#[ink(function = 8)]
+	fn citizens_count() -> u64;
+
+	#[ink(function = 9)]
+	fn llm_balance(account: AccountId) -> Balance;
+
+	#[ink(function = 10)]
+	fn assets_transfer(args: AssetsTransferArguments);
+
+	#[ink(function = 11)]
+	fn assets_approve_transfer(args: AssetsApproveTransferArguments);
+
+	#[ink(function = 12)]
+	fn assets_transfer_approved(args: AssetsTransferApprovedArguments);
+
+	#[ink(function = 13)]
+	fn assets_balance(args: AssetsBalanceArguments) -> Balance;
+
+	#[ink(function = 14)]
+	fn assets_total_supply(id: AssetId) -> Balance;
+
+	#[ink(function = 15)]
+	fn assets_allowance(args: AssetsAllowanceArguments) -> Balance;
+
+	#[ink(function = 16)]
+	fn assets_metadata(id: AssetId) -> AssetMetadata;
+
+	#[ink(function = 17)]
+	fn nfts_owner(args: NftsItemArguments) -> Option<AccountId>;
+
+	#[ink(function = 18)]
+	fn nfts_transfer(args: NftsTransferArguments);
+
+	#[ink(function = 19)]
+	fn court_call_runtime(call: Vec<u8>);
+
+	#[ink(function = 20)]
+	fn verify_signature(args: VerifySignatureArguments) -> bool;
+
+	#[ink(function = 21)]
+	fn assets_cancel_approval(args: AssetsCancelApprovalArguments);
 }
 
 impl ink::env::chain_extension::FromStatusCode for Error {
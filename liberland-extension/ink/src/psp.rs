@@ -0,0 +1,289 @@
+use crate::*;
+use ink::prelude::string::String;
+
+type Extension = <Liberland as ink::ChainExtensionInstance>::Instance;
+
+fn extension() -> Extension {
+	<Liberland as ink::ChainExtensionInstance>::instantiate()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum PSP22Error {
+	Custom(String),
+	InsufficientBalance,
+	InsufficientAllowance,
+	ZeroRecipientAddress,
+	ZeroSenderAddress,
+	SafeTransferCheckFailed(String),
+}
+
+impl From<Error> for PSP22Error {
+	fn from(_: Error) -> Self {
+		Self::Custom(String::from("Chain extension call failed"))
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum PSP34Error {
+	Custom(String),
+	SelfApprove,
+	NotApproved,
+	TokenExists,
+	TokenNotExists,
+	SafeTransferCheckFailed(String),
+}
+
+impl From<Error> for PSP34Error {
+	fn from(_: Error) -> Self {
+		Self::Custom(String::from("Chain extension call failed"))
+	}
+}
+
+/// PSP22-compatible view of a `pallet_assets` asset.
+///
+/// All calls are made on behalf of the calling contract, so `transfer` and
+/// `approve` move contract's funds and `transfer_from` requires an approval for
+/// the contract. Eresidency requirements of the asset are enforced by the
+/// runtime.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct PSP22Asset {
+	pub id: AssetId,
+}
+
+impl PSP22Asset {
+	pub fn new(id: AssetId) -> Self {
+		Self { id }
+	}
+
+	pub fn total_supply(&self) -> Result<Balance, PSP22Error> {
+		Ok(extension().assets_total_supply(self.id)?)
+	}
+
+	pub fn balance_of(&self, owner: AccountId) -> Result<Balance, PSP22Error> {
+		Ok(extension().assets_balance(AssetsBalanceArguments { id: self.id, who: owner })?)
+	}
+
+	pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Result<Balance, PSP22Error> {
+		Ok(extension().assets_allowance(AssetsAllowanceArguments {
+			id: self.id,
+			owner,
+			delegate: spender,
+		})?)
+	}
+
+	pub fn transfer(&self, to: AccountId, value: Balance) -> Result<(), PSP22Error> {
+		let from = ink::env::account_id::<LiberlandEnvironment>();
+		if self.balance_of(from)? < value {
+			return Err(PSP22Error::InsufficientBalance);
+		}
+		Ok(extension().assets_transfer(AssetsTransferArguments {
+			id: self.id,
+			target: to,
+			amount: value,
+		})?)
+	}
+
+	pub fn transfer_from(
+		&self,
+		from: AccountId,
+		to: AccountId,
+		value: Balance,
+	) -> Result<(), PSP22Error> {
+		let spender = ink::env::account_id::<LiberlandEnvironment>();
+		if self.allowance(from, spender)? < value {
+			return Err(PSP22Error::InsufficientAllowance);
+		}
+		if self.balance_of(from)? < value {
+			return Err(PSP22Error::InsufficientBalance);
+		}
+		Ok(extension().assets_transfer_approved(AssetsTransferApprovedArguments {
+			id: self.id,
+			owner: from,
+			destination: to,
+			amount: value,
+		})?)
+	}
+
+	/// Sets allowance of `spender` to `value`, replacing the current one.
+	pub fn approve(&self, spender: AccountId, value: Balance) -> Result<(), PSP22Error> {
+		let owner = ink::env::account_id::<LiberlandEnvironment>();
+		if self.allowance(owner, spender)? > 0 {
+			extension().assets_cancel_approval(AssetsCancelApprovalArguments {
+				id: self.id,
+				delegate: spender,
+			})?;
+		}
+		if value > 0 {
+			self.increase_allowance(spender, value)?;
+		}
+		Ok(())
+	}
+
+	/// `pallet_assets` approvals are additive, so this maps directly to
+	/// `approve_transfer`.
+	pub fn increase_allowance(
+		&self,
+		spender: AccountId,
+		delta_value: Balance,
+	) -> Result<(), PSP22Error> {
+		Ok(extension().assets_approve_transfer(AssetsApproveTransferArguments {
+			id: self.id,
+			delegate: spender,
+			amount: delta_value,
+		})?)
+	}
+
+	pub fn decrease_allowance(
+		&self,
+		spender: AccountId,
+		delta_value: Balance,
+	) -> Result<(), PSP22Error> {
+		let owner = ink::env::account_id::<LiberlandEnvironment>();
+		let allowance = self.allowance(owner, spender)?;
+		if allowance < delta_value {
+			return Err(PSP22Error::InsufficientAllowance);
+		}
+		self.approve(spender, allowance - delta_value)
+	}
+
+	pub fn token_name(&self) -> Result<Option<String>, PSP22Error> {
+		let metadata = extension().assets_metadata(self.id)?;
+		Ok(String::from_utf8(metadata.name).ok().filter(|n| !n.is_empty()))
+	}
+
+	pub fn token_symbol(&self) -> Result<Option<String>, PSP22Error> {
+		let metadata = extension().assets_metadata(self.id)?;
+		Ok(String::from_utf8(metadata.symbol).ok().filter(|s| !s.is_empty()))
+	}
+
+	pub fn token_decimals(&self) -> Result<u8, PSP22Error> {
+		Ok(extension().assets_metadata(self.id)?.decimals)
+	}
+}
+
+/// PSP34-compatible view of a `pallet_nfts` collection.
+///
+/// Transfers are made on behalf of the calling contract. Citizenship
+/// requirements of the collection are enforced by the runtime.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct PSP34Collection {
+	pub id: CollectionId,
+}
+
+impl PSP34Collection {
+	pub fn new(id: CollectionId) -> Self {
+		Self { id }
+	}
+
+	pub fn collection_id(&self) -> CollectionId {
+		self.id
+	}
+
+	pub fn owner_of(&self, item: ItemId) -> Result<Option<AccountId>, PSP34Error> {
+		Ok(extension().nfts_owner(NftsItemArguments { collection: self.id, item })?)
+	}
+
+	pub fn transfer(&self, to: AccountId, item: ItemId) -> Result<(), PSP34Error> {
+		let owner = self.owner_of(item)?.ok_or(PSP34Error::TokenNotExists)?;
+		if owner != ink::env::account_id::<LiberlandEnvironment>() {
+			return Err(PSP34Error::NotApproved);
+		}
+		Ok(extension().nfts_transfer(NftsTransferArguments {
+			collection: self.id,
+			item,
+			dest: to,
+		})?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use ink::{
+		env::{test, DefaultEnvironment},
+		scale::{Decode, Encode},
+	};
+	use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+	/// Allowances granted by the contract, keyed by delegate.
+	type Allowances = Rc<RefCell<BTreeMap<AccountId, Balance>>>;
+
+	/// Mimics additive `pallet_assets` approvals of the runtime extension.
+	struct MockAssets(Allowances);
+
+	impl test::ChainExtension for MockAssets {
+		fn ext_id(&self) -> u16 {
+			0
+		}
+
+		fn call(&mut self, func_id: u16, mut input: &[u8], output: &mut Vec<u8>) -> u32 {
+			let mut allowances = self.0.borrow_mut();
+			match func_id {
+				11 => {
+					let args = AssetsApproveTransferArguments::decode(&mut input).unwrap();
+					*allowances.entry(args.delegate).or_default() += args.amount;
+				},
+				15 => {
+					let args = AssetsAllowanceArguments::decode(&mut input).unwrap();
+					allowances.get(&args.delegate).copied().unwrap_or(0).encode_to(output);
+				},
+				21 => {
+					let args = AssetsCancelApprovalArguments::decode(&mut input).unwrap();
+					if allowances.remove(&args.delegate).is_none() {
+						return 1;
+					}
+				},
+				_ => return 1,
+			}
+			0
+		}
+	}
+
+	fn setup() -> (PSP22Asset, AccountId, AccountId) {
+		let accounts = test::default_accounts::<DefaultEnvironment>();
+		test::set_callee::<DefaultEnvironment>(accounts.alice);
+		(PSP22Asset::new(1), accounts.alice, accounts.bob)
+	}
+
+	#[ink::test]
+	fn approve_sets_allowance() {
+		let allowances = Allowances::default();
+		test::register_chain_extension(MockAssets(allowances.clone()));
+		let (asset, owner, spender) = setup();
+
+		assert_eq!(asset.approve(spender, 10), Ok(()));
+		assert_eq!(asset.allowance(owner, spender), Ok(10));
+		assert_eq!(asset.approve(spender, 4), Ok(()));
+		assert_eq!(asset.allowance(owner, spender), Ok(4));
+		assert_eq!(asset.approve(spender, 0), Ok(()));
+		assert_eq!(asset.allowance(owner, spender), Ok(0));
+		assert!(allowances.borrow().is_empty());
+	}
+
+	#[ink::test]
+	fn increase_and_decrease_allowance_work() {
+		test::register_chain_extension(MockAssets(Allowances::default()));
+		let (asset, owner, spender) = setup();
+
+		assert_eq!(asset.increase_allowance(spender, 10), Ok(()));
+		assert_eq!(asset.increase_allowance(spender, 5), Ok(()));
+		assert_eq!(asset.allowance(owner, spender), Ok(15));
+		assert_eq!(asset.decrease_allowance(spender, 20), Err(PSP22Error::InsufficientAllowance));
+		assert_eq!(asset.decrease_allowance(spender, 6), Ok(()));
+		assert_eq!(asset.allowance(owner, spender), Ok(9));
+	}
+
+	#[ink::test]
+	fn failed_extension_call_is_reported() {
+		test::register_chain_extension(MockAssets(Allowances::default()));
+		let (asset, _, spender) = setup();
+
+		assert!(matches!(asset.transfer(spender, 1), Err(PSP22Error::Custom(_))));
+	}
+}
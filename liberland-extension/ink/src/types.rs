@@ -1,7 +1,13 @@
 use ink::env::Environment;
+use ink::prelude::vec::Vec;
 
-type AccountId = <ink::env::DefaultEnvironment as Environment>::AccountId;
-type Balance = <ink::env::DefaultEnvironment as Environment>::Balance;
+pub type AccountId = <ink::env::DefaultEnvironment as Environment>::AccountId;
+pub type Balance = <ink::env::DefaultEnvironment as Environment>::Balance;
+
+pub type ContractIndex = u32;
+pub type AssetId = u32;
+pub type CollectionId = u32;
+pub type ItemId = u32;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
@@ -20,6 +26,112 @@ pub struct LLMForceTransferArguments {
 	pub amount: Balance,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct ContractsRegistryCreateContractArguments {
+	pub data: Vec<u8>,
+	pub parties: Option<Vec<AccountId>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct AssetsTransferArguments {
+	pub id: AssetId,
+	pub target: AccountId,
+	pub amount: Balance,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct AssetsApproveTransferArguments {
+	pub id: AssetId,
+	pub delegate: AccountId,
+	pub amount: Balance,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct AssetsTransferApprovedArguments {
+	pub id: AssetId,
+	pub owner: AccountId,
+	pub destination: AccountId,
+	pub amount: Balance,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct AssetsCancelApprovalArguments {
+	pub id: AssetId,
+	pub delegate: AccountId,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct AssetsBalanceArguments {
+	pub id: AssetId,
+	pub who: AccountId,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct AssetsAllowanceArguments {
+	pub id: AssetId,
+	pub owner: AccountId,
+	pub delegate: AccountId,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct AssetMetadata {
+	pub name: Vec<u8>,
+	pub symbol: Vec<u8>,
+	pub decimals: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct NftsItemArguments {
+	pub collection: CollectionId,
+	pub item: ItemId,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct NftsTransferArguments {
+	pub collection: CollectionId,
+	pub item: ItemId,
+	pub dest: AccountId,
+}
+
+/// Signature of a 32-byte public key. Encoded the same way as runtime's
+/// `MultiSignature`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum Signature {
+	Ed25519([u8; 64]),
+	Sr25519([u8; 64]),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct VerifySignatureArguments {
+	pub signature: Signature,
+	pub message: Vec<u8>,
+	pub signer: [u8; 32],
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
 pub enum Error {
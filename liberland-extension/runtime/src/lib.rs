@@ -1,11 +1,35 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, DecodeLimit, Encode, MaxEncodedLen};
+use frame_support::{
+	dispatch::GetDispatchInfo,
+	traits::{
+		fungibles::{approvals::Inspect as ApprovalsInspect, metadata::Inspect as MetadataInspect},
+		Contains, Currency, Get, Nothing,
+	},
+	weights::Weight,
+	BoundedVec,
+};
+use liberland_traits::{CitizenshipChecker, LLM};
 use log::{error, trace};
-use pallet_contracts::chain_extension::{ChainExtension, Environment, Ext, InitState, RetVal};
-use sp_runtime::DispatchError;
+use pallet_contracts::chain_extension::{
+	BufInBufOutState, ChainExtension, Environment, Ext, InitState, RetVal,
+};
+use sp_runtime::{
+	traits::{Dispatchable, StaticLookup, Verify},
+	AccountId32, DispatchError, MultiSignature,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
 
 type BalanceOfAssets<T> = <T as pallet_assets::Config>::Balance;
+type AssetIdOf<T> = <T as pallet_assets::Config>::AssetId;
+type ContractIndex = u32;
+type BalanceOfIdentity<T> = <<T as pallet_identity::Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+/// Maximum nesting of calls dispatched with `court_call_runtime`.
+const MAX_DECODE_NESTING: u32 = 256;
 
 #[derive(Decode, Encode, MaxEncodedLen)]
 pub struct LLMForceTransferArguments<T: pallet_llm::Config> {
@@ -14,11 +38,119 @@ pub struct LLMForceTransferArguments<T: pallet_llm::Config> {
 	amount: BalanceOfAssets<T>,
 }
 
+#[derive(Decode, Encode, MaxEncodedLen)]
+pub struct ContractsRegistryCreateContractArguments<T: pallet_contracts_registry::Config> {
+	data: BoundedVec<u8, T::MaxContractContentLen>,
+	parties: Option<BoundedVec<T::AccountId, T::MaxParties>>,
+}
+
+#[derive(Decode, Encode, MaxEncodedLen)]
+pub struct AssetsTransferArguments<T: pallet_assets::Config> {
+	id: AssetIdOf<T>,
+	target: T::AccountId,
+	amount: BalanceOfAssets<T>,
+}
+
+#[derive(Decode, Encode, MaxEncodedLen)]
+pub struct AssetsApproveTransferArguments<T: pallet_assets::Config> {
+	id: AssetIdOf<T>,
+	delegate: T::AccountId,
+	amount: BalanceOfAssets<T>,
+}
+
+#[derive(Decode, Encode, MaxEncodedLen)]
+pub struct AssetsCancelApprovalArguments<T: pallet_assets::Config> {
+	id: AssetIdOf<T>,
+	delegate: T::AccountId,
+}
+
+#[derive(Decode, Encode, MaxEncodedLen)]
+pub struct AssetsTransferApprovedArguments<T: pallet_assets::Config> {
+	id: AssetIdOf<T>,
+	owner: T::AccountId,
+	destination: T::AccountId,
+	amount: BalanceOfAssets<T>,
+}
+
+#[derive(Decode, Encode, MaxEncodedLen)]
+pub struct AssetsBalanceArguments<T: pallet_assets::Config> {
+	id: AssetIdOf<T>,
+	who: T::AccountId,
+}
+
+#[derive(Decode, Encode, MaxEncodedLen)]
+pub struct AssetsAllowanceArguments<T: pallet_assets::Config> {
+	id: AssetIdOf<T>,
+	owner: T::AccountId,
+	delegate: T::AccountId,
+}
+
+#[derive(Decode, Encode, MaxEncodedLen)]
+pub struct NftsItemArguments<T: pallet_nfts::Config> {
+	collection: T::CollectionId,
+	item: T::ItemId,
+}
+
+#[derive(Decode, Encode, MaxEncodedLen)]
+pub struct NftsTransferArguments<T: pallet_nfts::Config> {
+	collection: T::CollectionId,
+	item: T::ItemId,
+	dest: T::AccountId,
+}
+
+#[derive(Decode, Encode)]
+pub struct VerifySignatureArguments {
+	signature: MultiSignature,
+	message: Vec<u8>,
+	signer: [u8; 32],
+}
+
+/// Weight of reading an account's `IdentityOf` record, including its proof size.
+fn identity_read_weight<T: pallet_identity::Config>() -> Weight {
+	let registration = pallet_identity::Registration::<
+		BalanceOfIdentity<T>,
+		T::MaxRegistrars,
+		T::MaxAdditionalFields,
+	>::max_encoded_len();
+	// storage prefix, Twox64Concat key and the registration itself
+	let proof_size = 32 + 8 + T::AccountId::max_encoded_len() + registration;
+	T::DbWeight::get()
+		.reads(1)
+		.saturating_add(Weight::from_parts(0, proof_size as u64))
+}
+
+/// Dispatches `call` on behalf of the calling contract, charging its weight.
+///
+/// Unlike `Ext::call_runtime`, this doesn't go through `pallet_contracts::Config::CallFilter`,
+/// so calls wrapped by the extension don't have to be opened to `seal_call_runtime`.
+fn dispatch_as_contract<E: Ext>(
+	env: &mut Environment<E, BufInBufOutState>,
+	call: <E::T as pallet_contracts::Config>::RuntimeCall,
+) -> Result<(), DispatchError> {
+	let dispatch_info = call.get_dispatch_info();
+	let charged = env.charge_weight(dispatch_info.weight)?;
+	let origin = frame_system::RawOrigin::Signed(env.ext().address().clone()).into();
+	let (post_info, result) = match call.dispatch(origin) {
+		Ok(post_info) => (post_info, Ok(())),
+		Err(e) => (e.post_info, Err(e.error)),
+	};
+	env.adjust_weight(charged, post_info.actual_weight.unwrap_or(dispatch_info.weight));
+	result
+}
+
 /// Contract extension for the Liberland Chain
-#[derive(Default)]
-pub struct LiberlandExtension;
+///
+/// `CourtCallFilter` decides which calls Courts (see `pallet_llm::Courts`) can
+/// dispatch with `court_call_runtime`.
+pub struct LiberlandExtension<CourtCallFilter = Nothing>(PhantomData<CourtCallFilter>);
+
+impl<CourtCallFilter> Default for LiberlandExtension<CourtCallFilter> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
 
-impl LiberlandExtension {
+impl<CourtCallFilter> LiberlandExtension<CourtCallFilter> {
 	fn llm_force_transfer<E: Ext>(
 		&mut self,
 		env: Environment<E, InitState>,
@@ -44,21 +176,550 @@ impl LiberlandExtension {
 		ext.call_runtime(call).map_err(|e| e.error)?;
 		Ok(RetVal::Converging(0))
 	}
+
+	fn contracts_registry_create_contract<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_contracts_registry::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall:
+			From<pallet_contracts_registry::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|contracts_registry_create_contract"
+		);
+		let mut env = env.buf_in_buf_out();
+		let len = env.in_len();
+		// charge for reading and decoding the contract data before touching it
+		let input_per_byte = <E::T as pallet_contracts::Config>::Schedule::get()
+			.host_fn_weights
+			.input_per_byte;
+		env.charge_weight(input_per_byte.saturating_mul(len.into()))?;
+		let args: ContractsRegistryCreateContractArguments<E::T> = env.read_as_unbounded(len)?;
+		env.charge_weight(<E::T as frame_system::Config>::DbWeight::get().reads(1))?;
+		let contract_id: ContractIndex =
+			pallet_contracts_registry::Pallet::<E::T>::contracts_count();
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_contracts_registry::Call::<E::T>::create_contract {
+				data: args.data,
+				parties: args.parties,
+			}
+			.into();
+		// charged for the maximum data length, refunded down to the actual one
+		let dispatch_info = call.get_dispatch_info();
+		let charged = env.charge_weight(dispatch_info.weight)?;
+		let post_info = env.ext().call_runtime(call).map_err(|e| e.error)?;
+		env.adjust_weight(charged, post_info.actual_weight.unwrap_or(dispatch_info.weight));
+		env.write(&contract_id.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn contracts_registry_party_sign_contract<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_contracts_registry::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall:
+			From<pallet_contracts_registry::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|contracts_registry_party_sign_contract"
+		);
+		let mut env = env.buf_in_buf_out();
+		let contract_id: ContractIndex = env.read_as()?;
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_contracts_registry::Call::<E::T>::party_sign_contract { contract_id }.into();
+		let dispatch_info = call.get_dispatch_info();
+		let charged = env.charge_weight(dispatch_info.weight)?;
+		let post_info = env.ext().call_runtime(call).map_err(|e| e.error)?;
+		env.adjust_weight(charged, post_info.actual_weight.unwrap_or(dispatch_info.weight));
+		Ok(RetVal::Converging(0))
+	}
+
+	fn is_citizen<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_llm::Config + pallet_contracts::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|is_citizen"
+		);
+		let mut env = env.buf_in_buf_out();
+		let account: <E::T as frame_system::Config>::AccountId = env.read_as()?;
+		// IdentityOf
+		env.charge_weight(identity_read_weight::<E::T>())?;
+		let is_citizen = pallet_llm::Pallet::<E::T>::is_citizen(&account);
+		env.write(&is_citizen.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn ensure_stocks_allowed<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_llm::Config + pallet_contracts::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|ensure_stocks_allowed"
+		);
+		let mut env = env.buf_in_buf_out();
+		let account: <E::T as frame_system::Config>::AccountId = env.read_as()?;
+		// IdentityOf
+		env.charge_weight(identity_read_weight::<E::T>())?;
+		match pallet_llm::Pallet::<E::T>::ensure_stocks_allowed(&account) {
+			Ok(()) => Ok(RetVal::Converging(0)),
+			Err(_) => Ok(RetVal::Converging(1)),
+		}
+	}
+
+	fn get_llm_politics<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_llm::Config + pallet_contracts::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|get_llm_politics"
+		);
+		let mut env = env.buf_in_buf_out();
+		let account: <E::T as frame_system::Config>::AccountId = env.read_as()?;
+		// LLMPolitics
+		env.charge_weight(<E::T as frame_system::Config>::DbWeight::get().reads(1))?;
+		let amount: BalanceOfAssets<E::T> = pallet_llm::Pallet::<E::T>::get_llm_politics(&account);
+		env.write(&amount.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn citizens_count<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_llm::Config + pallet_contracts::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|citizens_count"
+		);
+		let mut env = env.buf_in_buf_out();
+		// Citizens
+		env.charge_weight(<E::T as frame_system::Config>::DbWeight::get().reads(1))?;
+		let count = pallet_llm::Pallet::<E::T>::citizens_count();
+		env.write(&count.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn llm_balance<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_llm::Config + pallet_contracts::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|llm_balance"
+		);
+		let mut env = env.buf_in_buf_out();
+		let account: <E::T as frame_system::Config>::AccountId = env.read_as()?;
+		// Assets::Account
+		env.charge_weight(<E::T as frame_system::Config>::DbWeight::get().reads(1))?;
+		let balance: BalanceOfAssets<E::T> = pallet_llm::Pallet::<E::T>::balance(account);
+		env.write(&balance.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn contracts_registry_is_fully_signed<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_contracts_registry::Config + pallet_contracts::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|contracts_registry_is_fully_signed"
+		);
+		let mut env = env.buf_in_buf_out();
+		let contract_id: ContractIndex = env.read_as()?;
+		env.charge_weight(<E::T as frame_system::Config>::DbWeight::get().reads(1))?;
+		let fully_signed =
+			pallet_contracts_registry::Pallet::<E::T>::is_fully_executed(contract_id);
+		env.write(&fully_signed.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn assets_transfer<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_assets::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_assets::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|assets_transfer"
+		);
+		let mut env = env.buf_in_buf_out();
+		let args: AssetsTransferArguments<E::T> = env.read_as()?;
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_assets::Call::<E::T>::transfer {
+				id: args.id.into(),
+				target: <E::T as frame_system::Config>::Lookup::unlookup(args.target),
+				amount: args.amount,
+			}
+			.into();
+		dispatch_as_contract(&mut env, call)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn assets_approve_transfer<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_assets::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_assets::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|assets_approve_transfer"
+		);
+		let mut env = env.buf_in_buf_out();
+		let args: AssetsApproveTransferArguments<E::T> = env.read_as()?;
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_assets::Call::<E::T>::approve_transfer {
+				id: args.id.into(),
+				delegate: <E::T as frame_system::Config>::Lookup::unlookup(args.delegate),
+				amount: args.amount,
+			}
+			.into();
+		dispatch_as_contract(&mut env, call)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn assets_cancel_approval<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_assets::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_assets::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|assets_cancel_approval"
+		);
+		let mut env = env.buf_in_buf_out();
+		let args: AssetsCancelApprovalArguments<E::T> = env.read_as()?;
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_assets::Call::<E::T>::cancel_approval {
+				id: args.id.into(),
+				delegate: <E::T as frame_system::Config>::Lookup::unlookup(args.delegate),
+			}
+			.into();
+		dispatch_as_contract(&mut env, call)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn assets_transfer_approved<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_assets::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_assets::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|assets_transfer_approved"
+		);
+		let mut env = env.buf_in_buf_out();
+		let args: AssetsTransferApprovedArguments<E::T> = env.read_as()?;
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_assets::Call::<E::T>::transfer_approved {
+				id: args.id.into(),
+				owner: <E::T as frame_system::Config>::Lookup::unlookup(args.owner),
+				destination: <E::T as frame_system::Config>::Lookup::unlookup(args.destination),
+				amount: args.amount,
+			}
+			.into();
+		dispatch_as_contract(&mut env, call)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn assets_balance<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_assets::Config + pallet_contracts::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|assets_balance"
+		);
+		let mut env = env.buf_in_buf_out();
+		let args: AssetsBalanceArguments<E::T> = env.read_as()?;
+		// Account
+		env.charge_weight(<E::T as frame_system::Config>::DbWeight::get().reads(1))?;
+		let balance = pallet_assets::Pallet::<E::T>::balance(args.id, args.who);
+		env.write(&balance.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn assets_total_supply<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_assets::Config + pallet_contracts::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|assets_total_supply"
+		);
+		let mut env = env.buf_in_buf_out();
+		let id: AssetIdOf<E::T> = env.read_as()?;
+		// Asset
+		env.charge_weight(<E::T as frame_system::Config>::DbWeight::get().reads(1))?;
+		let supply = pallet_assets::Pallet::<E::T>::total_supply(id);
+		env.write(&supply.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn assets_allowance<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_assets::Config + pallet_contracts::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|assets_allowance"
+		);
+		let mut env = env.buf_in_buf_out();
+		let args: AssetsAllowanceArguments<E::T> = env.read_as()?;
+		// Approvals
+		env.charge_weight(<E::T as frame_system::Config>::DbWeight::get().reads(1))?;
+		let allowance =
+			pallet_assets::Pallet::<E::T>::allowance(args.id, &args.owner, &args.delegate);
+		env.write(&allowance.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn assets_metadata<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_assets::Config + pallet_contracts::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|assets_metadata"
+		);
+		let mut env = env.buf_in_buf_out();
+		let id: AssetIdOf<E::T> = env.read_as()?;
+		// Metadata, read once per field
+		env.charge_weight(<E::T as frame_system::Config>::DbWeight::get().reads(3))?;
+		let metadata = (
+			pallet_assets::Pallet::<E::T>::name(id.clone()),
+			pallet_assets::Pallet::<E::T>::symbol(id.clone()),
+			pallet_assets::Pallet::<E::T>::decimals(id),
+		);
+		env.write(&metadata.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn nfts_owner<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_nfts::Config + pallet_contracts::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|nfts_owner"
+		);
+		let mut env = env.buf_in_buf_out();
+		let args: NftsItemArguments<E::T> = env.read_as()?;
+		// Item
+		env.charge_weight(<E::T as frame_system::Config>::DbWeight::get().reads(1))?;
+		let owner = pallet_nfts::Pallet::<E::T>::owner(args.collection, args.item);
+		env.write(&owner.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn nfts_transfer<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_nfts::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_nfts::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|nfts_transfer"
+		);
+		let mut env = env.buf_in_buf_out();
+		let args: NftsTransferArguments<E::T> = env.read_as()?;
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_nfts::Call::<E::T>::transfer {
+				collection: args.collection,
+				item: args.item,
+				dest: <E::T as frame_system::Config>::Lookup::unlookup(args.dest),
+			}
+			.into();
+		dispatch_as_contract(&mut env, call)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn court_call_runtime<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_llm::Config + pallet_contracts::Config,
+		CourtCallFilter: Contains<<E::T as pallet_contracts::Config>::RuntimeCall>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|court_call_runtime"
+		);
+		let mut env = env.buf_in_buf_out();
+		let len = env.in_len();
+		// charge for reading and decoding the call before touching it
+		let input_per_byte = <E::T as pallet_contracts::Config>::Schedule::get()
+			.host_fn_weights
+			.input_per_byte;
+		env.charge_weight(input_per_byte.saturating_mul(len.into()))?;
+		let encoded_call: Vec<u8> = env.read_as_unbounded(len)?;
+		let call = <<E::T as pallet_contracts::Config>::RuntimeCall>::decode_all_with_depth_limit(
+			MAX_DECODE_NESTING,
+			&mut &encoded_call[..],
+		)
+		.map_err(|_| DispatchError::Other("Invalid call"))?;
+
+		// Courts
+		env.charge_weight(<E::T as frame_system::Config>::DbWeight::get().reads(1))?;
+		let court = env.ext().address().clone();
+		if !pallet_llm::Pallet::<E::T>::is_court(&court) || !CourtCallFilter::contains(&call) {
+			return Ok(RetVal::Converging(1));
+		}
+
+		let dispatch_info = call.get_dispatch_info();
+		let charged = env.charge_weight(dispatch_info.weight)?;
+		let origin = frame_system::RawOrigin::Signed(court).into();
+		let (post_info, status) = match call.dispatch(origin) {
+			Ok(post_info) => (post_info, 0),
+			Err(e) => (e.post_info, 1),
+		};
+		env.adjust_weight(charged, post_info.actual_weight.unwrap_or(dispatch_info.weight));
+		Ok(RetVal::Converging(status))
+	}
+
+	fn verify_signature<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_contracts::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|verify_signature"
+		);
+		let mut env = env.buf_in_buf_out();
+		let len = env.in_len();
+		let host_fn_weights = <E::T as pallet_contracts::Config>::Schedule::get().host_fn_weights;
+		env.charge_weight(host_fn_weights.input_per_byte.saturating_mul(len.into()))?;
+		let args: VerifySignatureArguments = env.read_as_unbounded(len)?;
+		let message_len = args.message.len() as u64;
+		let verify_weight = match args.signature {
+			// ecdsa hashes the message with blake2 and recovers the public key
+			MultiSignature::Ecdsa(_) => host_fn_weights
+				.ecdsa_recover
+				.saturating_add(host_fn_weights.hash_blake2_256)
+				.saturating_add(
+					host_fn_weights.hash_blake2_256_per_byte.saturating_mul(message_len),
+				),
+			// there's no ed25519 host function, its cost is close to sr25519
+			MultiSignature::Ed25519(_) | MultiSignature::Sr25519(_) => {
+				host_fn_weights.sr25519_verify.saturating_add(
+					host_fn_weights.sr25519_verify_per_byte.saturating_mul(message_len),
+				)
+			},
+		};
+		env.charge_weight(verify_weight)?;
+		let valid = args.signature.verify(&args.message[..], &AccountId32::from(args.signer));
+		env.write(&valid.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
 }
 
-impl<T> ChainExtension<T> for LiberlandExtension
+impl<T, CourtCallFilter> ChainExtension<T> for LiberlandExtension<CourtCallFilter>
 where
-	T: pallet_llm::Config + pallet_contracts::Config,
-	<T as pallet_contracts::Config>::RuntimeCall: From<pallet_llm::Call<T>>,
+	CourtCallFilter: Contains<<T as pallet_contracts::Config>::RuntimeCall>,
+	T: pallet_llm::Config
+		+ pallet_contracts_registry::Config
+		+ pallet_nfts::Config
+		+ pallet_contracts::Config,
+	<T as pallet_contracts::Config>::RuntimeCall: From<pallet_llm::Call<T>>
+		+ From<pallet_contracts_registry::Call<T>>
+		+ From<pallet_assets::Call<T>>
+		+ From<pallet_nfts::Call<T>>,
 {
 	fn call<E: Ext>(&mut self, env: Environment<E, InitState>) -> Result<RetVal, DispatchError>
 	where
-		E::T: pallet_llm::Config + pallet_contracts::Config,
-		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_llm::Call<E::T>>,
+		E::T: pallet_llm::Config
+			+ pallet_contracts_registry::Config
+			+ pallet_nfts::Config
+			+ pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_llm::Call<E::T>>
+			+ From<pallet_contracts_registry::Call<E::T>>
+			+ From<pallet_assets::Call<E::T>>
+			+ From<pallet_nfts::Call<E::T>>,
+		CourtCallFilter: Contains<<E::T as pallet_contracts::Config>::RuntimeCall>,
 	{
 		let func_id = env.func_id();
 		match func_id {
 			1 => self.llm_force_transfer::<E>(env),
+			2 => self.contracts_registry_create_contract::<E>(env),
+			3 => self.contracts_registry_party_sign_contract::<E>(env),
+			4 => self.contracts_registry_is_fully_signed::<E>(env),
+			5 => self.is_citizen::<E>(env),
+			6 => self.ensure_stocks_allowed::<E>(env),
+			7 => self.get_llm_politics::<E>(env),
+			8 => self.citizens_count::<E>(env),
+			9 => self.llm_balance::<E>(env),
+			10 => self.assets_transfer::<E>(env),
+			11 => self.assets_approve_transfer::<E>(env),
+			12 => self.assets_transfer_approved::<E>(env),
+			13 => self.assets_balance::<E>(env),
+			14 => self.assets_total_supply::<E>(env),
+			15 => self.assets_allowance::<E>(env),
+			16 => self.assets_metadata::<E>(env),
+			17 => self.nfts_owner::<E>(env),
+			18 => self.nfts_transfer::<E>(env),
+			19 => self.court_call_runtime::<E>(env),
+			20 => self.verify_signature::<E>(env),
+			21 => self.assets_cancel_approval::<E>(env),
 			_ => {
 				error!("Called an unregistered `func_id`: {:}", func_id);
 				return Err(DispatchError::Other("Unimplemented func_id"));
@@ -274,7 +274,15 @@ pub fn new_partial(
 				backend: rpc_backend.clone(),
 			};
 
-			node_rpc::create_full(deps).map_err(Into::into)
+			node_rpc::create_full::<
+				_,
+				_,
+				_,
+				_,
+				kitchensink_runtime::RuntimeHoldReason,
+				kitchensink_runtime::impls::AssetFreezeReason,
+			>(deps)
+			.map_err(Into::into)
 		};
 
 		(rpc_extensions_builder, shared_voter_state2)
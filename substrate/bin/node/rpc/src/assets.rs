@@ -0,0 +1,240 @@
+/*
+
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+//! RPC interface for querying assets.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use node_primitives::{AccountId, Balance, Block, BlockNumber, Hash};
+use pallet_assets::{AssetDetails, AssetMetadata, AssetParameters};
+use pallet_assets_runtime_api::AssetsApi as AssetsRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::codec::Codec;
+
+/// Id of an asset.
+pub type AssetId = u32;
+
+/// Maximum number of holders returned by a single `assets_holders` call.
+pub const MAX_HOLDERS_PAGE: u32 = 1000;
+
+/// Error code of failed runtime API calls.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Version of the runtime API that added everything but `account_balances`.
+const DETAILS_API_VERSION: u32 = 2;
+
+/// Assets RPC methods.
+#[rpc(server)]
+pub trait AssetsApi {
+	/// Returns balances of all assets held by the account.
+	#[method(name = "assets_accountBalances")]
+	fn account_balances(
+		&self,
+		account: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(AssetId, Balance)>>;
+
+	/// Returns details of the asset, including its owner, issuer, supply and status.
+	#[method(name = "assets_details")]
+	fn details(
+		&self,
+		asset: AssetId,
+		at: Option<Hash>,
+	) -> RpcResult<Option<AssetDetails<Balance, AccountId, Balance>>>;
+
+	/// Returns metadata of the asset, if it was set.
+	#[method(name = "assets_metadata")]
+	fn metadata(
+		&self,
+		asset: AssetId,
+		at: Option<Hash>,
+	) -> RpcResult<Option<AssetMetadata<Balance, Vec<u8>>>>;
+
+	/// Returns compliance rules of the asset.
+	#[method(name = "assets_parameters")]
+	fn parameters(
+		&self,
+		asset: AssetId,
+		at: Option<Hash>,
+	) -> RpcResult<AssetParameters<BlockNumber>>;
+
+	/// Returns the company the asset is related to, if any.
+	#[method(name = "assets_relatedCompany")]
+	fn related_company(&self, asset: AssetId, at: Option<Hash>) -> RpcResult<Option<u32>>;
+
+	/// Returns up to `limit` holders of the asset with their balances, but no more than
+	/// `MAX_HOLDERS_PAGE`. Pass the last returned holder as `start_after` to get the next page.
+	#[method(name = "assets_holders")]
+	fn holders(
+		&self,
+		asset: AssetId,
+		start_after: Option<AccountId>,
+		limit: u32,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(AccountId, Balance)>>;
+}
+
+/// Provides RPC methods to query assets. `HoldReason` and `FreezeId` are the hold and freeze
+/// reasons of the runtime.
+pub struct Assets<C, HoldReason, FreezeId> {
+	client: Arc<C>,
+	_marker: PhantomData<(HoldReason, FreezeId)>,
+}
+
+impl<C, HoldReason, FreezeId> Assets<C, HoldReason, FreezeId> {
+	/// Creates a new instance of the Assets RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+
+	fn at(&self, at: Option<Hash>) -> Hash
+	where
+		C: HeaderBackend<Block>,
+	{
+		at.unwrap_or_else(|| self.client.info().best_hash)
+	}
+}
+
+impl<C, HoldReason, FreezeId> Assets<C, HoldReason, FreezeId>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: AssetsRuntimeApi<
+		Block,
+		AccountId,
+		Balance,
+		AssetId,
+		Balance,
+		BlockNumber,
+		HoldReason,
+		FreezeId,
+	>,
+	HoldReason: Codec,
+	FreezeId: Codec,
+{
+	/// Returns the block to query, if its runtime has the methods added in
+	/// `DETAILS_API_VERSION`. Runtimes before it only have `account_balances`.
+	fn at_with_details(&self, at: Option<Hash>) -> RpcResult<Hash> {
+		let at = self.at(at);
+		let api_version = self
+			.client
+			.runtime_api()
+			.api_version::<dyn AssetsRuntimeApi<
+				Block,
+				AccountId,
+				Balance,
+				AssetId,
+				Balance,
+				BlockNumber,
+				HoldReason,
+				FreezeId,
+			>>(at)
+			.map_err(runtime_error)?;
+		match api_version {
+			Some(version) if version >= DETAILS_API_VERSION => Ok(at),
+			_ => Err(runtime_error("AssetsApi of the runtime is too old.")),
+		}
+	}
+}
+
+fn runtime_error(err: impl std::fmt::Display) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Unable to query assets.",
+		Some(err.to_string()),
+	))
+	.into()
+}
+
+impl<C, HoldReason, FreezeId> AssetsApiServer for Assets<C, HoldReason, FreezeId>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: AssetsRuntimeApi<
+		Block,
+		AccountId,
+		Balance,
+		AssetId,
+		Balance,
+		BlockNumber,
+		HoldReason,
+		FreezeId,
+	>,
+	HoldReason: Codec + Send + Sync + 'static,
+	FreezeId: Codec + Send + Sync + 'static,
+{
+	fn account_balances(
+		&self,
+		account: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(AssetId, Balance)>> {
+		self.client
+			.runtime_api()
+			.account_balances(self.at(at), account)
+			.map_err(runtime_error)
+	}
+
+	fn details(
+		&self,
+		asset: AssetId,
+		at: Option<Hash>,
+	) -> RpcResult<Option<AssetDetails<Balance, AccountId, Balance>>> {
+		self.client
+			.runtime_api()
+			.asset_details(self.at_with_details(at)?, asset)
+			.map_err(runtime_error)
+	}
+
+	fn metadata(
+		&self,
+		asset: AssetId,
+		at: Option<Hash>,
+	) -> RpcResult<Option<AssetMetadata<Balance, Vec<u8>>>> {
+		self.client
+			.runtime_api()
+			.asset_metadata(self.at_with_details(at)?, asset)
+			.map_err(runtime_error)
+	}
+
+	fn parameters(
+		&self,
+		asset: AssetId,
+		at: Option<Hash>,
+	) -> RpcResult<AssetParameters<BlockNumber>> {
+		self.client
+			.runtime_api()
+			.asset_parameters(self.at_with_details(at)?, asset)
+			.map_err(runtime_error)
+	}
+
+	fn related_company(&self, asset: AssetId, at: Option<Hash>) -> RpcResult<Option<u32>> {
+		self.client
+			.runtime_api()
+			.related_company(self.at_with_details(at)?, asset)
+			.map_err(runtime_error)
+	}
+
+	fn holders(
+		&self,
+		asset: AssetId,
+		start_after: Option<AccountId>,
+		limit: u32,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(AccountId, Balance)>> {
+		self.client
+			.runtime_api()
+			.holders(self.at_with_details(at)?, asset, start_after, limit.min(MAX_HOLDERS_PAGE))
+			.map_err(runtime_error)
+	}
+}
@@ -50,6 +50,8 @@ use sp_consensus::SelectChain;
 use sp_consensus_babe::BabeApi;
 use sp_keystore::KeystorePtr;
 
+pub mod assets;
+
 /// Extra dependencies for BABE.
 pub struct BabeDeps {
 	/// A handle to the BABE worker for issuing requests.
@@ -92,8 +94,9 @@ pub struct FullDeps<C, P, SC, B> {
 	pub backend: Arc<B>,
 }
 
-/// Instantiate all Full RPC extensions.
-pub fn create_full<C, P, SC, B>(
+/// Instantiate all Full RPC extensions. `HoldReason` and `FreezeId` are the hold and freeze
+/// reasons of the assets runtime API.
+pub fn create_full<C, P, SC, B, HoldReason, FreezeId>(
 	FullDeps {
 		client,
 		pool,
@@ -118,11 +121,24 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BabeApi<Block>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: pallet_assets_runtime_api::AssetsApi<
+		Block,
+		AccountId,
+		Balance,
+		assets::AssetId,
+		Balance,
+		BlockNumber,
+		HoldReason,
+		FreezeId,
+	>,
 	P: TransactionPool + 'static,
 	SC: SelectChain<Block> + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashingFor<Block>>,
+	HoldReason: sp_runtime::codec::Codec + Send + Sync + 'static,
+	FreezeId: sp_runtime::codec::Codec + Send + Sync + 'static,
 {
+	use assets::{Assets, AssetsApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use sc_consensus_babe_rpc::{Babe, BabeApiServer};
 	use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
@@ -152,6 +168,7 @@ where
 
 	io.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
 	io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	io.merge(Assets::<_, HoldReason, FreezeId>::new(client.clone()).into_rpc())?;
 	io.merge(
 		Babe::new(client.clone(), babe_worker_handle.clone(), keystore, select_chain, deny_unsafe)
 			.into_rpc(),
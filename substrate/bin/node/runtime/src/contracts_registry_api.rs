@@ -0,0 +1,14 @@
+//! Runtime API definition for contracts registry.
+
+use pallet_contracts_registry::{ContentHash, ContractIndex};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait ContractsRegistryApi {
+		/// Returns the content hash registered for a contract with off-chain content.
+		fn content_hash(contract_id: ContractIndex) -> Option<ContentHash>;
+
+		/// Checks if `document` matches the content registered for a contract.
+		fn verify_content(contract_id: ContractIndex, document: Vec<u8>) -> bool;
+	}
+}
@@ -18,16 +18,23 @@
 //! Some configurable implementations as associated type for the substrate runtime.
 
 use super::*;
-use codec::{Encode, Decode};
+use codec::{Encode, Decode, DecodeAll};
 use frame_support::{
 	BoundedVec,
 	pallet_prelude::{ConstU32, PhantomData, Get, MaxEncodedLen},
 	traits::{
 		Currency, OnUnbalanced, InstanceFilter,
 		Contains, PrivilegeCmp, EnsureOrigin,
+		tokens::{fungibles, Fortitude, Precision, Preservation},
 	},
 };
-use sp_runtime::{RuntimeDebug, AccountId32, DispatchError, traits::{TrailingZeroInput, Morph}};
+use sp_runtime::{
+	RuntimeDebug, AccountId32, DispatchError, DispatchResult, Rounding,
+	helpers_128bit::multiply_rational,
+	traits::{TrailingZeroInput, Morph, DispatchInfoOf, PostDispatchInfoOf},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+use pallet_asset_conversion_tx_payment::{AssetConversionAdapter, OnChargeAssetTransaction};
 use sp_std::{vec, cmp::{max, min, Ordering}};
 use scale_info::TypeInfo;
 use sp_runtime::traits::Dispatchable;
@@ -266,7 +273,11 @@ impl InstanceFilter<RuntimeCall> for NftsCallFilter {
 			RuntimeCall::Nfts(pallet_nfts::Call::approve_item_attributes { .. }) |
 			RuntimeCall::Nfts(pallet_nfts::Call::cancel_item_attributes_approval { .. }) |
 			RuntimeCall::Nfts(pallet_nfts::Call::set_metadata { .. }) |
-			RuntimeCall::Nfts(pallet_nfts::Call::clear_metadata { .. })
+			RuntimeCall::Nfts(pallet_nfts::Call::clear_metadata { .. }) |
+			RuntimeCall::Nfts(pallet_nfts::Call::approve_pending_transfer { .. }) |
+			RuntimeCall::Nfts(pallet_nfts::Call::cancel_pending_transfer { .. }) |
+			RuntimeCall::Nfts(pallet_nfts::Call::subdivide { .. }) |
+			RuntimeCall::Nfts(pallet_nfts::Call::merge { .. })
 		);
 		match self {
 			NftsCallFilter::Manager => matches_manage_items || matches!(c,
@@ -282,7 +293,8 @@ impl InstanceFilter<RuntimeCall> for NftsCallFilter {
 					RuntimeCall::Nfts(pallet_nfts::Call::set_accept_ownership { .. }) |
 					RuntimeCall::Nfts(pallet_nfts::Call::set_collection_max_supply { .. }) |
 					RuntimeCall::Nfts(pallet_nfts::Call::update_mint_settings { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::set_citizenship_required { .. })
+					RuntimeCall::Nfts(pallet_nfts::Call::set_citizenship_required { .. }) |
+					RuntimeCall::Nfts(pallet_nfts::Call::set_transfer_approval_required { .. })
 				),
 			NftsCallFilter::ManageItems => matches_manage_items,
 		}
@@ -396,6 +408,60 @@ where
 }
 
 use pallet_democracy::Voting;
+/// Companies registered in the company registry.
+pub struct RegisteredCompanies;
+impl Contains<u32> for RegisteredCompanies {
+	fn contains(company: &u32) -> bool {
+		CompanyRegistry::entity_owner(company).is_some()
+	}
+}
+
+/// Registers companies for `pallet_assets` benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AssetsBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_assets::BenchmarkHelper<codec::Compact<u32>> for AssetsBenchmarkHelper {
+	fn create_asset_id_parameter(id: u32) -> codec::Compact<u32> {
+		id.into()
+	}
+
+	fn create_company() -> u32 {
+		let owner = AccountId::from([0x42; 32]);
+		Balances::make_free_balance_be(&owner, 1_000 * crate::constants::currency::DOLLARS);
+		CompanyRegistry::add_registry(RuntimeOrigin::root(), owner.clone()).unwrap();
+		let registry = CompanyRegistry::registrars().len() as u32 - 1;
+		CompanyRegistry::request_entity(
+			RuntimeOrigin::signed(owner.clone()),
+			registry,
+			Default::default(),
+			false,
+		)
+		.unwrap();
+		(0..).find(|company| CompanyRegistry::owner_entities(&owner, company)).unwrap()
+	}
+}
+
+/// Reasons for which asset balances can be frozen.
+#[derive(
+	Copy,
+	Clone,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub enum AssetFreezeReason {
+	/// Frozen by a court order
+	CourtOrder,
+	/// Pledged as collateral
+	Pledge,
+}
+
 pub struct OnLLMPoliticsUnlock;
 impl liberland_traits::OnLLMPoliticsUnlock<AccountId32> for OnLLMPoliticsUnlock
 {
@@ -417,6 +483,91 @@ impl liberland_traits::OnLLMPoliticsUnlock<AccountId32> for OnLLMPoliticsUnlock
 	}
 }
 
+/// `LiquidityInfo` of `CurrencyAdapter` used by `pallet_transaction_payment`.
+type NativeLiquidityInfo = Option<NegativeImbalance>;
+type SwapAdapter = AssetConversionAdapter<Balances, AssetConversion>;
+
+/// Charges transaction fees paid in LLM directly in LLM, at the rate set with
+/// `pallet_llm::set_fee_rate` or, if there's none, at the LLM/LLD pool price. Collected LLM goes
+/// to the LLM treasury. Fees in other assets are swapped to LLD through `AssetConversion`.
+pub struct LLMFeeAdapter;
+
+impl LLMFeeAdapter {
+	fn fee_in_llm(fee: Balance) -> Option<Balance> {
+		LLM::fee_in_llm(fee).or_else(|| {
+			AssetConversion::quote_price_tokens_for_exact_tokens(
+				NativeOrAssetId::Asset(LLM::llm_id()),
+				NativeOrAssetId::Native,
+				fee,
+				true,
+			)
+		})
+	}
+}
+
+impl OnChargeAssetTransaction<Runtime> for LLMFeeAdapter {
+	type Balance = Balance;
+	type AssetId = u32;
+	/// Native fee with tip the charged LLM corresponds to, used to compute refunds.
+	type LiquidityInfo = Balance;
+
+	fn withdraw_fee(
+		who: &AccountId,
+		call: &RuntimeCall,
+		dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		asset_id: u32,
+		fee: Balance,
+		tip: Balance,
+	) -> Result<(NativeLiquidityInfo, Balance, Balance), TransactionValidityError> {
+		if asset_id != LLM::llm_id() {
+			return SwapAdapter::withdraw_fee(who, call, dispatch_info, asset_id, fee, tip);
+		}
+		let total = fee.saturating_add(tip);
+		let llm_fee = Self::fee_in_llm(total).ok_or(InvalidTransaction::Payment)?;
+		LLM::charge_fee(who, llm_fee).map_err(|_| InvalidTransaction::Payment)?;
+		Ok((None, total, llm_fee))
+	}
+
+	fn correct_and_deposit_fee(
+		who: &AccountId,
+		dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		post_info: &PostDispatchInfoOf<RuntimeCall>,
+		corrected_fee: Balance,
+		tip: Balance,
+		fee_paid: NativeLiquidityInfo,
+		received_exchanged: Balance,
+		asset_id: u32,
+		initial_asset_consumed: Balance,
+	) -> Result<Balance, TransactionValidityError> {
+		if asset_id != LLM::llm_id() {
+			return SwapAdapter::correct_and_deposit_fee(
+				who,
+				dispatch_info,
+				post_info,
+				corrected_fee,
+				tip,
+				fee_paid,
+				received_exchanged,
+				asset_id,
+				initial_asset_consumed,
+			);
+		}
+		let charged = multiply_rational(
+			initial_asset_consumed,
+			corrected_fee.saturating_add(tip),
+			received_exchanged,
+			Rounding::Up,
+		)
+		.unwrap_or(initial_asset_consumed)
+		.min(initial_asset_consumed);
+		let refund = initial_asset_consumed - charged;
+		if refund > 0 {
+			LLM::refund_fee(who, refund).map_err(|_| InvalidTransaction::Payment)?;
+		}
+		Ok(charged)
+	}
+}
+
 pub struct EnsureCmp<L>(sp_std::marker::PhantomData<L>);
 impl<L: EnsureOrigin<RuntimeOrigin>> PrivilegeCmp<OriginCaller> for EnsureCmp<L> {
   fn cmp_privilege(left: &OriginCaller, _: &OriginCaller) -> Option<Ordering> {
@@ -458,93 +609,610 @@ pub struct Coords {
 	Serialize,
 	Deserialize,
 )]
-pub struct Metadata<MaxCoords: Get<u32>, MaxString: Get<u32>> {
+pub struct Metadata<MaxCoords: Get<u32>> {
 	demarcation: BoundedVec<Coords, MaxCoords>,
-	r#type: BoundedVec<u8, MaxString>,
-	status: BoundedVec<u8, MaxString>,
+	r#type: LandType,
+	status: LandStatus,
 }
 
-pub struct LandMetadataValidator<CoordsBounds: Get<(Coords, Coords)>>(PhantomData<CoordsBounds>);
+// `Unspecified` variants come first, so they have the same encoding as the empty strings
+// `type` and `status` used to be.
+#[derive(
+	Clone,
+	Copy,
+	Default,
+	Eq,
+	PartialEq,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	MaxEncodedLen,
+	scale_info::TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub enum LandType {
+	#[default]
+	Unspecified,
+	Residential,
+	Commercial,
+	Industrial,
+	Agricultural,
+	Public,
+	Mixed,
+}
 
-impl<CoordsBounds: Get<(Coords, Coords)>, StringLimit>
-	pallet_nfts::traits::MetadataValidator<u32, u32, StringLimit>
-	for LandMetadataValidator<CoordsBounds>
-{
-	fn validate_metadata(collection: u32, _: u32, data: &BoundedVec<u8, StringLimit>) -> bool {
-		// https://www.geeksforgeeks.org/check-if-two-given-line-segments-intersect/
-		#[derive(PartialEq, Eq)]
-		enum PointsOrientation {
-			Clockwise,
-			Counterclockwise,
-			Collinear,
+#[derive(
+	Clone,
+	Copy,
+	Default,
+	Eq,
+	PartialEq,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	MaxEncodedLen,
+	scale_info::TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub enum LandStatus {
+	#[default]
+	Unspecified,
+	Undeveloped,
+	UnderConstruction,
+	Developed,
+}
+
+// lowercase, with spaces, dashes and underscores removed
+fn normalize_legacy_string(s: &[u8]) -> Vec<u8> {
+	s.iter()
+		.filter(|c| !matches!(c, b' ' | b'-' | b'_'))
+		.map(|c| c.to_ascii_lowercase())
+		.collect()
+}
+
+impl LandType {
+	fn from_legacy(s: &[u8]) -> Self {
+		match &normalize_legacy_string(s)[..] {
+			b"residential" => Self::Residential,
+			b"commercial" => Self::Commercial,
+			b"industrial" => Self::Industrial,
+			b"agricultural" => Self::Agricultural,
+			b"public" => Self::Public,
+			b"mixed" => Self::Mixed,
+			_ => Self::Unspecified,
 		}
-		fn intersection(p1: Coords, q1: Coords, p2: Coords, q2: Coords) -> bool {
-			fn on_segment(p: Coords, q: Coords, r: Coords) -> bool {
-				q.long <= max(p.long, r.long) &&
-				   q.long >= min(p.long, r.long) &&
-				   q.lat <= max(p.lat, r.lat) &&
-				   q.lat >= min(p.lat, r.lat)
-			}
-			fn orientation(p: Coords, q: Coords, r: Coords) -> PointsOrientation {
-				let val = ((q.lat - p.lat) * (r.long - q.long)) - ((q.long - p.long) * (r.lat - q.lat));
-				match val {
-					val if val > 0 => {
-						PointsOrientation::Clockwise
-					}
-					val if val < 0 => {
-						PointsOrientation::Counterclockwise
-					}
-					_ => {
-						PointsOrientation::Collinear
-					}
-				}
+	}
+}
+
+impl LandStatus {
+	fn from_legacy(s: &[u8]) -> Self {
+		match &normalize_legacy_string(s)[..] {
+			b"undeveloped" => Self::Undeveloped,
+			b"underconstruction" => Self::UnderConstruction,
+			b"developed" => Self::Developed,
+			_ => Self::Unspecified,
+		}
+	}
+}
+
+impl<MaxCoords: Get<u32>> Metadata<MaxCoords> {
+	/// Translates metadata set while `type` and `status` were free-form strings. Returns `None`
+	/// if `data` is already in the current format or can't be decoded as the old one. Strings
+	/// that don't name any variant become `Unspecified`.
+	pub fn from_legacy(data: &[u8]) -> Option<Self> {
+		// old metadata with empty strings has the same encoding as `Unspecified` variants
+		if Self::decode_all(&mut &data[..]).is_ok() {
+			return None
+		}
+		let (demarcation, r#type, status) =
+			<(BoundedVec<Coords, MaxCoords>, Vec<u8>, Vec<u8>)>::decode_all(&mut &data[..]).ok()?;
+		Some(Self {
+			demarcation,
+			r#type: LandType::from_legacy(&r#type),
+			status: LandStatus::from_legacy(&status),
+		})
+	}
+}
+
+/// Size and location of a land plot, derived from its demarcation.
+#[derive(
+	Clone,
+	Copy,
+	Eq,
+	PartialEq,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	MaxEncodedLen,
+	scale_info::TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct PlotMeasurements {
+	/// Area in square meters.
+	pub area: u64,
+	/// Perimeter in meters.
+	pub perimeter: u64,
+	pub centroid: Coords,
+}
+
+/// Records the clerk executing the call via land registry office as the approver of NFT
+/// transfers, instead of the office account itself.
+pub struct OfficeClerkTransferApprover;
+
+impl pallet_nfts::traits::TransferApprover<AccountId> for OfficeClerkTransferApprover {
+	fn approver(origin: &AccountId) -> AccountId {
+		let land_office: AccountId =
+			crate::LandRegistryOfficePalletId::get().into_account_truncating();
+		let metaverse_office: AccountId =
+			crate::MetaverseLandRegistryOfficePalletId::get().into_account_truncating();
+		let clerk = if origin == &land_office {
+			crate::LandRegistryOffice::executing_clerk()
+		} else if origin == &metaverse_office {
+			crate::MetaverseLandRegistryOffice::executing_clerk()
+		} else {
+			None
+		};
+		clerk.unwrap_or_else(|| origin.clone())
+	}
+}
+
+/// Issues shares of fractionalized NFTs as `pallet_assets` assets. Assets are owned by a keyless
+/// account, so no more shares can be minted.
+pub struct AssetShares;
+
+impl pallet_nfts::traits::Shares<AccountId> for AssetShares {
+	type AssetId = u32;
+	type Balance = Balance;
+
+	fn issue(
+		asset: u32,
+		beneficiary: &AccountId,
+		amount: Balance,
+		citizenship_required: bool,
+	) -> DispatchResult {
+		let owner: AccountId = crate::NftSharesPalletId::get().into_account_truncating();
+		<crate::Assets as fungibles::Create<AccountId>>::create(asset, owner, false, 1)?;
+		crate::Assets::do_set_parameters(
+			asset,
+			pallet_assets::AssetParameters::new(if citizenship_required {
+				pallet_assets::HolderRequirement::Citizen
+			} else {
+				pallet_assets::HolderRequirement::Anyone
+			}),
+			None,
+		)?;
+		<crate::Assets as fungibles::Mutate<AccountId>>::mint_into(asset, beneficiary, amount)?;
+		Ok(())
+	}
+
+	fn holds_all(asset: u32, who: &AccountId) -> bool {
+		let supply = <crate::Assets as fungibles::Inspect<AccountId>>::total_issuance(asset);
+		supply > 0 && <crate::Assets as fungibles::Inspect<AccountId>>::balance(asset, who) == supply
+	}
+
+	fn redeem(asset: u32, who: &AccountId) -> DispatchResult {
+		let amount = <crate::Assets as fungibles::Inspect<AccountId>>::balance(asset, who);
+		<crate::Assets as fungibles::Mutate<AccountId>>::burn_from(
+			asset,
+			who,
+			amount,
+			Precision::Exact,
+			Fortitude::Force,
+		)?;
+		let limit = <Runtime as pallet_assets::Config>::RemoveItemsLimit::get();
+		<crate::Assets as fungibles::Destroy<AccountId>>::start_destroy(asset, None)?;
+		<crate::Assets as fungibles::Destroy<AccountId>>::destroy_accounts(asset, limit)?;
+		<crate::Assets as fungibles::Destroy<AccountId>>::destroy_approvals(asset, limit)?;
+		<crate::Assets as fungibles::Destroy<AccountId>>::finish_destroy(asset)
+	}
+
+	fn issue_weight() -> Weight {
+		use pallet_assets::WeightInfo as _;
+		type AssetsWeight = <Runtime as pallet_assets::Config>::WeightInfo;
+		AssetsWeight::force_create()
+			.saturating_add(AssetsWeight::force_set_parameters())
+			.saturating_add(AssetsWeight::mint())
+	}
+
+	fn redeem_weight() -> Weight {
+		use pallet_assets::WeightInfo as _;
+		type AssetsWeight = <Runtime as pallet_assets::Config>::WeightInfo;
+		let limit = <Runtime as pallet_assets::Config>::RemoveItemsLimit::get();
+		<Runtime as frame_system::Config>::DbWeight::get()
+			.reads(2)
+			.saturating_add(AssetsWeight::burn())
+			.saturating_add(AssetsWeight::start_destroy())
+			.saturating_add(AssetsWeight::destroy_accounts(limit))
+			.saturating_add(AssetsWeight::destroy_approvals(limit))
+			.saturating_add(AssetsWeight::finish_destroy())
+	}
+}
+
+impl pallet_nfts::traits::RentAssets<AccountId> for AssetShares {
+	type AssetId = u32;
+	type Balance = Balance;
+
+	fn transfer(asset: u32, from: &AccountId, to: &AccountId, amount: Balance) -> DispatchResult {
+		<crate::Assets as fungibles::Mutate<AccountId>>::transfer(
+			asset,
+			from,
+			to,
+			amount,
+			Preservation::Expendable,
+		)?;
+		Ok(())
+	}
+
+	fn transfer_weight() -> Weight {
+		use pallet_assets::WeightInfo as _;
+		<Runtime as pallet_assets::Config>::WeightInfo::transfer()
+	}
+}
+
+/// Size of a cell of the land plots spatial index, in the same units as `Coords` (0.0000001°).
+/// 0.01° is roughly 1.1km x 0.8km around Liberland, so with `LAND_MAX_INDEX_KEYS` cells plots up
+/// to ~10km across can be indexed.
+pub const LAND_GRID_CELL_SIZE: i64 = 100_000;
+/// Max number of grid cells a land plot may cover.
+pub const LAND_MAX_INDEX_KEYS: u32 = 128;
+/// Max number of distinct neighbors checked for overlaps with a land plot. Plots with more
+/// neighbors are rejected with `TooManyPlotsInCell`.
+pub const LAND_MAX_OVERLAP_CHECKS: u32 = 100;
+/// Max number of index entries visited when looking up neighbors of a land plot. A neighbor is
+/// visited once per grid cell it shares with the plot.
+pub const LAND_MAX_INDEX_ENTRIES: u32 = 1_000;
+
+#[derive(PartialEq, Eq)]
+enum PointsOrientation {
+	Clockwise,
+	Counterclockwise,
+	Collinear,
+}
+
+#[derive(PartialEq, Eq)]
+enum PointLocation {
+	Inside,
+	Boundary,
+	Outside,
+}
+
+fn orientation(p: Coords, q: Coords, r: Coords) -> PointsOrientation {
+	let val = ((q.lat - p.lat) as i128 * (r.long - q.long) as i128) -
+		((q.long - p.long) as i128 * (r.lat - q.lat) as i128);
+	match val {
+		val if val > 0 => PointsOrientation::Clockwise,
+		val if val < 0 => PointsOrientation::Counterclockwise,
+		_ => PointsOrientation::Collinear,
+	}
+}
+
+fn on_segment(p: Coords, q: Coords, r: Coords) -> bool {
+	q.long <= max(p.long, r.long) &&
+		q.long >= min(p.long, r.long) &&
+		q.lat <= max(p.lat, r.lat) &&
+		q.lat >= min(p.lat, r.lat)
+}
+
+// https://www.geeksforgeeks.org/check-if-two-given-line-segments-intersect/
+fn intersection(p1: Coords, q1: Coords, p2: Coords, q2: Coords) -> bool {
+	let o1 = orientation(p1, q1, p2);
+	let o2 = orientation(p1, q1, q2);
+	let o3 = orientation(p2, q2, p1);
+	let o4 = orientation(p2, q2, q1);
+	(o1 != o2 && o3 != o4) ||
+		(o1 == PointsOrientation::Collinear && on_segment(p1, p2, q1)) ||
+		(o2 == PointsOrientation::Collinear && on_segment(p1, q2, q1)) ||
+		(o3 == PointsOrientation::Collinear && on_segment(p2, p1, q2)) ||
+		(o4 == PointsOrientation::Collinear && on_segment(p2, q1, q2))
+}
+
+// segments cross at a single point that's not an endpoint of either of them
+fn proper_intersection(p1: Coords, q1: Coords, p2: Coords, q2: Coords) -> bool {
+	let o1 = orientation(p1, q1, p2);
+	let o2 = orientation(p1, q1, q2);
+	let o3 = orientation(p2, q2, p1);
+	let o4 = orientation(p2, q2, q1);
+	o1 != PointsOrientation::Collinear &&
+		o2 != PointsOrientation::Collinear &&
+		o3 != PointsOrientation::Collinear &&
+		o4 != PointsOrientation::Collinear &&
+		o1 != o2 && o3 != o4
+}
+
+fn edges(polygon: &[Coords]) -> impl Iterator<Item = (Coords, Coords)> + '_ {
+	polygon.iter().zip(polygon.iter().cycle().skip(1)).map(|(a, b)| (*a, *b))
+}
+
+// ray casting, with exact integer arithmetic
+fn locate_point(p: Coords, polygon: &[Coords]) -> PointLocation {
+	let mut inside = false;
+	for (a, b) in edges(polygon) {
+		if orientation(a, p, b) == PointsOrientation::Collinear && on_segment(a, p, b) {
+			return PointLocation::Boundary
+		}
+		if (a.lat > p.lat) != (b.lat > p.lat) {
+			let lhs = (p.long - a.long) as i128 * (b.lat - a.lat) as i128;
+			let rhs = (b.long - a.long) as i128 * (p.lat - a.lat) as i128;
+			if (b.lat > a.lat && lhs < rhs) || (b.lat < a.lat && lhs > rhs) {
+				inside = !inside;
 			}
+		}
+	}
+	if inside {
+		PointLocation::Inside
+	} else {
+		PointLocation::Outside
+	}
+}
 
-			let o1 = orientation(p1, q1, p2);
-			let o2 = orientation(p1, q1, q2);
-			let o3 = orientation(p2, q2, p1);
-			let o4 = orientation(p2, q2, q1);
-			if (o1 != o2 && o3 != o4) ||
-			   (o1 == PointsOrientation::Collinear && on_segment(p1, p2, q1)) ||
-			   (o2 == PointsOrientation::Collinear && on_segment(p1, q2, q1)) ||
-			   (o3 == PointsOrientation::Collinear && on_segment(p2, p1, q2)) ||
-			   (o4 == PointsOrientation::Collinear && on_segment(p2, q1, q2))
-			{
+// Checks if interiors of two simple polygons overlap. Touching edges or vertices are fine, so
+// adjacent plots can share borders.
+fn polygons_overlap(a: &[Coords], b: &[Coords]) -> bool {
+	// scale by 2 so that edge midpoints have integer coordinates
+	let scale = |p: &[Coords]| -> Vec<Coords> {
+		p.iter().map(|c| Coords { lat: c.lat * 2, long: c.long * 2 }).collect()
+	};
+	let (a, b) = (scale(a), scale(b));
+
+	for (p1, q1) in edges(&a) {
+		for (p2, q2) in edges(&b) {
+			if proper_intersection(p1, q1, p2, q2) {
 				return true
 			}
+		}
+	}
 
-			false
+	let has_point_inside = |x: &[Coords], y: &[Coords]| {
+		edges(x).any(|(p, q)| {
+			let mid = Coords { lat: (p.lat + q.lat) / 2, long: (p.long + q.long) / 2 };
+			locate_point(p, y) == PointLocation::Inside ||
+				locate_point(mid, y) == PointLocation::Inside
+		})
+	};
+	if has_point_inside(&a, &b) || has_point_inside(&b, &a) {
+		return true
+	}
+
+	// the only remaining case of overlap is the same plot, possibly with extra vertices in the
+	// middle of its edges
+	let (mut a, mut b) = (without_collinear_vertices(&a), without_collinear_vertices(&b));
+	a.sort_by_key(|c| (c.lat, c.long));
+	b.sort_by_key(|c| (c.lat, c.long));
+	a == b
+}
+
+// drops vertices lying on a straight line between their neighbors, including duplicates
+fn without_collinear_vertices(polygon: &[Coords]) -> Vec<Coords> {
+	let n = polygon.len();
+	(0..n)
+		.filter(|&i| {
+			orientation(polygon[(i + n - 1) % n], polygon[i], polygon[(i + 1) % n]) !=
+				PointsOrientation::Collinear
+		})
+		.map(|i| polygon[i])
+		.collect()
+}
+
+// twice the area of a simple polygon (shoelace formula), in squared `Coords` units
+fn doubled_area(polygon: &[Coords]) -> i128 {
+	edges(polygon)
+		.map(|(a, b)| a.long as i128 * b.lat as i128 - b.long as i128 * a.lat as i128)
+		.sum::<i128>()
+		.abs()
+}
+
+// checks that `inner` lies within `outer`, touching its border is fine
+fn polygon_contains(outer: &[Coords], inner: &[Coords]) -> bool {
+	// scale by 2 so that edge midpoints have integer coordinates
+	let scale = |p: &[Coords]| -> Vec<Coords> {
+		p.iter().map(|c| Coords { lat: c.lat * 2, long: c.long * 2 }).collect()
+	};
+	let (outer, inner) = (scale(outer), scale(inner));
+
+	for (p1, q1) in edges(&outer) {
+		for (p2, q2) in edges(&inner) {
+			if proper_intersection(p1, q1, p2, q2) {
+				return false
+			}
 		}
+	}
 
-		if collection != 0 && collection != 1 {
-			return true
+	let contained = edges(&inner).all(|(p, q)| {
+		let mid = Coords { lat: (p.lat + q.lat) / 2, long: (p.long + q.long) / 2 };
+		locate_point(p, &outer) != PointLocation::Outside &&
+			locate_point(mid, &outer) != PointLocation::Outside
+	});
+	contained
+}
+
+// `parts` exactly cover `whole` if they're all within it, don't overlap and their areas add up
+fn is_exact_tiling(whole: &[Coords], parts: &[Vec<Coords>]) -> bool {
+	if !parts.iter().all(|part| polygon_contains(whole, part)) {
+		return false
+	}
+	for i in 0..parts.len() {
+		for j in (i + 1)..parts.len() {
+			if polygons_overlap(&parts[i], &parts[j]) {
+				return false
+			}
 		}
+	}
+	parts.iter().map(|part| doubled_area(part)).sum::<i128>() == doubled_area(whole)
+}
 
-		let data = data.clone();
-		let data = Metadata::<ConstU32<100>, ConstU32<100>>::decode(&mut TrailingZeroInput::new(
-			&data[..],
-		));
-		if data.is_err() {
-			return false
+fn grid_cell(c: &Coords) -> (i64, i64) {
+	(c.lat.div_euclid(LAND_GRID_CELL_SIZE), c.long.div_euclid(LAND_GRID_CELL_SIZE))
+}
+
+fn grid_key((lat, long): (i64, i64)) -> u64 {
+	((lat as u32 as u64) << 32) | (long as u32 as u64)
+}
+
+// keys of all grid cells covered by the bounding box of the polygon
+fn grid_keys(polygon: &[Coords]) -> Vec<u64> {
+	let (mut min_cell, mut max_cell) = (grid_cell(&polygon[0]), grid_cell(&polygon[0]));
+	for c in polygon.iter().map(grid_cell) {
+		min_cell = (min(min_cell.0, c.0), min(min_cell.1, c.1));
+		max_cell = (max(max_cell.0, c.0), max(max_cell.1, c.1));
+	}
+	let mut keys = vec![];
+	for lat in min_cell.0..=max_cell.0 {
+		for long in min_cell.1..=max_cell.1 {
+			keys.push(grid_key((lat, long)));
 		}
+	}
+	keys
+}
 
-		let mut data = data.unwrap();
+// fixed point arithmetic scale used for geodesic computations
+const GEO_SCALE: i128 = 1_000_000_000_000;
+// π / 180, scaled by 1e18
+const PI_OVER_180: i128 = 17_453_292_519_943_295;
+// `Coords` units per degree
+const COORDS_PER_DEGREE: i128 = 10_000_000;
+// WGS84 semi-major axis in meters
+const WGS84_A: i128 = 6_378_137;
+// WGS84 first eccentricity squared, scaled by `GEO_SCALE`
+const WGS84_E2: i128 = 6_694_379_990;
+
+/// Attribute keys under which plot measurements are stored.
+pub const AREA_ATTRIBUTE: &[u8] = b"area";
+pub const PERIMETER_ATTRIBUTE: &[u8] = b"perimeter";
+pub const CENTROID_ATTRIBUTE: &[u8] = b"centroid";
+
+fn isqrt(n: u128) -> u128 {
+	if n < 2 {
+		return n
+	}
+	let mut x = n;
+	let mut y = n / 2 + 1;
+	while y < x {
+		x = y;
+		y = (x + n / x) / 2;
+	}
+	x
+}
+
+// sine and cosine of `x` radians, Taylor series. `x` and results are scaled by `GEO_SCALE`.
+fn sin_cos(x: i128) -> (i128, i128) {
+	let x2 = x * x / GEO_SCALE;
+	let series = |mut term: i128, mut n: i128| {
+		let mut sum = 0;
+		while term != 0 {
+			sum += term;
+			term = -term * x2 / GEO_SCALE / ((n + 1) * (n + 2));
+			n += 2;
+		}
+		sum
+	};
+	(series(x, 1), series(GEO_SCALE, 0))
+}
+
+// Length of one `Coords` unit of latitude and longitude at latitude `lat`, in meters scaled by
+// `GEO_SCALE`. Uses meridional and prime vertical radii of curvature of the WGS84 ellipsoid.
+fn meters_per_unit(lat: i64) -> (i128, i128) {
+	let phi = lat as i128 * PI_OVER_180 / COORDS_PER_DEGREE / 1_000_000;
+	let (sin, cos) = sin_cos(phi);
+	let w = GEO_SCALE - WGS84_E2 * (sin * sin / GEO_SCALE) / GEO_SCALE;
+	let sqrt_w = isqrt((w * GEO_SCALE) as u128) as i128;
+	let prime_vertical = WGS84_A * GEO_SCALE * GEO_SCALE / sqrt_w;
+	let meridional = WGS84_A * (GEO_SCALE - WGS84_E2) * GEO_SCALE / w * GEO_SCALE / sqrt_w;
+	let per_unit =
+		|radius: i128| radius * PI_OVER_180 / COORDS_PER_DEGREE / 1_000_000_000_000_000_000;
+	(per_unit(meridional), per_unit(prime_vertical * cos / GEO_SCALE))
+}
+
+// Centroid of a simple polygon. It's preserved by the (linear) local projection, so it can be
+// computed directly on `Coords`.
+fn centroid(polygon: &[Coords]) -> Coords {
+	let origin = polygon[0];
+	let relative = |c: &Coords| ((c.long - origin.long) as i128, (c.lat - origin.lat) as i128);
+	let (mut doubled_area, mut long, mut lat) = (0i128, 0i128, 0i128);
+	for (a, b) in edges(polygon) {
+		let ((x1, y1), (x2, y2)) = (relative(&a), relative(&b));
+		let cross = x1 * y2 - x2 * y1;
+		doubled_area += cross;
+		long += (x1 + x2) * cross;
+		lat += (y1 + y2) * cross;
+	}
+	let (long, lat) = if doubled_area == 0 {
+		// degenerate polygon, fall back to the average of vertices
+		let n = polygon.len() as i128;
+		let (long, lat) =
+			polygon.iter().map(relative).fold((0, 0), |(x, y), (dx, dy)| (x + dx, y + dy));
+		(long / n, lat / n)
+	} else {
+		(long / (3 * doubled_area), lat / (3 * doubled_area))
+	};
+	Coords { lat: origin.lat + lat as i64, long: origin.long + long as i64 }
+}
+
+/// Computes area, perimeter and centroid of a plot. Vertices are projected to a plane tangent to
+/// the WGS84 ellipsoid at the middle latitude of the plot, which is accurate to well below a
+/// meter for plots up to a few kilometers across. Integer arithmetic only, so results are
+/// deterministic.
+pub fn plot_measurements(demarcation: &[Coords]) -> PlotMeasurements {
+	let (min_lat, max_lat) = demarcation
+		.iter()
+		.fold((i64::MAX, i64::MIN), |(lo, hi), c| (min(lo, c.lat), max(hi, c.lat)));
+	let (lat_scale, long_scale) = meters_per_unit(min_lat / 2 + max_lat / 2);
+
+	// projected vertices, in micrometers
+	let origin = demarcation[0];
+	let project = |c: Coords| {
+		(
+			(c.long - origin.long) as i128 * long_scale / 1_000_000,
+			(c.lat - origin.lat) as i128 * lat_scale / 1_000_000,
+		)
+	};
+	let (mut doubled_area, mut perimeter) = (0i128, 0u128);
+	for (a, b) in edges(demarcation) {
+		let ((x1, y1), (x2, y2)) = (project(a), project(b));
+		doubled_area += x1 * y2 - x2 * y1;
+		perimeter += isqrt(((x2 - x1) * (x2 - x1) + (y2 - y1) * (y2 - y1)) as u128);
+	}
+
+	// rounded to the nearest square meter and meter
+	let square_meter = 1_000_000_000_000u128;
+	let area = (doubled_area.unsigned_abs() + square_meter) / (2 * square_meter);
+	PlotMeasurements {
+		area: area as u64,
+		perimeter: ((perimeter + 500_000) / 1_000_000) as u64,
+		centroid: centroid(demarcation),
+	}
+}
+
+pub struct LandMetadataValidator<CoordsBounds: Get<(Coords, Coords)>>(PhantomData<CoordsBounds>);
+
+impl<CoordsBounds: Get<(Coords, Coords)>> LandMetadataValidator<CoordsBounds> {
+	/// Decodes demarcation of a plot from metadata. Drops last point if it's the same as first.
+	/// Only the demarcation is decoded, so plots with `type` and `status` set before they
+	/// became enums are still found.
+	fn demarcation(data: &[u8]) -> Option<Vec<Coords>> {
+		let mut demarcation =
+			BoundedVec::<Coords, ConstU32<100>>::decode(&mut TrailingZeroInput::new(data))
+				.ok()?
+				.into_inner();
 
 		// pop last point if it's the same as first
-		if data.demarcation.len() > 1 &&
-		   data.demarcation.first() == data.demarcation.last()
-		{
-			data.demarcation.pop();
+		if demarcation.len() > 1 && demarcation.first() == demarcation.last() {
+			demarcation.pop();
 		}
+		Some(demarcation)
+	}
 
+	fn is_valid_demarcation(demarcation: &[Coords]) -> bool {
 		// does it have at least 3 points
-		if data.demarcation.len() < 3 {
+		if demarcation.len() < 3 {
 			return false
 		}
 
 		// is it roughly in the good place
 		let (a, b) = CoordsBounds::get();
-		for c in data.demarcation.iter() {
+		for c in demarcation.iter() {
 			if c.lat < a.lat || c.lat > b.lat || c.long < a.long || c.long > b.long {
 				return false
 			}
@@ -552,12 +1220,7 @@ impl<CoordsBounds: Get<(Coords, Coords)>, StringLimit>
 
 		// check self intersection. we do it by checking if any of line segments
 		// intersect with any other non-neighboring segment in the plot
-		let mut lines = vec![];
-		for i in 1..data.demarcation.len() {
-			lines.push((data.demarcation[i - 1].clone(), data.demarcation[i].clone()))
-		}
-		lines.push((data.demarcation[data.demarcation.len() - 1], data.demarcation[0]));
-
+		let lines: Vec<(Coords, Coords)> = edges(demarcation).collect();
 		for i in 0..lines.len() {
 			let a = lines[i];
 			for j in (i + 2)..=(lines.len() + i - 2) {
@@ -571,6 +1234,177 @@ impl<CoordsBounds: Get<(Coords, Coords)>, StringLimit>
 
 		true
 	}
+
+	/// Distinct plots indexed in grid cells covered by the plot, excluding the plot itself.
+	/// Returns `None` if there are more than `LAND_MAX_OVERLAP_CHECKS` of them or looking them
+	/// up visits more than `LAND_MAX_INDEX_ENTRIES` index entries.
+	fn neighbors(collection: u32, item: u32, keys: Vec<u64>) -> Option<Vec<u32>> {
+		let mut neighbors = vec![];
+		let mut visited = 0;
+		for key in keys {
+			for other in
+				pallet_nfts::MetadataIndex::<crate::Runtime>::iter_key_prefix((collection, key))
+			{
+				visited += 1;
+				if visited > LAND_MAX_INDEX_ENTRIES {
+					return None
+				}
+				if other == item || neighbors.contains(&other) {
+					continue
+				}
+				if neighbors.len() >= LAND_MAX_OVERLAP_CHECKS as usize {
+					return None
+				}
+				neighbors.push(other);
+			}
+		}
+		Some(neighbors)
+	}
+
+	/// Checks if the plot overlaps with any other indexed plot in the collection. Plots covering
+	/// more than `LAND_MAX_INDEX_KEYS` cells are treated as overlapping and plots with too many
+	/// neighbors are expected to be rejected by `within_validation_limits` first, so that the
+	/// check stays bounded.
+	fn overlaps_existing_plot(collection: u32, item: u32, demarcation: &[Coords]) -> bool {
+		let keys = grid_keys(demarcation);
+		if keys.len() > LAND_MAX_INDEX_KEYS as usize {
+			return true
+		}
+		match Self::neighbors(collection, item, keys) {
+			Some(neighbors) => neighbors.into_iter().any(|other| {
+				crate::Nfts::item_metadata(collection, other)
+					.and_then(|data| Self::demarcation(&data[..]))
+					.map_or(false, |other_demarcation| {
+						polygons_overlap(demarcation, &other_demarcation)
+					})
+			}),
+			None => true,
+		}
+	}
+
+	/// Returns measurements of a plot stored in its attributes.
+	pub fn plot_measurements(collection: u32, item: u32) -> Option<PlotMeasurements> {
+		let attribute = |key: &[u8]| crate::Nfts::derived_attribute(collection, item, key.to_vec());
+		Some(PlotMeasurements {
+			area: Decode::decode(&mut &attribute(AREA_ATTRIBUTE)?[..]).ok()?,
+			perimeter: Decode::decode(&mut &attribute(PERIMETER_ATTRIBUTE)?[..]).ok()?,
+			centroid: Decode::decode(&mut &attribute(CENTROID_ATTRIBUTE)?[..]).ok()?,
+		})
+	}
+
+	/// Returns the plot in `collection` that contains `point`, including its border.
+	pub fn plot_at(collection: u32, point: Coords) -> Option<u32> {
+		crate::Nfts::indexed_items(collection, grid_key(grid_cell(&point))).into_iter().find(
+			|item| {
+				crate::Nfts::item_metadata(collection, *item)
+					.and_then(|data| Self::demarcation(&data[..]))
+					.map_or(false, |d| locate_point(point, &d) != PointLocation::Outside)
+			},
+		)
+	}
+}
+
+impl<CoordsBounds: Get<(Coords, Coords)>, StringLimit>
+	pallet_nfts::traits::MetadataValidator<u32, u32, StringLimit>
+	for LandMetadataValidator<CoordsBounds>
+{
+	fn validate_metadata(collection: u32, item: u32, data: &BoundedVec<u8, StringLimit>) -> bool {
+		if collection != 0 && collection != 1 {
+			return true
+		}
+
+		if Metadata::<ConstU32<100>>::decode(&mut TrailingZeroInput::new(&data[..])).is_err() {
+			return false
+		}
+
+		match Self::demarcation(&data[..]) {
+			Some(demarcation) =>
+				Self::is_valid_demarcation(&demarcation) &&
+					!Self::overlaps_existing_plot(collection, item, &demarcation),
+			None => false,
+		}
+	}
+
+	fn within_validation_limits(
+		collection: u32,
+		item: u32,
+		data: &BoundedVec<u8, StringLimit>,
+	) -> bool {
+		if collection != 0 && collection != 1 {
+			return true
+		}
+
+		// oversized and malformed plots are rejected by `validate_metadata`
+		match Self::demarcation(&data[..]) {
+			Some(demarcation) if !demarcation.is_empty() => {
+				let keys = grid_keys(&demarcation);
+				keys.len() > LAND_MAX_INDEX_KEYS as usize ||
+					Self::neighbors(collection, item, keys).is_some()
+			},
+			_ => true,
+		}
+	}
+
+	fn validate_tiling(
+		collection: u32,
+		whole: &BoundedVec<u8, StringLimit>,
+		parts: &[BoundedVec<u8, StringLimit>],
+	) -> bool {
+		if collection != 0 && collection != 1 {
+			return true
+		}
+
+		let whole = match Self::demarcation(&whole[..]) {
+			Some(whole) if Self::is_valid_demarcation(&whole) => whole,
+			_ => return false,
+		};
+		let mut part_demarcations = Vec::with_capacity(parts.len());
+		for part in parts {
+			match Self::demarcation(&part[..]) {
+				Some(part) if Self::is_valid_demarcation(&part) => part_demarcations.push(part),
+				_ => return false,
+			}
+		}
+		is_exact_tiling(&whole, &part_demarcations)
+	}
+
+	// a read per grid cell and visited index entry, plus metadata of each checked neighbor
+	fn max_validation_reads() -> u32 {
+		LAND_MAX_INDEX_KEYS + LAND_MAX_INDEX_ENTRIES + LAND_MAX_OVERLAP_CHECKS
+	}
+
+	fn index_keys(collection: u32, _: u32, data: &BoundedVec<u8, StringLimit>) -> Vec<u64> {
+		if collection != 0 && collection != 1 {
+			return vec![]
+		}
+
+		match Self::demarcation(&data[..]) {
+			Some(demarcation) if !demarcation.is_empty() => grid_keys(&demarcation),
+			_ => vec![],
+		}
+	}
+
+	fn derived_attributes(
+		collection: u32,
+		_: u32,
+		data: &BoundedVec<u8, StringLimit>,
+	) -> Vec<(Vec<u8>, Vec<u8>)> {
+		if collection != 0 && collection != 1 {
+			return vec![]
+		}
+
+		match Self::demarcation(&data[..]) {
+			Some(demarcation) if !demarcation.is_empty() => {
+				let measurements = plot_measurements(&demarcation);
+				vec![
+					(AREA_ATTRIBUTE.to_vec(), measurements.area.encode()),
+					(PERIMETER_ATTRIBUTE.to_vec(), measurements.perimeter.encode()),
+					(CENTROID_ATTRIBUTE.to_vec(), measurements.centroid.encode()),
+				]
+			},
+			_ => vec![],
+		}
+	}
 }
 
 #[derive(
@@ -580,7 +1414,34 @@ pub struct ContractsCallFilter;
 
 impl Contains<RuntimeCall> for ContractsCallFilter {
 	fn contains(c: &RuntimeCall) -> bool {
-		matches!(c, RuntimeCall::LLM(pallet_llm::Call::force_transfer { .. }))
+		matches!(
+			c,
+			RuntimeCall::LLM(pallet_llm::Call::force_transfer { .. }) |
+				RuntimeCall::ContractsRegistry(
+					pallet_contracts_registry::Call::create_contract { .. } |
+						pallet_contracts_registry::Call::party_sign_contract { .. }
+				)
+		)
+	}
+}
+
+pub struct CourtCallFilter;
+
+impl Contains<RuntimeCall> for CourtCallFilter {
+	fn contains(c: &RuntimeCall) -> bool {
+		matches!(c,
+			RuntimeCall::LLM(pallet_llm::Call::force_transfer { .. }) |
+			RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { .. }) |
+			RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { .. }) |
+			RuntimeCall::Assets(pallet_assets::Call::transfer { .. }) |
+			RuntimeCall::Assets(pallet_assets::Call::transfer_keep_alive { .. }) |
+			RuntimeCall::Assets(pallet_assets::Call::freeze { .. }) |
+			RuntimeCall::Assets(pallet_assets::Call::thaw { .. }) |
+			RuntimeCall::Assets(pallet_assets::Call::freeze_amount { .. }) |
+			RuntimeCall::Assets(pallet_assets::Call::thaw_amount { .. }) |
+			RuntimeCall::ContractsRegistry(pallet_contracts_registry::Call::judge_sign_contract { .. }) |
+			RuntimeCall::ContractsRegistry(pallet_contracts_registry::Call::judge_sign_contract_hash { .. })
+		)
 	}
 }
 
@@ -1116,6 +1977,199 @@ mod ministry_of_finance_call_filter_tests {
 	}
 }
 
+#[cfg(test)]
+mod contracts_call_filter_tests {
+	use super::{ContractsCallFilter, RuntimeCall};
+	use frame_support::{PalletId, traits::Contains};
+	use sp_runtime::{traits::AccountIdConversion, AccountId32};
+
+	fn accid() -> AccountId32 {
+		PalletId(*b"12345678").into_account_truncating()
+	}
+
+	fn acc() -> sp_runtime::MultiAddress<AccountId32, ()> {
+		accid().into()
+	}
+
+	#[test]
+	fn allows_registry_and_llm_calls() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let calls = vec![
+				RuntimeCall::LLM(pallet_llm::Call::force_transfer { from: pallet_llm::LLMAccount::Liquid(accid()), to: pallet_llm::LLMAccount::Liquid(accid()), amount: 1u8.into() }),
+				RuntimeCall::ContractsRegistry(pallet_contracts_registry::Call::create_contract { data: vec![].try_into().unwrap(), parties: None }),
+				RuntimeCall::ContractsRegistry(pallet_contracts_registry::Call::party_sign_contract { contract_id: 0 }),
+			];
+			for call in calls {
+				assert!(ContractsCallFilter::contains(&call));
+			}
+		});
+	}
+
+	#[test]
+	fn disallows_asset_and_nft_transfers() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			// the chain extension dispatches these itself, contracts can't call them directly
+			let calls = vec![
+				RuntimeCall::Assets(pallet_assets::Call::transfer { id: 1u32.into(), target: acc(), amount: 1u8.into() }),
+				RuntimeCall::Assets(pallet_assets::Call::approve_transfer { id: 1u32.into(), delegate: acc(), amount: 1u8.into() }),
+				RuntimeCall::Assets(pallet_assets::Call::transfer_approved { id: 1u32.into(), owner: acc(), destination: acc(), amount: 1u8.into() }),
+				RuntimeCall::Nfts(pallet_nfts::Call::transfer { collection: 0, item: 0, dest: acc() }),
+			];
+			for call in calls {
+				assert!(!ContractsCallFilter::contains(&call));
+			}
+		});
+	}
+}
+
+#[cfg(test)]
+mod court_call_filter_tests {
+	use super::{AssetFreezeReason, CourtCallFilter, RuntimeCall};
+	use crate::{
+		constants::currency::DOLLARS, Assets, Balances, ContractsRegistry, RuntimeOrigin, LLM,
+	};
+	use frame_support::{
+		assert_ok,
+		traits::{fungible, fungibles, Contains},
+	};
+	use node_primitives::AccountId;
+	use pallet_contracts_registry::{ContentHash, HashAlgorithm};
+	use sp_runtime::{traits::Dispatchable, AccountId32, MultiAddress};
+
+	const ASSET: u32 = 100;
+
+	fn court() -> AccountId {
+		AccountId32::new([7u8; 32])
+	}
+
+	fn party() -> AccountId {
+		AccountId32::new([8u8; 32])
+	}
+
+	fn party_addr() -> MultiAddress<AccountId, ()> {
+		MultiAddress::Id(party())
+	}
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut ext = sp_io::TestExternalities::default();
+		ext.execute_with(|| {
+			assert_ok!(LLM::set_courts(RuntimeOrigin::root(), vec![court()].try_into().unwrap()));
+			for who in [court(), party()] {
+				assert_ok!(<Balances as fungible::Mutate<AccountId>>::mint_into(
+					&who,
+					100 * DOLLARS
+				));
+			}
+
+			// court is the owner, admin and freezer of the asset
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				ASSET.into(),
+				MultiAddress::Id(court()),
+				true,
+				1
+			));
+			for who in [court(), party()] {
+				assert_ok!(<Assets as fungibles::Mutate<AccountId>>::mint_into(ASSET, &who, 1_000));
+			}
+
+			assert_ok!(ContractsRegistry::add_judge(RuntimeOrigin::root(), court()));
+			for _ in 0..2 {
+				assert_ok!(ContractsRegistry::create_contract(
+					RuntimeOrigin::signed(party()),
+					b"contract".to_vec().try_into().unwrap(),
+					None,
+				));
+			}
+		});
+		ext
+	}
+
+	#[test]
+	fn court_can_dispatch_allowed_calls() {
+		new_test_ext().execute_with(|| {
+			let content_hash = ContentHash {
+				algorithm: HashAlgorithm::Blake2_256,
+				hash: sp_io::hashing::blake2_256(b"contract"),
+			};
+			let calls = vec![
+				RuntimeCall::LLM(pallet_llm::Call::force_transfer {
+					from: pallet_llm::LLMAccount::Locked(party()),
+					to: pallet_llm::LLMAccount::Locked(court()),
+					amount: 0,
+				}),
+				RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+					dest: party_addr(),
+					value: DOLLARS,
+				}),
+				RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+					dest: party_addr(),
+					value: DOLLARS,
+				}),
+				RuntimeCall::Assets(pallet_assets::Call::transfer {
+					id: ASSET.into(),
+					target: party_addr(),
+					amount: 10,
+				}),
+				RuntimeCall::Assets(pallet_assets::Call::transfer_keep_alive {
+					id: ASSET.into(),
+					target: party_addr(),
+					amount: 10,
+				}),
+				RuntimeCall::Assets(pallet_assets::Call::freeze {
+					id: ASSET.into(),
+					who: party_addr(),
+				}),
+				RuntimeCall::Assets(pallet_assets::Call::thaw {
+					id: ASSET.into(),
+					who: party_addr(),
+				}),
+				RuntimeCall::Assets(pallet_assets::Call::freeze_amount {
+					id: ASSET.into(),
+					who: party_addr(),
+					reason: AssetFreezeReason::CourtOrder,
+					amount: 100,
+				}),
+				RuntimeCall::Assets(pallet_assets::Call::thaw_amount {
+					id: ASSET.into(),
+					who: party_addr(),
+					reason: AssetFreezeReason::CourtOrder,
+				}),
+				RuntimeCall::ContractsRegistry(
+					pallet_contracts_registry::Call::judge_sign_contract { contract_id: 0 },
+				),
+				RuntimeCall::ContractsRegistry(
+					pallet_contracts_registry::Call::judge_sign_contract_hash {
+						contract_id: 1,
+						content_hash,
+					},
+				),
+			];
+			for call in calls {
+				assert!(CourtCallFilter::contains(&call));
+				assert_ok!(call.dispatch(RuntimeOrigin::signed(court())));
+			}
+		});
+	}
+
+	#[test]
+	fn disallows_other_calls() {
+		new_test_ext().execute_with(|| {
+			let calls = vec![
+				RuntimeCall::Assets(pallet_assets::Call::mint {
+					id: ASSET.into(),
+					beneficiary: party_addr(),
+					amount: 1,
+				}),
+				RuntimeCall::System(frame_system::Call::remark { remark: vec![] }),
+			];
+			for call in calls {
+				assert!(!CourtCallFilter::contains(&call));
+			}
+		});
+	}
+}
+
 #[cfg(test)]
 mod multiplier_tests {
 	use frame_support::{
@@ -1516,7 +2570,7 @@ mod multiplier_tests {
 	}
 
 	use super::{Coords, LandMetadataValidator, Metadata};
-	use codec::Encode;
+	use codec::{Decode, Encode};
 	use frame_support::{parameter_types, BoundedVec};
 	use pallet_nfts::traits::MetadataValidator;
 	use sp_core::ConstU32;
@@ -1535,7 +2589,7 @@ mod multiplier_tests {
 	}
 	#[test]
 	fn land_metadata_validator_is_sane() {
-		let good: BoundedVec<u8, ConstU32<1000>> = Metadata::<ConstU32<10>, ConstU32<10>> {
+		let good: BoundedVec<u8, ConstU32<1000>> = Metadata::<ConstU32<10>> {
 			r#type: Default::default(),
 			status: Default::default(),
 			demarcation: vec![
@@ -1553,7 +2607,7 @@ mod multiplier_tests {
 		.try_into()
 		.unwrap();
 		let not_enough_coords: BoundedVec<u8, ConstU32<1000>> =
-			Metadata::<ConstU32<10>, ConstU32<10>> {
+			Metadata::<ConstU32<10>> {
 				r#type: Default::default(),
 				status: Default::default(),
 				demarcation: vec![
@@ -1567,7 +2621,7 @@ mod multiplier_tests {
 			.try_into()
 			.unwrap();
 		let invalid_coord: BoundedVec<u8, ConstU32<1000>> =
-			Metadata::<ConstU32<10>, ConstU32<10>> {
+			Metadata::<ConstU32<10>> {
 				r#type: Default::default(),
 				status: Default::default(),
 				demarcation: vec![
@@ -1582,7 +2636,7 @@ mod multiplier_tests {
 			.try_into()
 			.unwrap();
 		let self_intersecting: BoundedVec<u8, ConstU32<1000>> =
-			Metadata::<ConstU32<10>, ConstU32<10>> {
+			Metadata::<ConstU32<10>> {
 				r#type: Default::default(),
 				status: Default::default(),
 				demarcation: vec![
@@ -1598,12 +2652,278 @@ mod multiplier_tests {
 			.try_into()
 			.unwrap();
 
-		assert!(LandMetadataValidator::<TestCoords>::validate_metadata(1, 1, &good));
-		assert!(LandMetadataValidator::<TestCoords>::validate_metadata(99, 0, &not_enough_coords));
-		assert!(!LandMetadataValidator::<TestCoords>::validate_metadata(0, 1, &not_enough_coords));
-		assert!(!LandMetadataValidator::<TestCoords>::validate_metadata(1, 1, &not_enough_coords));
-		assert!(!LandMetadataValidator::<TestCoords>::validate_metadata(1, 1, &invalid_coord));
-		assert!(!LandMetadataValidator::<TestCoords>::validate_metadata(1, 1, &self_intersecting));
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert!(LandMetadataValidator::<TestCoords>::validate_metadata(1, 1, &good));
+			assert!(LandMetadataValidator::<TestCoords>::validate_metadata(99, 0, &not_enough_coords));
+			assert!(!LandMetadataValidator::<TestCoords>::validate_metadata(0, 1, &not_enough_coords));
+			assert!(!LandMetadataValidator::<TestCoords>::validate_metadata(1, 1, &not_enough_coords));
+			assert!(!LandMetadataValidator::<TestCoords>::validate_metadata(1, 1, &invalid_coord));
+			assert!(!LandMetadataValidator::<TestCoords>::validate_metadata(1, 1, &self_intersecting));
+		});
+	}
+
+	fn square(lat: i64, long: i64, size: i64) -> Vec<Coords> {
+		vec![
+			Coords { lat, long },
+			Coords { lat: lat + size, long },
+			Coords { lat: lat + size, long: long + size },
+			Coords { lat, long: long + size },
+		]
+	}
+
+	#[test]
+	fn polygons_overlap_works() {
+		use super::polygons_overlap;
+		let a = square(0, 0, 10);
+		// disjoint
+		assert!(!polygons_overlap(&a, &square(20, 20, 10)));
+		// sharing an edge or a vertex
+		assert!(!polygons_overlap(&a, &square(10, 0, 10)));
+		assert!(!polygons_overlap(&a, &square(10, 10, 10)));
+		assert!(!polygons_overlap(&a, &square(10, 5, 10)));
+		// partial overlap
+		assert!(polygons_overlap(&a, &square(5, 5, 10)));
+		assert!(polygons_overlap(&a, &square(5, 0, 10)));
+		// containment, both ways
+		assert!(polygons_overlap(&a, &square(2, 2, 5)));
+		assert!(polygons_overlap(&square(2, 2, 5), &a));
+		assert!(polygons_overlap(&a, &square(0, 0, 5)));
+		// same plot, different starting point
+		let mut b = a.clone();
+		b.rotate_left(1);
+		assert!(polygons_overlap(&a, &b));
+		// same plot with an extra vertex in the middle of an edge
+		let mut c = a.clone();
+		c.push(Coords { lat: 0, long: 5 });
+		assert!(polygons_overlap(&a, &c));
+		assert!(polygons_overlap(&c, &a));
+		// crossing, no vertices inside
+		let cross = vec![
+			Coords { lat: -5, long: 3 },
+			Coords { lat: 15, long: 3 },
+			Coords { lat: 15, long: 6 },
+			Coords { lat: -5, long: 6 },
+		];
+		assert!(polygons_overlap(&a, &cross));
+	}
+
+	#[test]
+	fn polygon_contains_works() {
+		use super::polygon_contains;
+		let a = square(0, 0, 10);
+		// nested, touching the border or not
+		assert!(polygon_contains(&a, &square(2, 2, 5)));
+		assert!(polygon_contains(&a, &square(0, 0, 5)));
+		assert!(polygon_contains(&a, &a));
+		assert!(!polygon_contains(&square(2, 2, 5), &a));
+		// partial overlap
+		assert!(!polygon_contains(&a, &square(5, 5, 10)));
+		assert!(!polygon_contains(&a, &square(5, 0, 10)));
+		// disjoint and adjacent
+		assert!(!polygon_contains(&a, &square(20, 20, 5)));
+		assert!(!polygon_contains(&a, &square(10, 0, 10)));
+	}
+
+	#[test]
+	fn is_exact_tiling_works() {
+		use super::is_exact_tiling;
+		let whole = square(0, 0, 10);
+		let halves = vec![
+			vec![
+				Coords { lat: 0, long: 0 },
+				Coords { lat: 10, long: 0 },
+				Coords { lat: 10, long: 5 },
+				Coords { lat: 0, long: 5 },
+			],
+			vec![
+				Coords { lat: 0, long: 5 },
+				Coords { lat: 10, long: 5 },
+				Coords { lat: 10, long: 10 },
+				Coords { lat: 0, long: 10 },
+			],
+		];
+		assert!(is_exact_tiling(&whole, &halves));
+		let quarters =
+			vec![square(0, 0, 5), square(5, 0, 5), square(0, 5, 5), square(5, 5, 5)];
+		assert!(is_exact_tiling(&whole, &quarters));
+		// a gap
+		assert!(!is_exact_tiling(&whole, &quarters[..3]));
+		// overlapping parts
+		assert!(!is_exact_tiling(&whole, &[halves[0].clone(), halves[0].clone()]));
+		// part sticking out
+		assert!(!is_exact_tiling(&whole, &[halves[0].clone(), square(0, 5, 11)]));
+		// merge works the same way
+		assert!(is_exact_tiling(&square(0, 0, 20), &[
+			square(0, 0, 10),
+			square(10, 0, 10),
+			vec![
+				Coords { lat: 0, long: 10 },
+				Coords { lat: 20, long: 10 },
+				Coords { lat: 20, long: 20 },
+				Coords { lat: 0, long: 20 },
+			],
+		]));
+	}
+
+	#[test]
+	fn locate_point_works() {
+		use super::{locate_point, PointLocation};
+		let a = square(0, 0, 10);
+		assert!(locate_point(Coords { lat: 5, long: 5 }, &a) == PointLocation::Inside);
+		assert!(locate_point(Coords { lat: 0, long: 5 }, &a) == PointLocation::Boundary);
+		assert!(locate_point(Coords { lat: 10, long: 10 }, &a) == PointLocation::Boundary);
+		assert!(locate_point(Coords { lat: 11, long: 5 }, &a) == PointLocation::Outside);
+		assert!(locate_point(Coords { lat: 5, long: -1 }, &a) == PointLocation::Outside);
+	}
+
+	#[test]
+	fn plot_measurements_work() {
+		use super::{plot_measurements, PlotMeasurements};
+		// ~100m x ~70m, 0.0009° on each side near Liberland
+		assert_eq!(
+			plot_measurements(&square(45_7700000, 18_8850000, 9000)),
+			PlotMeasurements {
+				area: 7003,
+				perimeter: 340,
+				centroid: Coords { lat: 45_7704500, long: 18_8854500 },
+			}
+		);
+		// doesn't depend on orientation or starting point
+		let mut reversed = square(45_7700000, 18_8850000, 9000);
+		reversed.reverse();
+		reversed.rotate_left(2);
+		assert_eq!(plot_measurements(&reversed).area, 7003);
+		assert_eq!(plot_measurements(&reversed).perimeter, 340);
+		// a degree of longitude on the equator is ~111.3km long
+		let strip = vec![
+			Coords { lat: 0, long: 0 },
+			Coords { lat: 10, long: 0 },
+			Coords { lat: 10, long: 10_000_000 },
+			Coords { lat: 0, long: 10_000_000 },
+		];
+		assert_eq!(plot_measurements(&strip).perimeter, 222_639);
+		// triangle centroid
+		let triangle = vec![
+			Coords { lat: 0, long: 0 },
+			Coords { lat: 0, long: 30 },
+			Coords { lat: 30, long: 0 },
+		];
+		assert_eq!(plot_measurements(&triangle).centroid, Coords { lat: 10, long: 10 });
+	}
+
+	#[test]
+	fn land_metadata_enums_decode_from_empty_strings() {
+		use super::{LandStatus, LandType};
+		let coords: BoundedVec<Coords, ConstU32<10>> =
+			vec![Coords { lat: 1, long: 2 }].try_into().unwrap();
+		let empty: BoundedVec<u8, ConstU32<10>> = Default::default();
+		let old = (coords.clone(), empty.clone(), empty).encode();
+		let metadata = Metadata::<ConstU32<10>>::decode(&mut &old[..]).unwrap();
+		assert_eq!(metadata.r#type, LandType::Unspecified);
+		assert_eq!(metadata.status, LandStatus::Unspecified);
+		assert_eq!(metadata.demarcation, coords);
+	}
+
+	#[test]
+	fn land_metadata_translates_from_legacy_strings() {
+		use super::{LandStatus, LandType};
+		let coords: BoundedVec<Coords, ConstU32<10>> =
+			vec![Coords { lat: 1, long: 2 }].try_into().unwrap();
+		let legacy = |r#type: &[u8], status: &[u8]| {
+			(coords.clone(), r#type.to_vec(), status.to_vec()).encode()
+		};
+
+		let metadata =
+			Metadata::<ConstU32<10>>::from_legacy(&legacy(b"Residential", b"under construction"))
+				.unwrap();
+		assert_eq!(metadata.demarcation, coords);
+		assert_eq!(metadata.r#type, LandType::Residential);
+		assert_eq!(metadata.status, LandStatus::UnderConstruction);
+
+		let metadata =
+			Metadata::<ConstU32<10>>::from_legacy(&legacy(b"castle", b"developed")).unwrap();
+		assert_eq!(metadata.r#type, LandType::Unspecified);
+		assert_eq!(metadata.status, LandStatus::Developed);
+
+		// already in the current format, translating again is a no-op
+		assert!(Metadata::<ConstU32<10>>::from_legacy(&metadata.encode()).is_none());
+		assert!(Metadata::<ConstU32<10>>::from_legacy(&legacy(b"", b"")).is_none());
+	}
+
+	#[test]
+	fn grid_keys_cover_bounding_box() {
+		use super::{grid_key, grid_keys, LAND_GRID_CELL_SIZE};
+		let cell = LAND_GRID_CELL_SIZE;
+		assert_eq!(grid_keys(&square(1, 1, 10)), vec![grid_key((0, 0))]);
+		assert_eq!(
+			grid_keys(&square(cell - 1, 1, 10)),
+			vec![grid_key((0, 0)), grid_key((1, 0))]
+		);
+		assert_eq!(grid_keys(&square(cell * 3 + 1, cell * 5 + 1, cell)).len(), 4);
+	}
+
+	#[test]
+	fn overlap_check_is_bounded() {
+		use super::LAND_GRID_CELL_SIZE;
+		let cell = LAND_GRID_CELL_SIZE;
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert!(!LandMetadataValidator::<TestCoords>::overlaps_existing_plot(
+				0,
+				1,
+				&square(0, 0, cell * 10)
+			));
+			// 13 x 13 cells
+			assert!(LandMetadataValidator::<TestCoords>::overlaps_existing_plot(
+				0,
+				1,
+				&square(0, 0, cell * 12)
+			));
+		});
+	}
+
+	#[test]
+	fn crowded_cells_are_rejected_separately() {
+		use super::{grid_key, LAND_GRID_CELL_SIZE, LAND_MAX_OVERLAP_CHECKS};
+		let cell = LAND_GRID_CELL_SIZE;
+		let plot = |demarcation: Vec<Coords>| -> BoundedVec<u8, ConstU32<1000>> {
+			Metadata::<ConstU32<100>> {
+				r#type: Default::default(),
+				status: Default::default(),
+				demarcation: demarcation.try_into().unwrap(),
+			}
+			.encode()
+			.try_into()
+			.unwrap()
+		};
+		let index = |item: u32, c: (i64, i64)| {
+			pallet_nfts::MetadataIndex::<crate::Runtime>::insert((0, grid_key(c), item), ());
+		};
+		let within_limits = |item: u32, data: &BoundedVec<u8, ConstU32<1000>>| {
+			LandMetadataValidator::<TestCoords>::within_validation_limits(0, item, data)
+		};
+		sp_io::TestExternalities::default().execute_with(|| {
+			let small = plot(square(10, 10, 10));
+			// a neighbor spanning all 4 cells of the big plot is counted once
+			let big = plot(square(cell / 2, cell / 2, cell));
+			for c in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+				index(0, c);
+			}
+			for item in 1..LAND_MAX_OVERLAP_CHECKS {
+				index(item, (0, 0));
+			}
+			assert!(within_limits(1_000, &small));
+			assert!(within_limits(1_000, &big));
+			// the plot itself isn't its own neighbor
+			index(1_000, (0, 0));
+			assert!(within_limits(1_000, &small));
+
+			// more than 100 distinct plots in the cell
+			index(LAND_MAX_OVERLAP_CHECKS, (0, 0));
+			assert!(!within_limits(1_000, &small));
+			assert!(!within_limits(1_000, &big));
+			// plots in other cells aren't affected
+			assert!(within_limits(1_000, &plot(square(cell * 5, cell * 5, 10))));
+		});
 	}
 
 	use frame_system::{EnsureRoot, RawOrigin};
@@ -1644,3 +2964,166 @@ mod multiplier_tests {
 		);
 	}
 }
+
+#[cfg(test)]
+mod llm_fee_adapter_tests {
+	use super::{LLMFeeAdapter, NativeOrAssetId};
+	use crate::{
+		constants::{
+			currency::{CENTS, DOLLARS},
+			llm::GRAINS_IN_LLM,
+		},
+		AssetConversion, Assets, Balances, RuntimeCall, RuntimeOrigin, LLM,
+	};
+	use frame_support::{
+		assert_ok,
+		dispatch::{DispatchInfo, PostDispatchInfo},
+		traits::{fungible, fungibles},
+	};
+	use node_primitives::{AccountId, Balance};
+	use pallet_asset_conversion_tx_payment::OnChargeAssetTransaction;
+	use sp_runtime::{AccountId32, FixedU128, MultiAddress};
+
+	const INITIAL_LLM: Balance = 1_000 * GRAINS_IN_LLM;
+
+	fn payer() -> AccountId {
+		AccountId32::new([1u8; 32])
+	}
+
+	fn llm_balance(who: &AccountId) -> Balance {
+		<Assets as fungibles::Inspect<AccountId>>::balance(LLM::llm_id(), who)
+	}
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut ext = sp_io::TestExternalities::default();
+		ext.execute_with(|| {
+			let treasury = LLM::get_llm_treasury_account();
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				LLM::llm_id().into(),
+				MultiAddress::Id(treasury.clone()),
+				true,
+				1,
+			));
+			for who in [payer(), treasury] {
+				assert_ok!(<Assets as fungibles::Mutate<AccountId>>::mint_into(
+					LLM::llm_id(),
+					&who,
+					INITIAL_LLM,
+				));
+			}
+		});
+		ext
+	}
+
+	fn withdraw(fee: Balance) -> (Balance, Balance) {
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		let (_, native_fee, llm_fee) = LLMFeeAdapter::withdraw_fee(
+			&payer(),
+			&call,
+			&DispatchInfo::default(),
+			LLM::llm_id(),
+			fee,
+			0,
+		)
+		.unwrap();
+		(native_fee, llm_fee)
+	}
+
+	fn correct(corrected_fee: Balance, native_fee: Balance, llm_fee: Balance) -> Balance {
+		LLMFeeAdapter::correct_and_deposit_fee(
+			&payer(),
+			&DispatchInfo::default(),
+			&PostDispatchInfo::default(),
+			corrected_fee,
+			0,
+			None,
+			native_fee,
+			LLM::llm_id(),
+			llm_fee,
+		)
+		.unwrap()
+	}
+
+	#[test]
+	fn fee_is_charged_at_the_fee_rate() {
+		new_test_ext().execute_with(|| {
+			let treasury = LLM::get_llm_treasury_account();
+			assert_ok!(LLM::set_fee_rate(
+				RuntimeOrigin::root(),
+				Some(FixedU128::from_rational(1, 2))
+			));
+
+			let (native_fee, llm_fee) = withdraw(1000);
+			assert_eq!((native_fee, llm_fee), (1000, 500));
+			assert_eq!(llm_balance(&payer()), INITIAL_LLM - 500);
+			assert_eq!(llm_balance(&treasury), INITIAL_LLM + 500);
+
+			// overestimated fee is refunded from the treasury
+			assert_eq!(correct(600, native_fee, llm_fee), 300);
+			assert_eq!(llm_balance(&payer()), INITIAL_LLM - 300);
+			assert_eq!(llm_balance(&treasury), INITIAL_LLM + 300);
+		});
+	}
+
+	#[test]
+	fn fee_is_charged_at_the_pool_price_without_fee_rate() {
+		new_test_ext().execute_with(|| {
+			let treasury = LLM::get_llm_treasury_account();
+			let native = NativeOrAssetId::Native;
+			let llm = NativeOrAssetId::Asset(LLM::llm_id());
+			let fee = CENTS;
+			assert_ok!(<Balances as fungible::Mutate<AccountId>>::mint_into(
+				&payer(),
+				1_000 * DOLLARS,
+			));
+			assert_ok!(AssetConversion::create_pool(
+				RuntimeOrigin::signed(payer()),
+				native,
+				llm
+			));
+			assert_ok!(AssetConversion::add_liquidity(
+				RuntimeOrigin::signed(payer()),
+				native,
+				llm,
+				200 * DOLLARS,
+				100 * GRAINS_IN_LLM,
+				0,
+				0,
+				payer(),
+			));
+			let expected =
+				AssetConversion::quote_price_tokens_for_exact_tokens(llm, native, fee, true)
+					.unwrap();
+			// ~2 LLD per LLM, plus the pool's fee
+			assert!(expected > fee / 2 && expected < fee);
+			let payer_before = llm_balance(&payer());
+
+			let (native_fee, llm_fee) = withdraw(fee);
+			assert_eq!((native_fee, llm_fee), (fee, expected));
+			assert_eq!(llm_balance(&payer()), payer_before - expected);
+			assert_eq!(llm_balance(&treasury), INITIAL_LLM + expected);
+
+			// nothing is refunded if the estimate was right
+			assert_eq!(correct(fee, native_fee, llm_fee), expected);
+			assert_eq!(llm_balance(&treasury), INITIAL_LLM + expected);
+		});
+	}
+
+	#[test]
+	fn fee_fails_without_rate_and_pool() {
+		new_test_ext().execute_with(|| {
+			let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+			assert!(LLMFeeAdapter::withdraw_fee(
+				&payer(),
+				&call,
+				&DispatchInfo::default(),
+				LLM::llm_id(),
+				1000,
+				0,
+			)
+			.is_err());
+			assert_eq!(llm_balance(&payer()), INITIAL_LLM);
+		});
+	}
+}
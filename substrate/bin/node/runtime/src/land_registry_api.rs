@@ -0,0 +1,42 @@
+//! Runtime API definition for land registry.
+
+use crate::impls::{Coords, PlotMeasurements};
+use codec::Codec;
+use node_primitives::Balance;
+use pallet_nfts::{Lease, LeaseOffer, PendingTransfer, Rent, TitleRecord};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait LandRegistryApi<AccountId, BlockNumber>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// Returns all approved transfers of an item, oldest first.
+		fn title_history(collection: u32, item: u32) -> Vec<TitleRecord<AccountId, BlockNumber>>;
+
+		/// Returns transfer of an item awaiting approval, if any.
+		fn pending_transfer(collection: u32, item: u32) -> Option<PendingTransfer<AccountId, BlockNumber>>;
+
+		/// Returns the plot in the land collection that contains given coordinates.
+		fn plot_at(collection: u32, coords: Coords) -> Option<u32>;
+
+		/// Returns area, perimeter and centroid of a plot, as stored in its attributes.
+		fn plot_measurements(collection: u32, item: u32) -> Option<PlotMeasurements>;
+
+		/// Returns the asset representing shares of a fractionalized item.
+		fn item_shares(collection: u32, item: u32) -> Option<u32>;
+
+		/// Returns the item represented by shares asset.
+		fn shares_item(asset: u32) -> Option<(u32, u32)>;
+
+		/// Returns the lease of an item, including a lapsed one that wasn't ended yet.
+		fn lease(collection: u32, item: u32) -> Option<Lease<AccountId, Rent<Balance, u32, Balance>, BlockNumber>>;
+
+		/// Returns the lease offered for an item, if any.
+		fn lease_offer(collection: u32, item: u32) -> Option<LeaseOffer<AccountId, Rent<Balance, u32, Balance>, BlockNumber>>;
+
+		/// Returns the current user of an item - the lessee of an active lease.
+		fn item_user(collection: u32, item: u32) -> Option<AccountId>;
+	}
+}
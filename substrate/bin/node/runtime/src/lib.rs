@@ -44,7 +44,7 @@ use frame_support::{
         tokens::nonfungibles_v2::Inspect,
 		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU16, ConstU32, MapSuccess,
 		Currency, EitherOf, EitherOfDiverse, Imbalance, InstanceFilter,
-		KeyOwnerProofSystem, LockIdentifier, OnUnbalanced
+		KeyOwnerProofSystem, LockIdentifier, Nothing, OnUnbalanced
 	},
 	weights::{
 		constants::{
@@ -77,7 +77,7 @@ use sp_runtime::{
 	generic, impl_opaque_keys,
 	traits::{
 		self, BlakeTwo256, Block as BlockT, Bounded, NumberFor, OpaqueKeys,
-		SaturatedConversion, StaticLookup, AccountIdConversion, AccountIdLookup,
+		SaturatedConversion, StaticLookup, AccountIdConversion, AccountIdLookup, Replace,
 	},
 	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, FixedPointNumber, Perbill, Percent, Permill, Perquintill,
@@ -112,7 +112,7 @@ use impls::{
 	Author, ToAccountId,
 	IdentityCallFilter, RegistryCallFilter, NftsCallFilter, OnLLMPoliticsUnlock,
 	ContainsMember, CouncilAccountCallFilter, EnsureCmp, ContractsCallFilter, SenateAccountCallFilter,
-	MinistryOfFinanceCallFilter,
+	MinistryOfFinanceCallFilter, CourtCallFilter, LLMFeeAdapter,
 };
 
 /// Constant values used within the runtime.
@@ -123,8 +123,14 @@ use sp_runtime::generic::Era;
 /// Generated voter bag information.
 mod voter_bags;
 
-/// Runtime API definition for assets.
-pub mod assets_api;
+/// Runtime API definition for contracts registry.
+pub mod contracts_registry_api;
+
+/// Runtime API definition for land registry.
+pub mod land_registry_api;
+
+/// Democracy tracks and the legislation origins checked against them.
+pub mod tracks;
 
 // Make the WASM binary available.
 #[cfg(feature = "std")]
@@ -538,8 +544,7 @@ impl pallet_transaction_payment::Config for Runtime {
 impl pallet_asset_conversion_tx_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Fungibles = Assets;
-	type OnChargeAssetTransaction =
-		pallet_asset_conversion_tx_payment::AssetConversionAdapter<Balances, AssetConversion>;
+	type OnChargeAssetTransaction = LLMFeeAdapter;
 }
 
 parameter_types! {
@@ -824,7 +829,13 @@ parameter_types! {
 	pub const MinimumDeposit: Balance = 10 * GRAINS_IN_LLM;
 	pub const CooloffPeriod: BlockNumber = 7 * DAYS;
 	pub const MaxProposals: u32 = 100;
-	pub const ProposalFeeAmount: Balance = 100 * DOLLARS;
+	// Referenda on a track use the track's quorum instead, see `tracks`
+	pub const RootQuorum: pallet_democracy::Quorum = pallet_democracy::Quorum::none();
+	pub const RichQuorum: pallet_democracy::Quorum = pallet_democracy::Quorum {
+		llm_approval: Perbill::from_parts(666_666_667),
+		voter_approval: Perbill::from_parts(666_666_667),
+		participation: Perbill::from_parts(0),
+	};
 }
 
 impl pallet_democracy::Config for Runtime {
@@ -891,7 +902,10 @@ impl pallet_democracy::Config for Runtime {
 	type DelegateeFilter = ContainsMember<Runtime, CouncilCollective>;
 
 	type ProposalFee = CouncilAccount;
-	type ProposalFeeAmount = ProposalFeeAmount;
+	type Tracks = tracks::TracksInfo;
+	type RootTracks = tracks::RootTracks;
+	type RootQuorum = RootQuorum;
+	type RichQuorum = RichQuorum;
 }
 
 parameter_types! {
@@ -1045,10 +1059,13 @@ impl pallet_treasury::Config for Runtime {
 	type SpendFunds = ();
 	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
 	type MaxApprovals = MaxApprovals;
-	type SpendOrigin = EnsureWithSuccess<
-		EnsureRootOrHalfCouncil,
-		AccountId,
-		MaxBalance
+	type SpendOrigin = EitherOf<
+		EnsureWithSuccess<EnsureRootOrHalfCouncil, AccountId, MaxBalance>,
+		EnsureWithSuccess<
+			pallet_democracy::EnsureTrack<Runtime, { tracks::TREASURY_SPEND }>,
+			AccountId,
+			MaxBalance,
+		>,
 	>;
 }
 
@@ -1086,7 +1103,7 @@ impl pallet_contracts::Config for Runtime {
 	type CallStack = [pallet_contracts::Frame<Self>; 5];
 	type WeightPrice = pallet_transaction_payment::Pallet<Self>;
 	type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
-	type ChainExtension = liberland_extension_runtime::LiberlandExtension;
+	type ChainExtension = liberland_extension_runtime::LiberlandExtension<CourtCallFilter>;
 	type Schedule = Schedule;
 	type AddressGenerator = pallet_contracts::DefaultAddressGenerator;
 	type MaxCodeLen = ConstU32<{ 123 * 1024 }>;
@@ -1264,8 +1281,17 @@ impl pallet_assets::Config for Runtime {
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
 	#[cfg(feature = "runtime-benchmarks")]
-	type BenchmarkHelper = ();
+	type BenchmarkHelper = impls::AssetsBenchmarkHelper;
 	type Citizenship = LLM;
+	type Companies = impls::RegisteredCompanies;
+	type MaxActiveDistributions = ConstU32<10>;
+	type MaxCheckpoints = ConstU32<100>;
+	type MaxOpenResolutions = ConstU32<10>;
+	type MaxVestingSchedules = ConstU32<28>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type FreezeIdentifier = impls::AssetFreezeReason;
+	type MaxHolds = ConstU32<4>;
+	type MaxFreezes = ConstU32<4>;
 }
 
 ord_parameter_types! {
@@ -1294,6 +1320,15 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type Citizenship = LLM;
+	type Companies = Nothing;
+	type MaxActiveDistributions = ConstU32<10>;
+	type MaxCheckpoints = ConstU32<100>;
+	type MaxOpenResolutions = ConstU32<10>;
+	type MaxVestingSchedules = ConstU32<28>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type FreezeIdentifier = impls::AssetFreezeReason;
+	type MaxHolds = ConstU32<4>;
+	type MaxFreezes = ConstU32<4>;
 }
 
 parameter_types! {
@@ -1371,6 +1406,7 @@ parameter_types! {
 	pub const MaxDeadlineDuration: BlockNumber = 12 * 30 * DAYS;
 	pub Features: PalletFeatures = PalletFeatures::all_enabled();
 	pub const MaxAttributesPerCall: u32 = 10;
+	pub const NftSharesPalletId: PalletId = PalletId(*b"nft/shar");
 	pub const LLCoords: (impls::Coords, impls::Coords) = (
 		impls::Coords {
 			lat: 45_7686480,
@@ -1412,6 +1448,11 @@ impl pallet_nfts::Config for Runtime {
 	type Locker = ();
 	type Citizenship = LLM;
 	type MetadataValidator = impls::LandMetadataValidator<LLCoords>;
+	type TransferApprover = impls::OfficeClerkTransferApprover;
+	type Shares = impls::AssetShares;
+	type RentAssets = impls::AssetShares;
+	type MaxMetadataIndexKeys = ConstU32<{ impls::LAND_MAX_INDEX_KEYS }>;
+	type MaxItemParts = ConstU32<16>;
 }
 
 impl pallet_transaction_storage::Config for Runtime {
@@ -1426,15 +1467,28 @@ impl pallet_transaction_storage::Config for Runtime {
 		ConstU32<{ pallet_transaction_storage::DEFAULT_MAX_TRANSACTION_SIZE }>;
 }
 
+parameter_types! {
+	pub const LawTier: pallet_liberland_legislation::types::LegislationTier =
+		pallet_liberland_legislation::types::LegislationTier::Law;
+}
+
 impl pallet_liberland_legislation::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Citizenship = LLM;
-	type ConstitutionOrigin = pallet_democracy::EnsureReferendumProportionAtLeast<Self, 2, 3>;
-	type InternationalTreatyOrigin = EnsureRootOrHalfCouncil;
+	type ConstitutionOrigin =
+		pallet_democracy::EnsureTrackProportionAtLeast<Self, { tracks::CONSTITUTION }, 2, 3>;
+	type InternationalTreatyOrigin = EitherOfDiverse<
+		EnsureRootOrHalfCouncil,
+		pallet_democracy::EnsureTrack<Self, { tracks::INTERNATIONAL_TREATY }>,
+	>;
 	type LowTierDeleteOrigin = EitherOf<
 		EnsureRoot<AccountId>,
 		EnsureSenateMajority
 	>;
+	type LowTierOrigin = EitherOf<
+		MapSuccess<EnsureRoot<AccountId>, Replace<LawTier>>,
+		tracks::EnsureLegislationTrack,
+	>;
 	type LLInitializer = LiberlandInitializer;
 	type WeightInfo = pallet_liberland_legislation::weights::SubstrateWeight<Runtime>;
 }
@@ -1631,6 +1685,9 @@ impl pallet_contracts_registry::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxContractContentLen = ConstU32<{ 2u32 * 1024u32 * 1024u32 }>;
 	type MaxParties = ConstU32<16u32>;
+	type MaxBlobReferences = ConstU32<8u32>;
+	type MaxBlobReferenceLen = ConstU32<128u32>;
+	type MaxJudgeInfoLen = ConstU32<128u32>;
 	type AddJudgeOrigin = EnsureRoot<AccountId>;
 	type SubmitOrigin = EnsureSigned<AccountId>;
 	type WeightInfo = pallet_contracts_registry::weights::SubstrateWeight<Runtime>;
@@ -1871,6 +1928,25 @@ pub type Executive = frame_executive::Executive<
 type Migrations = (
 	// Migrations for spec version 27 - delete when bumping to v28
 	crate::migrations::add_ministry_of_finance_office_pallet::Migration<Runtime>,
+	crate::migrations::translate_land_metadata::Migration<Runtime>,
+	crate::migrations::index_land_plots::Migration<Runtime>,
+	pallet_assets::migration::v2::MigrateToV2<Runtime>,
+	pallet_assets::migration::v2::MigrateToV2<Runtime, Instance2>,
+	pallet_assets::migration::v3::MigrateToV3<Runtime>,
+	pallet_assets::migration::v3::MigrateToV3<Runtime, Instance2>,
+	pallet_assets::migration::v4::MigrateToV4<Runtime>,
+	pallet_assets::migration::v4::MigrateToV4<Runtime, Instance2>,
+	pallet_assets::migration::v5::MigrateToV5<
+		Runtime,
+		(),
+		crate::migrations::register_pool_accounts::PoolAccounts,
+	>,
+	pallet_assets::migration::v5::MigrateToV5<Runtime, Instance2>,
+	pallet_nfts::migration::v2::MigrateToV2<
+		Runtime,
+		(),
+		crate::migrations::require_land_transfer_approval::LandCollections,
+	>,
 );
 
 type EventRecord = frame_system::EventRecord<
@@ -2080,16 +2156,116 @@ impl_runtime_apis! {
 		}
 	}
 
-	impl assets_api::AssetsApi<
+	#[api_version(2)]
+	impl pallet_assets_runtime_api::AssetsApi<
 		Block,
 		AccountId,
 		Balance,
 		u32,
+		Balance,
+		BlockNumber,
+		RuntimeHoldReason,
+		impls::AssetFreezeReason,
 	> for Runtime
 	{
 		fn account_balances(account: AccountId) -> Vec<(u32, Balance)> {
 			Assets::account_balances(account)
 		}
+
+		fn asset_details(asset: u32) -> Option<pallet_assets::AssetDetails<Balance, AccountId, Balance>> {
+			Assets::asset_details(asset)
+		}
+
+		fn asset_metadata(asset: u32) -> Option<pallet_assets::AssetMetadata<Balance, Vec<u8>>> {
+			Assets::asset_metadata(asset)
+		}
+
+		fn asset_parameters(asset: u32) -> pallet_assets::AssetParameters<BlockNumber> {
+			Assets::asset_parameters(asset)
+		}
+
+		fn related_company(asset: u32) -> Option<u32> {
+			Assets::related_company(asset)
+		}
+
+		fn holders(asset: u32, start_after: Option<AccountId>, limit: u32) -> Vec<(AccountId, Balance)> {
+			Assets::holders(asset, start_after, limit)
+		}
+
+		fn cap_table(company: u32) -> Vec<pallet_assets_runtime_api::CapTableEntry<AccountId, Balance, u32>> {
+			Assets::cap_table(company)
+				.into_iter()
+				.map(|(asset, holder, balance, percentage)| {
+					let display_name = Identity::identity(&holder).and_then(|r| match r.info.display {
+						pallet_identity::Data::Raw(name) => Some(name.into_inner()),
+						_ => None,
+					});
+					pallet_assets_runtime_api::CapTableEntry {
+						asset,
+						holder,
+						balance,
+						percentage,
+						display_name,
+					}
+				})
+				.collect()
+		}
+
+		fn holds(asset: u32, account: AccountId) -> Vec<(RuntimeHoldReason, Balance)> {
+			Assets::holds(asset, account)
+		}
+
+		fn freezes(asset: u32, account: AccountId) -> Vec<(impls::AssetFreezeReason, Balance)> {
+			Assets::freezes(asset, account)
+		}
+	}
+
+	impl contracts_registry_api::ContractsRegistryApi<Block> for Runtime {
+		fn content_hash(contract_id: pallet_contracts_registry::ContractIndex) -> Option<pallet_contracts_registry::ContentHash> {
+			ContractsRegistry::content_hash(contract_id)
+		}
+
+		fn verify_content(contract_id: pallet_contracts_registry::ContractIndex, document: Vec<u8>) -> bool {
+			ContractsRegistry::verify_content(contract_id, &document)
+		}
+	}
+
+	impl land_registry_api::LandRegistryApi<Block, AccountId, BlockNumber> for Runtime {
+		fn title_history(collection: u32, item: u32) -> Vec<pallet_nfts::TitleRecord<AccountId, BlockNumber>> {
+			Nfts::title_history(collection, item)
+		}
+
+		fn pending_transfer(collection: u32, item: u32) -> Option<pallet_nfts::PendingTransfer<AccountId, BlockNumber>> {
+			pallet_nfts::PendingTransfers::<Runtime>::get(collection, item)
+		}
+
+		fn plot_at(collection: u32, coords: impls::Coords) -> Option<u32> {
+			impls::LandMetadataValidator::<LLCoords>::plot_at(collection, coords)
+		}
+
+		fn plot_measurements(collection: u32, item: u32) -> Option<impls::PlotMeasurements> {
+			impls::LandMetadataValidator::<LLCoords>::plot_measurements(collection, item)
+		}
+
+		fn item_shares(collection: u32, item: u32) -> Option<u32> {
+			Nfts::item_shares(collection, item)
+		}
+
+		fn shares_item(asset: u32) -> Option<(u32, u32)> {
+			Nfts::shares_item(asset)
+		}
+
+		fn lease(collection: u32, item: u32) -> Option<pallet_nfts::Lease<AccountId, pallet_nfts::Rent<Balance, u32, Balance>, BlockNumber>> {
+			Nfts::lease(collection, item)
+		}
+
+		fn lease_offer(collection: u32, item: u32) -> Option<pallet_nfts::LeaseOffer<AccountId, pallet_nfts::Rent<Balance, u32, Balance>, BlockNumber>> {
+			Nfts::lease_offer(collection, item)
+		}
+
+		fn item_user(collection: u32, item: u32) -> Option<AccountId> {
+			Nfts::item_user(collection, item)
+		}
 	}
 
 	impl pallet_contracts::ContractsApi<Block, AccountId, Balance, BlockNumber, Hash, EventRecord> for Runtime
@@ -36,4 +36,157 @@ pub mod add_ministry_of_finance_office_pallet {
 			Ok(())
 		}
 	}
-}
\ No newline at end of file
+}
+pub mod translate_land_metadata {
+	use super::*;
+	use crate::impls::Metadata;
+
+	/// Translates `type` and `status` of land plots metadata from strings to enums. Metadata
+	/// already in the current format is left untouched, so running it again is a no-op.
+	pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+	impl OnRuntimeUpgrade for Migration<Runtime> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok(().encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut weight = Weight::zero();
+
+			for collection in [0u32, 1u32] {
+				let items: Vec<u32> =
+					pallet_nfts::ItemMetadataOf::<Runtime>::iter_key_prefix(collection).collect();
+				for item in items {
+					weight = weight.saturating_add(DbWeight::get().reads(2));
+					let metadata = match Nfts::item_metadata(collection, item)
+						.and_then(|data| Metadata::<ConstU32<100>>::from_legacy(&data[..]))
+					{
+						Some(metadata) => metadata,
+						None => continue,
+					};
+					let translated = BoundedVec::try_from(metadata.encode())
+						.map_err(|_| DispatchError::Other("metadata too long"))
+						.and_then(|data| Nfts::translate_item_metadata(collection, item, data));
+					match translated {
+						Ok(()) => weight = weight.saturating_add(DbWeight::get().writes(1)),
+						Err(e) => log::error!(
+							"Failed to translate metadata of land plot {}/{}: {:?}",
+							collection,
+							item,
+							e
+						),
+					}
+				}
+			}
+
+			weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			for collection in [0u32, 1u32] {
+				for item in pallet_nfts::ItemMetadataOf::<Runtime>::iter_key_prefix(collection) {
+					let data = Nfts::item_metadata(collection, item).unwrap_or_default();
+					ensure!(
+						Metadata::<ConstU32<100>>::from_legacy(&data[..]).is_none(),
+						"land plot metadata left in the legacy format"
+					);
+				}
+			}
+			Ok(())
+		}
+	}
+}
+
+pub mod index_land_plots {
+	use super::*;
+
+	pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+	impl OnRuntimeUpgrade for Migration<Runtime> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok(().encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut weight = Weight::zero();
+
+			for collection in [0u32, 1u32] {
+				let items: Vec<u32> =
+					pallet_nfts::ItemMetadataOf::<Runtime>::iter_key_prefix(collection).collect();
+				for item in items {
+					if let Err(e) = Nfts::reindex_item_metadata(collection, item) {
+						log::error!(
+							"Failed to index or measure land plot {}/{}: {:?}",
+							collection,
+							item,
+							e
+						);
+					}
+					let keys =
+						pallet_nfts::ItemMetadataIndexKeys::<Runtime>::decode_len(collection, item)
+							.unwrap_or(0) as u64;
+					weight = weight.saturating_add(DbWeight::get().reads_writes(3, keys + 1));
+					// area, perimeter and centroid attributes, each updating the collection
+					weight = weight.saturating_add(DbWeight::get().reads_writes(6, 6));
+				}
+			}
+
+			weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			for collection in [0u32, 1u32] {
+				for item in pallet_nfts::ItemMetadataOf::<Runtime>::iter_key_prefix(collection) {
+					ensure!(
+						pallet_nfts::ItemMetadataIndexKeys::<Runtime>::contains_key(collection, item),
+						"land plot left out of the index"
+					);
+				}
+			}
+			Ok(())
+		}
+	}
+}
+
+pub mod register_pool_accounts {
+	use super::*;
+	use pallet_asset_conversion::NativeOrAssetId;
+
+	/// Accounts of existing liquidity pools, paired with each of their non-native assets. Used
+	/// to register them as system accounts of these assets in `pallet_assets` v5 migration, so
+	/// that holder requirements are checked for pool users instead of the pools.
+	pub struct PoolAccounts;
+
+	type AssetId = <Runtime as pallet_assets::Config>::AssetId;
+
+	impl Get<Vec<(AssetId, AccountId)>> for PoolAccounts {
+		fn get() -> Vec<(AssetId, AccountId)> {
+			pallet_asset_conversion::Pools::<Runtime>::iter_keys()
+				.flat_map(|pool_id| {
+					let pool_account = AssetConversion::get_pool_account(&pool_id);
+					[pool_id.0, pool_id.1].into_iter().filter_map(move |asset| match asset {
+						NativeOrAssetId::Asset(asset) => Some((asset, pool_account.clone())),
+						NativeOrAssetId::Native => None,
+					})
+				})
+				.collect()
+		}
+	}
+}
+
+pub mod require_land_transfer_approval {
+	use super::*;
+
+	/// Land collections, which require transfer approval since `pallet_nfts` v2.
+	pub struct LandCollections;
+
+	impl Get<Vec<u32>> for LandCollections {
+		fn get() -> Vec<u32> {
+			sp_std::vec![0, 1]
+		}
+	}
+}
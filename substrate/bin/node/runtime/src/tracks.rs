@@ -0,0 +1,188 @@
+//! Democracy tracks and legislation origins derived from them.
+
+use crate::{constants::currency::DOLLARS, EnactmentPeriod, Runtime, RuntimeOrigin, VotingPeriod};
+use frame_support::traits::{Contains, EnsureOrigin};
+use node_primitives::{Balance, BlockNumber};
+use pallet_democracy::{Quorum, TrackId, TrackInfo, VoteThreshold};
+use pallet_liberland_legislation::types::LegislationTier;
+use sp_runtime::Perbill;
+
+pub const CONSTITUTION: TrackId = 0;
+pub const INTERNATIONAL_TREATY: TrackId = 1;
+pub const LAW: TrackId = 2;
+pub const TIER3: TrackId = 3;
+pub const TIER4: TrackId = 4;
+pub const TIER5: TrackId = 5;
+pub const DECISION: TrackId = 6;
+pub const TREASURY_SPEND: TrackId = 10;
+pub const RUNTIME_UPGRADE: TrackId = 11;
+
+/// Two thirds of LLM votes and of voters must be ayes.
+const SUPERMAJORITY_QUORUM: Quorum = Quorum {
+	llm_approval: Perbill::from_parts(666_666_667),
+	voter_approval: Perbill::from_parts(666_666_667),
+	participation: Perbill::from_parts(0),
+};
+
+/// Like `SUPERMAJORITY_QUORUM`, but a tenth of all citizens must vote too.
+const CONSTITUTION_QUORUM: Quorum =
+	Quorum { participation: Perbill::from_percent(10), ..SUPERMAJORITY_QUORUM };
+
+static TRACKS: [(TrackId, TrackInfo<Balance, BlockNumber>); 9] = [
+	(
+		CONSTITUTION,
+		TrackInfo {
+			name: "constitution",
+			voting_period: 2 * VotingPeriod::get(),
+			enactment_period: 2 * EnactmentPeriod::get(),
+			threshold: VoteThreshold::SuperMajorityApprove,
+			quorum: CONSTITUTION_QUORUM,
+			max_deciding: 1,
+			proposal_fee: 1000 * DOLLARS,
+		},
+	),
+	(
+		INTERNATIONAL_TREATY,
+		TrackInfo {
+			name: "international_treaty",
+			voting_period: VotingPeriod::get(),
+			enactment_period: EnactmentPeriod::get(),
+			threshold: VoteThreshold::SuperMajorityApprove,
+			quorum: SUPERMAJORITY_QUORUM,
+			max_deciding: 5,
+			proposal_fee: 500 * DOLLARS,
+		},
+	),
+	(
+		LAW,
+		TrackInfo {
+			name: "law",
+			voting_period: VotingPeriod::get(),
+			enactment_period: EnactmentPeriod::get(),
+			threshold: VoteThreshold::SuperMajorityApprove,
+			quorum: SUPERMAJORITY_QUORUM,
+			max_deciding: 10,
+			proposal_fee: 200 * DOLLARS,
+		},
+	),
+	(
+		TIER3,
+		TrackInfo {
+			name: "tier3",
+			voting_period: VotingPeriod::get(),
+			enactment_period: EnactmentPeriod::get() / 2,
+			threshold: VoteThreshold::SimpleMajority,
+			quorum: Quorum::none(),
+			max_deciding: 10,
+			proposal_fee: 100 * DOLLARS,
+		},
+	),
+	(
+		TIER4,
+		TrackInfo {
+			name: "tier4",
+			voting_period: VotingPeriod::get(),
+			enactment_period: EnactmentPeriod::get() / 2,
+			threshold: VoteThreshold::SimpleMajority,
+			quorum: Quorum::none(),
+			max_deciding: 10,
+			proposal_fee: 100 * DOLLARS,
+		},
+	),
+	(
+		TIER5,
+		TrackInfo {
+			name: "tier5",
+			voting_period: VotingPeriod::get(),
+			enactment_period: EnactmentPeriod::get() / 2,
+			threshold: VoteThreshold::SimpleMajority,
+			quorum: Quorum::none(),
+			max_deciding: 10,
+			proposal_fee: 100 * DOLLARS,
+		},
+	),
+	(
+		DECISION,
+		TrackInfo {
+			name: "decision",
+			voting_period: VotingPeriod::get() / 2,
+			enactment_period: EnactmentPeriod::get() / 2,
+			threshold: VoteThreshold::SimpleMajority,
+			quorum: Quorum::none(),
+			max_deciding: 20,
+			proposal_fee: 100 * DOLLARS,
+		},
+	),
+	(
+		TREASURY_SPEND,
+		TrackInfo {
+			name: "treasury_spend",
+			voting_period: VotingPeriod::get(),
+			enactment_period: EnactmentPeriod::get(),
+			threshold: VoteThreshold::SuperMajorityApprove,
+			quorum: SUPERMAJORITY_QUORUM,
+			max_deciding: 10,
+			proposal_fee: 100 * DOLLARS,
+		},
+	),
+	(
+		RUNTIME_UPGRADE,
+		TrackInfo {
+			name: "runtime_upgrade",
+			voting_period: VotingPeriod::get(),
+			enactment_period: 2 * EnactmentPeriod::get(),
+			threshold: VoteThreshold::SuperMajorityApprove,
+			quorum: Quorum::none(),
+			max_deciding: 1,
+			proposal_fee: 1000 * DOLLARS,
+		},
+	),
+];
+
+pub struct TracksInfo;
+impl pallet_democracy::TracksInfo<Balance, BlockNumber> for TracksInfo {
+	fn tracks() -> &'static [(TrackId, TrackInfo<Balance, BlockNumber>)] {
+		&TRACKS
+	}
+}
+
+/// Tracks on which proposals dispatched as Root may be made. Other tracks dispatch
+/// `RawOrigin::Track`, which only the origins built from them accept.
+pub struct RootTracks;
+impl Contains<TrackId> for RootTracks {
+	fn contains(track: &TrackId) -> bool {
+		*track == RUNTIME_UPGRADE
+	}
+}
+
+/// Legislation tier a track may change, if any.
+fn legislation_tier(track: TrackId) -> Option<LegislationTier> {
+	match track {
+		LAW => Some(LegislationTier::Law),
+		TIER3 => Some(LegislationTier::Tier3),
+		TIER4 => Some(LegislationTier::Tier4),
+		TIER5 => Some(LegislationTier::Tier5),
+		DECISION => Some(LegislationTier::Decision),
+		_ => None,
+	}
+}
+
+/// Ensures the origin is a referendum passed on one of the lower legislation tier tracks.
+/// Succeeds with the tier of the track.
+pub struct EnsureLegislationTrack;
+impl EnsureOrigin<RuntimeOrigin> for EnsureLegislationTrack {
+	type Success = LegislationTier;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		let democracy_origin: Result<pallet_democracy::Origin<Runtime>, _> = o.clone().into();
+		match democracy_origin {
+			Ok(pallet_democracy::RawOrigin::Track(track, ..)) => legislation_tier(track).ok_or(o),
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		<pallet_democracy::EnsureTrack<Runtime, LAW> as EnsureOrigin<RuntimeOrigin>>::try_successful_origin()
+	}
+}
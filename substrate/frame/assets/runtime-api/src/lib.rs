@@ -0,0 +1,107 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the FRAME assets pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use pallet_assets::{AssetDetails, AssetMetadata, AssetParameters};
+use scale_info::TypeInfo;
+use sp_runtime::{Permill, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// Holder of an asset related to a company.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CapTableEntry<AccountId, AssetBalance, AssetId> {
+	/// The asset
+	pub asset: AssetId,
+	/// The holder
+	pub holder: AccountId,
+	/// Holder's balance of the asset
+	pub balance: AssetBalance,
+	/// Holder's share of the asset's supply
+	pub percentage: Permill,
+	/// Holder's identity display name, if set
+	pub display_name: Option<Vec<u8>>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// Version 1 only had `account_balances`, the rest was added in version 2.
+	#[api_version(2)]
+	pub trait AssetsApi<
+		AccountId,
+		AssetBalance,
+		AssetId,
+		DepositBalance,
+		BlockNumber,
+		HoldReason,
+		FreezeId,
+	>
+	where
+		AccountId: Codec,
+		AssetBalance: Codec,
+		AssetId: Codec,
+		DepositBalance: Codec,
+		BlockNumber: Codec,
+		HoldReason: Codec,
+		FreezeId: Codec,
+	{
+		/// Returns the list of `AssetId`s and corresponding balance that an `AccountId` has.
+		fn account_balances(account: AccountId) -> Vec<(AssetId, AssetBalance)>;
+
+		/// Returns details of the asset, including its owner, issuer, supply and status.
+		#[api_version(2)]
+		fn asset_details(
+			asset: AssetId,
+		) -> Option<AssetDetails<AssetBalance, AccountId, DepositBalance>>;
+
+		/// Returns metadata of the asset, if it was set.
+		#[api_version(2)]
+		fn asset_metadata(asset: AssetId) -> Option<AssetMetadata<DepositBalance, Vec<u8>>>;
+
+		/// Returns compliance rules of the asset.
+		#[api_version(2)]
+		fn asset_parameters(asset: AssetId) -> AssetParameters<BlockNumber>;
+
+		/// Returns the company the asset is related to, if any.
+		#[api_version(2)]
+		fn related_company(asset: AssetId) -> Option<u32>;
+
+		/// Returns up to `limit` holders of the asset with their balances. Pass the last returned
+		/// holder as `start_after` to get the next page.
+		#[api_version(2)]
+		fn holders(
+			asset: AssetId,
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, AssetBalance)>;
+
+		/// Returns holders of all assets related to the company, with their balances, share of
+		/// asset's supply and identity display names.
+		#[api_version(2)]
+		fn cap_table(company: u32) -> Vec<CapTableEntry<AccountId, AssetBalance, AssetId>>;
+
+		/// Returns named holds of the account's balance of the asset.
+		#[api_version(2)]
+		fn holds(asset: AssetId, account: AccountId) -> Vec<(HoldReason, AssetBalance)>;
+
+		/// Returns named freezes of the account's balance of the asset. The largest one applies.
+		#[api_version(2)]
+		fn freezes(asset: AssetId, account: AccountId) -> Vec<(FreezeId, AssetBalance)>;
+	}
+}
@@ -23,9 +23,13 @@ use super::*;
 use frame_benchmarking::v1::{
 	account, benchmarks_instance_pallet, whitelist_account, whitelisted_caller, BenchmarkError,
 };
-use frame_support::traits::{EnsureOrigin, Get, UnfilteredDispatchable};
-use frame_system::RawOrigin as SystemOrigin;
-use sp_runtime::traits::Bounded;
+use frame_support::{
+	traits::{EnsureOrigin, Get, UnfilteredDispatchable},
+	BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin as SystemOrigin};
+use codec::Decode;
+use sp_runtime::traits::{Bounded, TrailingZeroInput};
 use sp_std::prelude::*;
 
 use crate::Pallet as Assets;
@@ -125,6 +129,153 @@ fn add_approvals<T: Config<I>, I: 'static>(minter: T::AccountId, n: u32) {
 	}
 }
 
+/// Makes the transfer hooks of the default asset do as much work as they can for `accounts`,
+/// without blocking the benchmarked call.
+fn worst_case_hooks<T: Config<I>, I: 'static>(accounts: &[T::AccountId]) {
+	let asset_id: T::AssetId = default_asset_id::<T, I>().into();
+	let mut parameters = AssetParameters::new(HolderRequirement::Anyone);
+	parameters.list = ComplianceListMode::Blocklist;
+	parameters.lockup = Some(1u32.into());
+	Parameters::<T, I>::insert(&asset_id, parameters);
+	let active: Vec<_> = (0..T::MaxActiveDistributions::get())
+		.map(|distribution_id| (distribution_id, BlockNumberFor::<T>::max_value()))
+		.collect();
+	ActiveDistributions::<T, I>::insert(&asset_id, BoundedVec::truncate_from(active));
+	// every change drops a checkpoint needed by all open resolutions
+	CheckpointsSince::<T, I>::insert(&asset_id, BlockNumberFor::<T>::zero());
+	SupplyCheckpoints::<T, I>::insert(&asset_id, full_checkpoints::<T, I>());
+	let open: Vec<_> = (0..T::MaxOpenResolutions::get())
+		.map(|resolution_id| (resolution_id, BlockNumberFor::<T>::zero()))
+		.collect();
+	OpenResolutions::<T, I>::insert(&asset_id, BoundedVec::truncate_from(open));
+	frame_system::Pallet::<T>::set_block_number((T::MaxCheckpoints::get() + 1).into());
+	for who in accounts {
+		BalanceCheckpoints::<T, I>::insert(&asset_id, who, full_checkpoints::<T, I>());
+		MintLockups::<T, I>::insert(
+			&asset_id,
+			who,
+			MintLockup { amount: Zero::zero(), until: Zero::zero() },
+		);
+		Vesting::<T, I>::insert(
+			&asset_id,
+			who,
+			vested_schedules::<T, I>(who, T::MaxVestingSchedules::get()),
+		);
+		// holds would keep the sender alive, but empty freezes are read without locking anything
+		full_freezes::<T, I>(who, Zero::zero());
+	}
+}
+
+/// Returns a hold or freeze reason decoded from zeroes.
+fn zero_reason<R: Decode>() -> R {
+	R::decode(&mut TrailingZeroInput::zeroes()).expect("infinite input; qed")
+}
+
+/// Gives `who` the maximum number of freezes of the default asset, all for the zero reason.
+fn full_freezes<T: Config<I>, I: 'static>(who: &T::AccountId, amount: T::Balance) {
+	let asset_id: T::AssetId = default_asset_id::<T, I>().into();
+	let freeze = IdAmount { id: zero_reason::<T::FreezeIdentifier>(), amount };
+	let freezes = vec![freeze; T::MaxFreezes::get() as usize];
+	Freezes::<T, I>::insert(&asset_id, who, BoundedVec::truncate_from(freezes));
+}
+
+/// Gives `who` the maximum number of holds and freezes of the default asset, all for the zero
+/// reason.
+fn full_holds_and_freezes<T: Config<I>, I: 'static>(who: &T::AccountId) {
+	let asset_id: T::AssetId = default_asset_id::<T, I>().into();
+	let hold = IdAmount { id: zero_reason::<T::RuntimeHoldReason>(), amount: 1u32.into() };
+	let holds = vec![hold; T::MaxHolds::get() as usize];
+	Holds::<T, I>::insert(&asset_id, who, BoundedVec::truncate_from(holds));
+	full_freezes::<T, I>(who, 1u32.into());
+}
+
+/// Returns `n` vesting schedules of 1 unit granted by `grantor`, vested since block 1.
+fn vested_schedules<T: Config<I>, I: 'static>(
+	grantor: &T::AccountId,
+	n: u32,
+) -> BoundedVec<VestingScheduleOf<T, I>, T::MaxVestingSchedules> {
+	let schedule = VestingSchedule {
+		grantor: grantor.clone(),
+		amount: T::Balance::from(1u32),
+		start: Zero::zero(),
+		curve: VestingCurve::Linear { duration: 1u32.into(), cliff: Zero::zero() },
+		revocable: false,
+	};
+	BoundedVec::truncate_from(vec![schedule; n as usize])
+}
+
+/// Returns `MaxCheckpoints` checkpoints, one for each block since genesis.
+fn full_checkpoints<T: Config<I>, I: 'static>() -> CheckpointsOf<T, I> {
+	let entries: Vec<_> = (1..=T::MaxCheckpoints::get())
+		.map(|block| (block.into(), T::Balance::from(100u32)))
+		.collect();
+	Checkpoints { since: Zero::zero(), entries: BoundedVec::truncate_from(entries) }
+}
+
+/// Enables checkpoints of the default asset at block 1 and moves to block 2.
+fn enable_default_checkpoints<T: Config<I>, I: 'static>(owner: &T::AccountId) {
+	frame_system::Pallet::<T>::set_block_number(1u32.into());
+	assert!(Assets::<T, I>::enable_checkpoints(
+		SystemOrigin::Signed(owner.clone()).into(),
+		default_asset_id::<T, I>(),
+	)
+	.is_ok());
+	frame_system::Pallet::<T>::set_block_number(2u32.into());
+}
+
+/// Proposes a resolution to holders of the default asset. Returns the id of the resolution.
+fn propose_default_resolution<T: Config<I>, I: 'static>(chair: &T::AccountId) -> ResolutionId {
+	let resolution_id = NextResolutionId::<T, I>::get();
+	assert!(Assets::<T, I>::propose_resolution(
+		SystemOrigin::Signed(chair.clone()).into(),
+		default_asset_id::<T, I>(),
+		vec![0; T::StringLimit::get() as usize],
+		10u32.into(),
+		Permill::from_percent(50),
+	)
+	.is_ok());
+	resolution_id
+}
+
+/// Creates a second asset, minted to `issuer`, to pay out distributions in.
+fn create_payout_asset<T: Config<I>, I: 'static>(issuer: &T::AccountId) -> T::AssetId {
+	let payout_id = T::BenchmarkHelper::create_asset_id_parameter(1);
+	let issuer_lookup = T::Lookup::unlookup(issuer.clone());
+	assert!(Assets::<T, I>::force_create(
+		SystemOrigin::Root.into(),
+		payout_id,
+		issuer_lookup.clone(),
+		true,
+		1u32.into(),
+	)
+	.is_ok());
+	assert!(Assets::<T, I>::mint(
+		SystemOrigin::Signed(issuer.clone()).into(),
+		payout_id,
+		issuer_lookup,
+		1_000_000_000u32.into(),
+	)
+	.is_ok());
+	payout_id.into()
+}
+
+/// Creates a distribution of `payout_asset` to holders of the default asset, expiring after 10
+/// blocks.
+fn create_default_distribution<T: Config<I>, I: 'static>(
+	issuer: &T::AccountId,
+	payout_asset: T::AssetId,
+) -> DistributionId {
+	let distribution_id = NextDistributionId::<T, I>::get();
+	assert!(Assets::<T, I>::create_distribution(
+		SystemOrigin::Signed(issuer.clone()).into(),
+		default_asset_id::<T, I>(),
+		Payout::Asset(payout_asset, 1_000_000u32.into()),
+		10u32.into(),
+	)
+	.is_ok());
+	distribution_id
+}
+
 fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
@@ -219,6 +370,7 @@ benchmarks_instance_pallet! {
 
 	mint {
 		let (asset_id, caller, caller_lookup) = create_default_asset::<T, I>(true);
+		worst_case_hooks::<T, I>(&[caller.clone()]);
 		let amount = T::Balance::from(100u32);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id, caller_lookup, amount)
 	verify {
@@ -228,6 +380,7 @@ benchmarks_instance_pallet! {
 	burn {
 		let amount = T::Balance::from(100u32);
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
+		worst_case_hooks::<T, I>(&[caller.clone()]);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id, caller_lookup, amount)
 	verify {
 		assert_last_event::<T, I>(Event::Burned { asset_id: asset_id.into(), owner: caller, balance: amount }.into());
@@ -238,6 +391,7 @@ benchmarks_instance_pallet! {
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
+		worst_case_hooks::<T, I>(&[caller.clone(), target.clone()]);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id, target_lookup, amount)
 	verify {
 		assert_last_event::<T, I>(Event::Transferred { asset_id: asset_id.into(), from: caller, to: target, amount }.into());
@@ -249,6 +403,7 @@ benchmarks_instance_pallet! {
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, mint_amount);
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
+		worst_case_hooks::<T, I>(&[caller.clone(), target.clone()]);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id, target_lookup, amount)
 	verify {
 		assert!(frame_system::Pallet::<T>::account_exists(&caller));
@@ -260,6 +415,7 @@ benchmarks_instance_pallet! {
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
+		worst_case_hooks::<T, I>(&[caller.clone(), target.clone()]);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id, caller_lookup, target_lookup, amount)
 	verify {
 		assert_last_event::<T, I>(
@@ -438,6 +594,7 @@ benchmarks_instance_pallet! {
 
 		let dest: T::AccountId = account("dest", 0, SEED);
 		let dest_lookup = T::Lookup::unlookup(dest.clone());
+		worst_case_hooks::<T, I>(&[owner.clone(), dest.clone()]);
 	}: _(SystemOrigin::Signed(delegate.clone()), asset_id, owner_lookup, dest_lookup, amount)
 	verify {
 		assert!(T::Currency::reserved_balance(&owner).is_zero());
@@ -550,7 +707,7 @@ benchmarks_instance_pallet! {
 
 	set_parameters {
 		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
-		let parameters = AssetParameters { eresidency_required: true };
+		let parameters = AssetParameters::new(HolderRequirement::EResident);
 	}: _(SystemOrigin::Signed(caller), asset_id, parameters)
 	verify {
 		assert_last_event::<T, I>(Event::ParametersSet { asset_id: asset_id.into(), parameters }.into());
@@ -558,11 +715,290 @@ benchmarks_instance_pallet! {
 
 	force_set_parameters {
 		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
-		let parameters = AssetParameters { eresidency_required: true };
+		let parameters = AssetParameters::new(HolderRequirement::EResident);
 	}: _(SystemOrigin::Root, asset_id, parameters)
 	verify {
 		assert_last_event::<T, I>(Event::ParametersSet { asset_id: asset_id.into(), parameters }.into());
 	}
 
+	set_compliance_list {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+	}: _(SystemOrigin::Signed(caller), asset_id, target_lookup, true)
+	verify {
+		assert_last_event::<T, I>(Event::ComplianceListUpdated { asset_id: asset_id.into(), who: target, listed: true }.into());
+	}
+
+	set_related_company {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
+		let company = T::BenchmarkHelper::create_company();
+		Assets::<T, I>::do_set_related_company(asset_id.into(), company, None)?;
+	}: _(SystemOrigin::Signed(caller), asset_id, company)
+	verify {
+		assert_last_event::<T, I>(Event::RelatedCompanySet { asset_id: asset_id.into(), company }.into());
+	}
+
+	force_set_related_company {
+		let (asset_id, _, _) = create_default_asset::<T, I>(true);
+		let company = T::BenchmarkHelper::create_company();
+		Assets::<T, I>::do_set_related_company(asset_id.into(), company, None)?;
+	}: _(SystemOrigin::Root, asset_id, company)
+	verify {
+		assert_last_event::<T, I>(Event::RelatedCompanySet { asset_id: asset_id.into(), company }.into());
+	}
+
+	create_distribution {
+		let (asset_id, caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let payout_asset = create_payout_asset::<T, I>(&caller);
+		for _ in 1..T::MaxActiveDistributions::get() {
+			create_default_distribution::<T, I>(&caller, payout_asset.clone());
+		}
+		let payout = Payout::Asset(payout_asset, 1_000_000u32.into());
+		let distribution_id = NextDistributionId::<T, I>::get();
+	}: _(SystemOrigin::Signed(caller), asset_id, payout, 10u32.into())
+	verify {
+		assert!(Distributions::<T, I>::contains_key(distribution_id));
+	}
+
+	claim_distribution {
+		let (_, caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let payout_asset = create_payout_asset::<T, I>(&caller);
+		let distribution_id = create_default_distribution::<T, I>(&caller, payout_asset);
+	}: _(SystemOrigin::Signed(caller.clone()), distribution_id)
+	verify {
+		assert_eq!(
+			DistributionAccounts::<T, I>::get(distribution_id, &caller),
+			Some(DistributionAccount::Claimed),
+		);
+	}
+
+	payout_distribution {
+		let n in 1 .. T::RemoveItemsLimit::get();
+		let (_, caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		add_sufficients::<T, I>(caller.clone(), n - 1);
+		let payout_asset = create_payout_asset::<T, I>(&caller);
+		let distribution_id = create_default_distribution::<T, I>(&caller, payout_asset);
+	}: _(SystemOrigin::Signed(caller.clone()), distribution_id, n)
+	verify {
+		assert_eq!(
+			DistributionAccounts::<T, I>::get(distribution_id, &caller),
+			Some(DistributionAccount::Claimed),
+		);
+	}
+
+	close_distribution {
+		let c in 0 .. T::RemoveItemsLimit::get();
+		let (_, caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let payout_asset = create_payout_asset::<T, I>(&caller);
+		let distribution_id = create_default_distribution::<T, I>(&caller, payout_asset);
+		for i in 0..c {
+			let holder: T::AccountId = account("holder", i, SEED);
+			DistributionAccounts::<T, I>::insert(distribution_id, holder, DistributionAccount::Claimed);
+		}
+		let expired = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+		frame_system::Pallet::<T>::set_block_number(expired);
+	}: _(SystemOrigin::Signed(caller), distribution_id)
+	verify {
+		assert!(!Distributions::<T, I>::contains_key(distribution_id));
+	}
+
+	enable_checkpoints {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
+	}: _(SystemOrigin::Signed(caller), asset_id)
+	verify {
+		assert_last_event::<T, I>(Event::CheckpointsEnabled { asset_id: asset_id.into() }.into());
+	}
+
+	propose_resolution {
+		let s in 0 .. T::StringLimit::get();
+		let (asset_id, caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		enable_default_checkpoints::<T, I>(&caller);
+		for _ in 1..T::MaxOpenResolutions::get() {
+			propose_default_resolution::<T, I>(&caller);
+		}
+		let mut checkpoints = full_checkpoints::<T, I>();
+		checkpoints.since = 1u32.into();
+		SupplyCheckpoints::<T, I>::insert(&asset_id.into(), checkpoints);
+		let resolution_id = NextResolutionId::<T, I>::get();
+	}: _(SystemOrigin::Signed(caller), asset_id, vec![0; s as usize], 10u32.into(), Permill::from_percent(50))
+	verify {
+		assert!(Resolutions::<T, I>::contains_key(resolution_id));
+	}
+
+	vote_resolution {
+		let (asset_id, caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		enable_default_checkpoints::<T, I>(&caller);
+		let resolution_id = propose_default_resolution::<T, I>(&caller);
+		let mut checkpoints = full_checkpoints::<T, I>();
+		checkpoints.since = 1u32.into();
+		BalanceCheckpoints::<T, I>::insert(&asset_id.into(), &caller, checkpoints);
+		Assets::<T, I>::vote_resolution(SystemOrigin::Signed(caller.clone()).into(), resolution_id, false)?;
+	}: _(SystemOrigin::Signed(caller.clone()), resolution_id, true)
+	verify {
+		assert_eq!(ResolutionVotes::<T, I>::get(resolution_id, &caller), Some((true, 100u32.into())));
+	}
+
+	close_resolution {
+		let c in 0 .. T::RemoveItemsLimit::get();
+		let (_, caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		enable_default_checkpoints::<T, I>(&caller);
+		let resolution_id = propose_default_resolution::<T, I>(&caller);
+		for i in 0..c {
+			let voter: T::AccountId = account("voter", i, SEED);
+			ResolutionVotes::<T, I>::insert(resolution_id, &voter, (true, T::Balance::from(1u32)));
+			ResolutionSnapshots::<T, I>::insert(resolution_id, &voter, T::Balance::from(1u32));
+		}
+		frame_system::Pallet::<T>::set_block_number(12u32.into());
+	}: _(SystemOrigin::Signed(caller), resolution_id)
+	verify {
+		assert!(!Resolutions::<T, I>::contains_key(resolution_id));
+	}
+
+	vested_transfer {
+		let amount = T::Balance::from(100u32);
+		let (asset_id, caller, _) = create_default_minted_asset::<T, I>(true, amount);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		worst_case_hooks::<T, I>(&[caller.clone(), target.clone()]);
+		let schedules = vested_schedules::<T, I>(&caller, T::MaxVestingSchedules::get() - 1);
+		Vesting::<T, I>::insert(&asset_id.into(), &target, schedules);
+		let start = BlockNumberFor::<T>::max_value();
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id, target_lookup, amount, start, VestingCurve::Cliff, true)
+	verify {
+		assert_last_event::<T, I>(Event::VestingScheduleAdded {
+			asset_id: asset_id.into(),
+			who: target,
+			grantor: caller,
+			amount,
+		}.into());
+	}
+
+	vest {
+		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let schedules = vested_schedules::<T, I>(&caller, T::MaxVestingSchedules::get());
+		Vesting::<T, I>::insert(&asset_id.into(), &caller, schedules);
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id, caller_lookup)
+	verify {
+		assert!(!Vesting::<T, I>::contains_key(&asset_id.into(), &caller));
+	}
+
+	merge_vesting_schedules {
+		let (asset_id, caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let schedules = vested_schedules::<T, I>(&caller, T::MaxVestingSchedules::get());
+		Vesting::<T, I>::insert(&asset_id.into(), &caller, schedules);
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id, 1, 0)
+	verify {
+		let schedules = Vesting::<T, I>::get(&asset_id.into(), &caller);
+		assert_eq!(schedules.len() as u32, T::MaxVestingSchedules::get() - 1);
+	}
+
+	split_vesting_schedule {
+		let (asset_id, caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let mut schedules = vested_schedules::<T, I>(&caller, T::MaxVestingSchedules::get() - 1);
+		schedules[0].amount = 2u32.into();
+		Vesting::<T, I>::insert(&asset_id.into(), &caller, schedules);
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id, 0, 1u32.into())
+	verify {
+		let schedules = Vesting::<T, I>::get(&asset_id.into(), &caller);
+		assert_eq!(schedules.len() as u32, T::MaxVestingSchedules::get());
+	}
+
+	revoke_vesting {
+		let amount = T::Balance::from(100u32);
+		let (asset_id, caller, _) = create_default_minted_asset::<T, I>(true, amount);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		worst_case_hooks::<T, I>(&[caller.clone(), target.clone()]);
+		Assets::<T, I>::transfer(
+			SystemOrigin::Signed(caller.clone()).into(),
+			asset_id,
+			target_lookup.clone(),
+			amount,
+		)?;
+		let mut schedules = vested_schedules::<T, I>(&caller, T::MaxVestingSchedules::get() - 1);
+		let locked = VestingSchedule {
+			grantor: caller.clone(),
+			amount,
+			start: BlockNumberFor::<T>::max_value(),
+			curve: VestingCurve::Cliff,
+			revocable: true,
+		};
+		assert!(schedules.try_insert(0, locked).is_ok());
+		Vesting::<T, I>::insert(&asset_id.into(), &target, schedules);
+	}: _(SystemOrigin::Signed(caller), asset_id, target_lookup, 0)
+	verify {
+		assert_last_event::<T, I>(Event::VestingRevoked {
+			asset_id: asset_id.into(),
+			who: target,
+			returned: amount,
+		}.into());
+	}
+
+	start_rescale {
+		let (asset_id, caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+	}: _(SystemOrigin::Signed(caller), asset_id, 2, 1)
+	verify {
+		assert_last_event::<T, I>(Event::RescaleStarted {
+			asset_id: asset_id.into(),
+			numerator: 2,
+			denominator: 1,
+		}.into());
+	}
+
+	continue_rescale_accounts {
+		let c in 1 .. T::RemoveItemsLimit::get();
+		let (asset_id, caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		add_sufficients::<T, I>(caller.clone(), c - 1);
+		let mut accounts = vec![caller.clone()];
+		accounts.extend((0..c - 1).map(|i| account("sufficient", i, SEED)));
+		worst_case_hooks::<T, I>(&accounts);
+		for who in &accounts {
+			full_holds_and_freezes::<T, I>(who);
+		}
+		Assets::<T, I>::start_rescale(SystemOrigin::Signed(caller.clone()).into(), asset_id, 2, 1)?;
+	}: continue_rescale(SystemOrigin::Signed(caller.clone()), asset_id)
+	verify {
+		assert_eq!(Assets::<T, I>::balance(asset_id.into(), &caller), 200u32.into());
+	}
+
+	continue_rescale_approvals {
+		// a full batch of approvals is finished by the next call
+		let a in 0 .. T::RemoveItemsLimit::get() - 1;
+		let (asset_id, caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		add_approvals::<T, I>(caller.clone(), a);
+		enable_default_checkpoints::<T, I>(&caller);
+		Assets::<T, I>::start_rescale(SystemOrigin::Signed(caller.clone()).into(), asset_id, 2, 1)?;
+		Assets::<T, I>::continue_rescale(SystemOrigin::Signed(caller.clone()).into(), asset_id)?;
+	}: continue_rescale(SystemOrigin::Signed(caller), asset_id)
+	verify {
+		assert!(!Rescales::<T, I>::contains_key(&asset_id.into()));
+	}
+
+	freeze_amount {
+		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		full_holds_and_freezes::<T, I>(&caller);
+		let reason = zero_reason::<T::FreezeIdentifier>();
+		let amount = T::Balance::from(50u32);
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id, caller_lookup, reason, amount)
+	verify {
+		assert_last_event::<T, I>(Event::FreezeSet {
+			asset_id: asset_id.into(),
+			who: caller,
+			reason,
+			amount,
+		}.into());
+	}
+
+	thaw_amount {
+		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		full_holds_and_freezes::<T, I>(&caller);
+		let reason = zero_reason::<T::FreezeIdentifier>();
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id, caller_lookup, reason)
+	verify {
+		assert!(!Freezes::<T, I>::contains_key(&asset_id.into(), &caller));
+	}
+
 	impl_benchmark_test_suite!(Assets, crate::mock::new_test_ext(), crate::mock::Test)
 }
@@ -0,0 +1,159 @@
+/*
+
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+use crate::*;
+use sp_runtime::DispatchResult;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub fn do_enable_checkpoints(
+		asset: T::AssetId,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		let d = Asset::<T, I>::get(&asset).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+		if let Some(check_owner) = maybe_check_owner {
+			ensure!(d.owner == check_owner, Error::<T, I>::NoPermission);
+		}
+		ensure!(
+			!CheckpointsSince::<T, I>::contains_key(&asset),
+			Error::<T, I>::CheckpointsAlreadyEnabled
+		);
+
+		CheckpointsSince::<T, I>::insert(&asset, frame_system::Pallet::<T>::block_number());
+		Self::deposit_event(Event::CheckpointsEnabled { asset_id: asset });
+		Ok(())
+	}
+
+	/// Balance of `who` at the end of `block`. `None` if it's unknown, i.e. checkpoints weren't
+	/// enabled at `block` or were already dropped.
+	pub fn balance_at(
+		asset: T::AssetId,
+		who: &T::AccountId,
+		block: BlockNumberFor<T>,
+	) -> Option<T::Balance> {
+		let since = Self::checkpoints_since(&asset, block)?;
+		let checkpoints = BalanceCheckpoints::<T, I>::get(&asset, who);
+		Self::value_at(checkpoints, since, block, || Self::balance_with_holds(asset.clone(), who))
+	}
+
+	/// Total supply of `asset` at the end of `block`. `None` if it's unknown, i.e. checkpoints
+	/// weren't enabled at `block` or were already dropped.
+	pub fn total_supply_at(asset: T::AssetId, block: BlockNumberFor<T>) -> Option<T::Balance> {
+		let since = Self::checkpoints_since(&asset, block)?;
+		let checkpoints = SupplyCheckpoints::<T, I>::get(&asset);
+		Self::value_at(checkpoints, since, block, || Self::total_supply(asset.clone()))
+	}
+
+	/// Records balance of `who`, if checkpoints are enabled and it wasn't recorded in this block
+	/// yet. Must be called before every change of the balance.
+	pub(super) fn checkpoint_balance(asset: &T::AssetId, who: &T::AccountId) {
+		let Some(since) = CheckpointsSince::<T, I>::get(asset) else { return };
+		let balance = Self::balance_with_holds(asset.clone(), who);
+		BalanceCheckpoints::<T, I>::mutate(asset, who, |checkpoints| {
+			if let Some((known_since, dropped)) =
+				Self::record_checkpoint(checkpoints, since, balance)
+			{
+				Self::keep_resolution_snapshots(asset, who, known_since, dropped);
+			}
+		});
+	}
+
+	/// Records supply of `asset`, if checkpoints are enabled and it wasn't recorded in this
+	/// block yet. Must be called before every change of the supply.
+	pub(super) fn checkpoint_supply(asset: &T::AssetId) {
+		let Some(since) = CheckpointsSince::<T, I>::get(asset) else { return };
+		let supply = Self::total_supply(asset.clone());
+		SupplyCheckpoints::<T, I>::mutate(asset, |checkpoints| {
+			Self::record_checkpoint(checkpoints, since, supply)
+		});
+	}
+
+	/// Keeps balance of `who` for open resolutions whose snapshot block was answered by the
+	/// `dropped` checkpoint, i.e. is between `known_since` and the block of `dropped`.
+	fn keep_resolution_snapshots(
+		asset: &T::AssetId,
+		who: &T::AccountId,
+		known_since: BlockNumberFor<T>,
+		dropped: (BlockNumberFor<T>, T::Balance),
+	) {
+		let (dropped_block, balance) = dropped;
+		for (resolution_id, snapshot) in OpenResolutions::<T, I>::get(asset) {
+			if known_since <= snapshot &&
+				snapshot < dropped_block &&
+				!ResolutionSnapshots::<T, I>::contains_key(resolution_id, who)
+			{
+				ResolutionSnapshots::<T, I>::insert(resolution_id, who, balance);
+			}
+		}
+	}
+
+	pub(super) fn remove_checkpoints(asset: &T::AssetId) {
+		CheckpointsSince::<T, I>::remove(asset);
+		SupplyCheckpoints::<T, I>::remove(asset);
+	}
+
+	fn checkpoints_since(
+		asset: &T::AssetId,
+		block: BlockNumberFor<T>,
+	) -> Option<BlockNumberFor<T>> {
+		let since = CheckpointsSince::<T, I>::get(asset)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		(since <= block && block <= now).then_some(since)
+	}
+
+	fn value_at(
+		checkpoints: Option<CheckpointsOf<T, I>>,
+		since: BlockNumberFor<T>,
+		block: BlockNumberFor<T>,
+		current: impl FnOnce() -> T::Balance,
+	) -> Option<T::Balance> {
+		// checkpoints left by a destroyed asset with the same id are ignored
+		let Some(checkpoints) = checkpoints.filter(|c| c.since >= since) else {
+			return Some(current())
+		};
+		if block < checkpoints.since {
+			return None
+		}
+		let idx = checkpoints.entries.partition_point(|(b, _)| *b <= block);
+		match checkpoints.entries.get(idx) {
+			Some((_, value)) => Some(*value),
+			None => Some(current()),
+		}
+	}
+
+	/// Records `value` as the value before the first change in the current block. If there's
+	/// no room, drops the oldest checkpoint and returns it with the first block it answered for.
+	fn record_checkpoint(
+		maybe_checkpoints: &mut Option<CheckpointsOf<T, I>>,
+		since: BlockNumberFor<T>,
+		value: T::Balance,
+	) -> Option<(BlockNumberFor<T>, (BlockNumberFor<T>, T::Balance))> {
+		let now = frame_system::Pallet::<T>::block_number();
+		// checkpoints left by a destroyed asset with the same id are reset
+		if maybe_checkpoints.as_ref().map_or(false, |c| c.since < since) {
+			*maybe_checkpoints = None;
+		}
+		let checkpoints = maybe_checkpoints
+			.get_or_insert_with(|| Checkpoints { since, entries: Default::default() });
+		if checkpoints.entries.last().map_or(false, |(b, _)| *b == now) {
+			return None
+		}
+		let Err(entry) = checkpoints.entries.try_push((now, value)) else { return None };
+		if checkpoints.entries.is_empty() {
+			return None
+		}
+		// history before the dropped checkpoint is no longer known
+		let known_since = checkpoints.since;
+		let dropped = checkpoints.entries.remove(0);
+		checkpoints.since = dropped.0;
+		let _ = checkpoints.entries.try_push(entry);
+		Some((known_since, dropped))
+	}
+}
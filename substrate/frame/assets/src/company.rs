@@ -0,0 +1,69 @@
+/*
+
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+use crate::*;
+use frame_support::traits::Contains;
+use sp_runtime::{DispatchResult, Permill};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub fn do_set_related_company(
+		asset: T::AssetId,
+		company: AssetRelatedCompany,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		let d = Asset::<T, I>::get(&asset).ok_or(Error::<T, I>::Unknown)?;
+		if let Some(check_owner) = maybe_check_owner {
+			ensure!(d.owner == check_owner, Error::<T, I>::NoPermission);
+		}
+		ensure!(T::Companies::contains(&company), Error::<T, I>::UnknownCompany);
+
+		Self::remove_related_company(&asset);
+		RelatedCompany::<T, I>::insert(&asset, company);
+		CompanyAssets::<T, I>::insert(company, &asset, ());
+		Self::deposit_event(Event::RelatedCompanySet { asset_id: asset, company });
+		Ok(())
+	}
+
+	pub(super) fn remove_related_company(asset: &T::AssetId) {
+		if RelatedCompany::<T, I>::contains_key(asset) {
+			let company = RelatedCompany::<T, I>::take(asset);
+			CompanyAssets::<T, I>::remove(company, asset);
+		}
+	}
+
+	/// Returns the company related to `asset`, if any.
+	pub fn related_company(asset: T::AssetId) -> Option<AssetRelatedCompany> {
+		RelatedCompany::<T, I>::try_get(asset).ok()
+	}
+
+	/// Returns assets related to `company`.
+	pub fn company_assets(company: AssetRelatedCompany) -> Vec<T::AssetId> {
+		CompanyAssets::<T, I>::iter_key_prefix(company).collect()
+	}
+
+	/// Returns holders of all assets related to `company`, with their balances and share of
+	/// asset's supply.
+	pub fn cap_table(
+		company: AssetRelatedCompany,
+	) -> Vec<(T::AssetId, T::AccountId, T::Balance, Permill)> {
+		Self::company_assets(company)
+			.into_iter()
+			.flat_map(|asset| {
+				let supply = Self::total_supply(asset.clone());
+				Account::<T, I>::iter_prefix(asset.clone())
+					.filter(|(_, account)| !account.balance.is_zero())
+					.map(move |(who, account)| {
+						let share = Permill::from_rational(account.balance, supply);
+						(asset.clone(), who, account.balance, share)
+					})
+			})
+			.collect()
+	}
+}
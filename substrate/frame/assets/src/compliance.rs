@@ -0,0 +1,139 @@
+/*
+
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+use crate::*;
+use sp_runtime::DispatchResult;
+use liberland_traits::CitizenshipChecker;
+use frame_support::ensure;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub fn do_set_parameters(
+		asset: T::AssetId,
+        parameters: AssetParametersOf<T>,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+        if let Some(check_owner) = maybe_check_owner {
+            let d = Asset::<T, I>::get(&asset).ok_or(Error::<T, I>::Unknown)?;
+            ensure!(d.owner == check_owner, Error::<T, I>::NoPermission);
+        }
+
+        Parameters::<T, I>::insert(&asset, parameters);
+		Self::deposit_event(Event::ParametersSet { asset_id: asset, parameters });
+        Ok(())
+	}
+
+	pub fn do_set_compliance_list(
+		asset: T::AssetId,
+		who: T::AccountId,
+		listed: bool,
+		maybe_check_issuer: Option<T::AccountId>,
+	) -> DispatchResult {
+		let d = Asset::<T, I>::get(&asset).ok_or(Error::<T, I>::Unknown)?;
+		if let Some(check_issuer) = maybe_check_issuer {
+			ensure!(d.issuer == check_issuer, Error::<T, I>::NoPermission);
+		}
+
+		if listed {
+			ComplianceList::<T, I>::insert(&asset, &who, ());
+		} else {
+			ComplianceList::<T, I>::remove(&asset, &who);
+		}
+		Self::deposit_event(Event::ComplianceListUpdated { asset_id: asset, who, listed });
+		Ok(())
+	}
+
+	/// Checks if `who` is allowed to hold `asset` - verifies holder requirement and compliance
+	/// list. System accounts, like liquidity pools, are always allowed.
+	pub fn ensure_allowed_holder(
+		asset: &T::AssetId,
+		who: &T::AccountId,
+		parameters: &AssetParametersOf<T>,
+	) -> DispatchResult {
+		if Self::is_system_account(asset, who) {
+			return Ok(())
+		}
+		match parameters.holders {
+			HolderRequirement::Anyone => {},
+			HolderRequirement::EResident => T::Citizenship::ensure_stocks_allowed(who)?,
+			HolderRequirement::Citizen => T::Citizenship::ensure_land_nfts_allowed(who)?,
+		}
+		let listed = ComplianceList::<T, I>::contains_key(asset, who);
+		match parameters.list {
+			ComplianceListMode::Disabled => {},
+			ComplianceListMode::Allowlist => ensure!(listed, TokenError::Blocked),
+			ComplianceListMode::Blocklist => ensure!(!listed, TokenError::Blocked),
+		}
+		Ok(())
+	}
+
+	/// Funds sent to a system account, like a liquidity pool, are traded on behalf of the sender,
+	/// so the sender must be allowed to hold `asset`.
+	pub(super) fn ensure_allowed_sender(
+		asset: &T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+	) -> DispatchResult {
+		if Self::is_system_account(asset, dest) {
+			Self::ensure_allowed_holder(asset, source, &Parameters::<T, I>::get(asset))?;
+		}
+		Ok(())
+	}
+
+	/// Returns true if `who` is a system account of `asset`, like a liquidity pool.
+	pub fn is_system_account(asset: &T::AssetId, who: &T::AccountId) -> bool {
+		SystemAccounts::<T, I>::contains_key(asset, who)
+	}
+
+	/// Checks if `balance` of `who` would exceed the maximum holding allowed by `parameters`.
+	pub(super) fn exceeds_max_holding(
+		details: &AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		parameters: &AssetParametersOf<T>,
+		who: &T::AccountId,
+		balance: T::Balance,
+		supply: T::Balance,
+	) -> bool {
+		match parameters.max_holding {
+			Some(max_holding) if who != &details.owner && who != &details.issuer =>
+				balance > max_holding * supply,
+			_ => false,
+		}
+	}
+
+	/// Locks `amount` minted to `who` for the lockup period of `asset`, if it has any. Extends
+	/// the lockup of previously minted funds that are still locked.
+	pub(super) fn lock_minted(asset: &T::AssetId, who: &T::AccountId, amount: T::Balance) {
+		let Some(lockup) = Parameters::<T, I>::get(asset).lockup else { return };
+		let now = frame_system::Pallet::<T>::block_number();
+		let locked = Self::mint_locked_balance(asset, who);
+		MintLockups::<T, I>::insert(
+			asset,
+			who,
+			MintLockup { amount: locked.saturating_add(amount), until: now.saturating_add(lockup) },
+		);
+	}
+
+	/// Returns the balance of `who` that can't be moved yet because of the lockup after mint,
+	/// vesting or freezes.
+	pub fn locked_balance(asset: &T::AssetId, who: &T::AccountId) -> T::Balance {
+		Self::mint_locked_balance(asset, who)
+			.saturating_add(Self::vesting_locked_balance(asset, who))
+			.saturating_add(Self::freeze_locked_balance(asset, who))
+	}
+
+	/// Returns the balance of `who` that can't be moved yet because of the lockup after mint.
+	pub fn mint_locked_balance(asset: &T::AssetId, who: &T::AccountId) -> T::Balance {
+		let now = frame_system::Pallet::<T>::block_number();
+		match MintLockups::<T, I>::get(asset, who) {
+			Some(lockup) if now < lockup.until => lockup.amount,
+			_ => Zero::zero(),
+		}
+	}
+
+}
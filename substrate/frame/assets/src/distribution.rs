@@ -0,0 +1,254 @@
+/*
+
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	storage::with_storage_layer,
+	traits::{ExistenceRequirement::AllowDeath, PalletInfoAccess},
+};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{Hash, TrailingZeroInput, UniqueSaturatedInto},
+	DispatchResult, Rounding,
+};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub fn do_create_distribution(
+		issuer: T::AccountId,
+		asset: T::AssetId,
+		payout: PayoutOf<T, I>,
+		duration: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let d = Asset::<T, I>::get(&asset).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+		ensure!(d.issuer == issuer, Error::<T, I>::NoPermission);
+		ensure!(
+			!d.supply.is_zero() && !duration.is_zero() && !Self::is_zero_payout(&payout),
+			Error::<T, I>::InvalidDistribution
+		);
+
+		let distribution_id = NextDistributionId::<T, I>::get();
+		NextDistributionId::<T, I>::put(
+			distribution_id.checked_add(1).ok_or(ArithmeticError::Overflow)?,
+		);
+		let snapshot = frame_system::Pallet::<T>::block_number();
+		let expires_at = snapshot.saturating_add(duration);
+		ActiveDistributions::<T, I>::try_mutate(&asset, |active| {
+			active.try_push((distribution_id, expires_at))
+		})
+		.map_err(|_| Error::<T, I>::TooManyDistributions)?;
+
+		let pot = Self::distribution_account(distribution_id);
+		frame_system::Pallet::<T>::inc_providers(&pot);
+		Self::transfer_payout(&payout, &issuer, &pot)?;
+
+		Distributions::<T, I>::insert(
+			distribution_id,
+			Distribution {
+				asset: asset.clone(),
+				issuer,
+				payout: payout.clone(),
+				supply: d.supply,
+				snapshot,
+				expires_at,
+				payout_cursor: None,
+				payout_complete: false,
+				closed: false,
+			},
+		);
+		Self::deposit_event(Event::DistributionCreated {
+			distribution_id,
+			asset_id: asset,
+			payout,
+			snapshot,
+			expires_at,
+		});
+		Ok(())
+	}
+
+	pub fn do_claim_distribution(
+		distribution_id: DistributionId,
+		who: T::AccountId,
+	) -> DispatchResult {
+		let d = Self::active_distribution(distribution_id)?;
+		let balance = match DistributionAccounts::<T, I>::get(distribution_id, &who) {
+			Some(DistributionAccount::Claimed) => return Err(Error::<T, I>::AlreadyClaimed.into()),
+			Some(DistributionAccount::Snapshot(balance)) => balance,
+			None => Self::balance_with_holds(d.asset.clone(), &who),
+		};
+		let payout = Self::pro_rata(&d.payout, balance, d.supply);
+		ensure!(!Self::is_zero_payout(&payout), Error::<T, I>::NothingToClaim);
+
+		DistributionAccounts::<T, I>::insert(distribution_id, &who, DistributionAccount::Claimed);
+		Self::transfer_payout(&payout, &Self::distribution_account(distribution_id), &who)?;
+
+		Self::deposit_event(Event::DistributionClaimed { distribution_id, who, payout });
+		Ok(())
+	}
+
+	pub fn do_payout_distribution(distribution_id: DistributionId, max: u32) -> DispatchResult {
+		let mut d = Self::active_distribution(distribution_id)?;
+		ensure!(!d.payout_complete, Error::<T, I>::NothingToClaim);
+
+		let holders = match &d.payout_cursor {
+			Some(last) => Account::<T, I>::iter_prefix_from(
+				d.asset.clone(),
+				Account::<T, I>::hashed_key_for(&d.asset, last),
+			),
+			None => Account::<T, I>::iter_prefix(d.asset.clone()),
+		};
+		// collected first, as the payout may be in the distributed asset
+		let holders: Vec<T::AccountId> = holders.take(max as usize).map(|(who, _)| who).collect();
+
+		d.payout_complete = holders.len() < max as usize;
+		d.payout_cursor = holders.last().cloned().or(d.payout_cursor);
+		for who in holders {
+			// holders that can't receive the payout or have nothing to claim are skipped
+			let _ = with_storage_layer(|| Self::do_claim_distribution(distribution_id, who));
+		}
+		Distributions::<T, I>::insert(distribution_id, d);
+		Ok(())
+	}
+
+	pub fn do_close_distribution(distribution_id: DistributionId) -> DispatchResult {
+		let mut d = Distributions::<T, I>::get(distribution_id)
+			.ok_or(Error::<T, I>::UnknownDistribution)?;
+		let pot = Self::distribution_account(distribution_id);
+
+		if !d.closed {
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= d.expires_at, Error::<T, I>::DistributionNotExpired);
+			ActiveDistributions::<T, I>::mutate(&d.asset, |active| {
+				active.retain(|(id, _)| *id != distribution_id)
+			});
+			let returned = match &d.payout {
+				Payout::Native(_) => Payout::Native(T::Currency::free_balance(&pot)),
+				Payout::Asset(asset, _) => Payout::Asset(asset.clone(), Self::balance(asset.clone(), &pot)),
+			};
+			if !Self::is_zero_payout(&returned) {
+				Self::transfer_payout(&returned, &pot, &d.issuer)?;
+			}
+			d.closed = true;
+			Self::deposit_event(Event::DistributionClosed { distribution_id, returned });
+		}
+
+		let removal = DistributionAccounts::<T, I>::clear_prefix(
+			distribution_id,
+			T::RemoveItemsLimit::get(),
+			None,
+		);
+		if removal.maybe_cursor.is_none() {
+			Distributions::<T, I>::remove(distribution_id);
+			let _ = frame_system::Pallet::<T>::dec_providers(&pot);
+		} else {
+			Distributions::<T, I>::insert(distribution_id, d);
+		}
+		Ok(())
+	}
+
+	/// Records balance of `who` in active distributions of `asset`, if it wasn't recorded yet.
+	/// Must be called before every change of the balance.
+	pub(super) fn snapshot_distributions(asset: &T::AssetId, who: &T::AccountId) {
+		let active = ActiveDistributions::<T, I>::get(asset);
+		if active.is_empty() {
+			return
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+		let balance = Self::balance_with_holds(asset.clone(), who);
+		for (distribution_id, expires_at) in active {
+			if now < expires_at && !DistributionAccounts::<T, I>::contains_key(distribution_id, who)
+			{
+				DistributionAccounts::<T, I>::insert(
+					distribution_id,
+					who,
+					DistributionAccount::Snapshot(balance),
+				);
+			}
+		}
+	}
+
+	/// Account holding funds of a distribution.
+	pub fn distribution_account(distribution_id: DistributionId) -> T::AccountId {
+		let entropy = (b"assets/distribution", Self::index() as u32, distribution_id)
+			.using_encoded(T::Hashing::hash);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
+	/// Returns payout of a distribution that `who` can claim.
+	pub fn claimable_distribution(
+		distribution_id: DistributionId,
+		who: &T::AccountId,
+	) -> Option<PayoutOf<T, I>> {
+		let d = Self::active_distribution(distribution_id).ok()?;
+		let balance = match DistributionAccounts::<T, I>::get(distribution_id, who) {
+			Some(DistributionAccount::Claimed) => return None,
+			Some(DistributionAccount::Snapshot(balance)) => balance,
+			None => Self::balance_with_holds(d.asset.clone(), who),
+		};
+		Some(Self::pro_rata(&d.payout, balance, d.supply))
+	}
+
+	fn active_distribution(
+		distribution_id: DistributionId,
+	) -> Result<DistributionOf<T, I>, DispatchError> {
+		let d = Distributions::<T, I>::get(distribution_id)
+			.ok_or(Error::<T, I>::UnknownDistribution)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(!d.closed && now < d.expires_at, Error::<T, I>::DistributionExpired);
+		Ok(d)
+	}
+
+	fn transfer_payout(
+		payout: &PayoutOf<T, I>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+	) -> DispatchResult {
+		match payout {
+			Payout::Native(amount) => T::Currency::transfer(from, to, *amount, AllowDeath),
+			Payout::Asset(asset, amount) => {
+				let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+				Self::do_transfer(asset.clone(), from, to, *amount, None, f).map(|_| ())
+			},
+		}
+	}
+
+	fn is_zero_payout(payout: &PayoutOf<T, I>) -> bool {
+		match payout {
+			Payout::Native(amount) => amount.is_zero(),
+			Payout::Asset(_, amount) => amount.is_zero(),
+		}
+	}
+
+	fn pro_rata(
+		payout: &PayoutOf<T, I>,
+		balance: T::Balance,
+		supply: T::Balance,
+	) -> PayoutOf<T, I> {
+		let share = |amount: u128| -> u128 {
+			multiply_by_rational_with_rounding(
+				amount,
+				balance.unique_saturated_into(),
+				supply.unique_saturated_into(),
+				Rounding::Down,
+			)
+			.unwrap_or_default()
+		};
+		match payout {
+			Payout::Native(amount) =>
+				Payout::Native(share((*amount).unique_saturated_into()).unique_saturated_into()),
+			Payout::Asset(asset, amount) => Payout::Asset(
+				asset.clone(),
+				share((*amount).unique_saturated_into()).unique_saturated_into(),
+			),
+		}
+	}
+}
@@ -137,18 +137,19 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			return DepositConsequence::Overflow
 		}
 
-		let AssetParameters { eresidency_required } = Parameters::<T, I>::get(&id);
-		if eresidency_required && T::Citizenship::ensure_stocks_allowed(who).is_err() {
+		let parameters = Parameters::<T, I>::get(&id);
+		if Self::ensure_allowed_holder(&id, who, &parameters).is_err() {
 			return DepositConsequence::Blocked
 		}
 
-		if let Some(account) = Account::<T, I>::get(id, who) {
+		let balance = if let Some(account) = Account::<T, I>::get(&id, who) {
 			if account.status.is_blocked() {
 				return DepositConsequence::Blocked
 			}
 			if account.balance.checked_add(&amount).is_none() {
 				return DepositConsequence::Overflow
 			}
+			account.balance
 		} else {
 			if amount < details.min_balance {
 				return DepositConsequence::BelowMinimum
@@ -159,6 +160,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			if details.is_sufficient && details.sufficients.checked_add(1).is_none() {
 				return DepositConsequence::Overflow
 			}
+			if parameters.max_holders.map_or(false, |max| details.accounts >= max) {
+				return DepositConsequence::Blocked
+			}
+			Zero::zero()
+		};
+
+		let supply =
+			if increase_supply { details.supply.saturating_add(amount) } else { details.supply };
+		let new_balance = balance.saturating_add(amount);
+		if Self::exceeds_max_holding(&details, &parameters, who, new_balance, supply) {
+			return DepositConsequence::Blocked
 		}
 
 		DepositConsequence::Success
@@ -193,6 +205,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			return Frozen
 		}
 		if let Some(rest) = account.balance.checked_sub(&amount) {
+			if rest < Self::locked_balance(&id, who) {
+				return Frozen
+			}
 			if let Some(frozen) = T::Freezer::frozen_balance(id.clone(), who) {
 				match frozen.checked_add(&details.min_balance) {
 					Some(required) if rest < required => return Frozen,
@@ -202,7 +217,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			}
 
 			if rest < details.min_balance {
-				if keep_alive {
+				if keep_alive || Self::has_holds(&id, who) {
 					WouldDie
 				} else {
 					ReducedToZero(rest)
@@ -228,20 +243,22 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let account = Account::<T, I>::get(&id, who).ok_or(Error::<T, I>::NoAccount)?;
 		ensure!(!account.status.is_frozen(), Error::<T, I>::Frozen);
 
+		let locked = Self::locked_balance(&id, who);
 		let amount = if let Some(frozen) = T::Freezer::frozen_balance(id, who) {
 			// Frozen balance: account CANNOT be deleted
 			let required =
 				frozen.checked_add(&details.min_balance).ok_or(ArithmeticError::Overflow)?;
 			account.balance.saturating_sub(required)
 		} else {
-			if keep_alive {
-				// We want to keep the account around.
+			if keep_alive || Self::has_holds(&id, who) {
+				// We want to keep the account around. Accounts with holds can't be deleted.
 				account.balance.saturating_sub(details.min_balance)
 			} else {
 				// Don't care if the account dies
 				account.balance
 			}
 		};
+		let amount = amount.min(account.balance.saturating_sub(locked));
 		Ok(amount.min(details.supply))
 	}
 
@@ -325,6 +342,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let deposit = T::AssetAccountDeposit::get();
 		let mut details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+		let parameters = Parameters::<T, I>::get(&id);
+		Self::ensure_allowed_holder(&id, &who, &parameters)?;
+		ensure!(
+			parameters.max_holders.map_or(true, |max| details.accounts < max),
+			TokenError::Blocked
+		);
 		ensure!(
 			!check_depositor || &depositor == &details.admin || &depositor == &details.freezer,
 			Error::<T, I>::NoPermission
@@ -356,6 +379,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let mut details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(matches!(details.status, Live | Frozen), Error::<T, I>::IncorrectStatus);
 		ensure!(account.balance.is_zero() || allow_burn, Error::<T, I>::WouldBurn);
+		Self::snapshot_distributions(&id, &who);
+		Self::checkpoint_balance(&id, &who);
 
 		if let Some(deposit) = account.reason.take_deposit() {
 			T::Currency::unreserve(&who, deposit);
@@ -417,6 +442,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		amount: T::Balance,
 		maybe_check_issuer: Option<T::AccountId>,
 	) -> DispatchResult {
+		Self::checkpoint_supply(&id);
 		Self::increase_balance(id.clone(), beneficiary, amount, |details| -> DispatchResult {
 			if let Some(check_issuer) = maybe_check_issuer {
 				ensure!(check_issuer == details.issuer, Error::<T, I>::NoPermission);
@@ -428,6 +454,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})?;
 
+		Self::lock_minted(&id, beneficiary, amount);
 		Self::deposit_event(Event::Issued { asset_id: id, owner: beneficiary.clone(), amount });
 
 		Ok(())
@@ -451,7 +478,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			return Ok(())
 		}
 
+		ensure!(!Rescales::<T, I>::contains_key(&id), Error::<T, I>::RescaleInProgress);
 		Self::can_increase(id.clone(), beneficiary, amount, true).into_result()?;
+		Self::snapshot_distributions(&id, beneficiary);
+		Self::checkpoint_balance(&id, beneficiary);
 		Asset::<T, I>::try_mutate(&id, |maybe_details| -> DispatchResult {
 			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
 			ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
@@ -501,6 +531,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Error::<T, I>::AssetNotLive
 		);
 
+		Self::checkpoint_supply(&id);
 		let actual = Self::decrease_balance(id.clone(), target, amount, f, |actual, details| {
 			// Check admin rights.
 			if let Some(check_admin) = maybe_check_admin {
@@ -542,6 +573,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 
 		let actual = Self::prep_debit(id.clone(), target, amount, f)?;
+		Self::snapshot_distributions(&id, target);
+		Self::checkpoint_balance(&id, target);
 		let mut target_died: Option<DeadConsequence> = None;
 
 		Asset::<T, I>::try_mutate(&id, |maybe_details| -> DispatchResult {
@@ -615,10 +648,18 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 		let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+		Self::ensure_allowed_sender(&id, source, dest)?;
 
 		// Figure out the debit and credit, together with side-effects.
 		let debit = Self::prep_debit(id.clone(), source, amount, f.into())?;
 		let (credit, maybe_burn) = Self::prep_credit(id.clone(), dest, amount, debit, f.burn_dust)?;
+		Self::snapshot_distributions(&id, source);
+		Self::checkpoint_balance(&id, source);
+		Self::snapshot_distributions(&id, dest);
+		Self::checkpoint_balance(&id, dest);
+		if maybe_burn.is_some() {
+			Self::checkpoint_supply(&id);
+		}
 
 		let mut source_account =
 			Account::<T, I>::get(&id, &source).ok_or(Error::<T, I>::NoAccount)?;
@@ -745,6 +786,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			if let Some(check_owner) = maybe_check_owner {
 				ensure!(details.owner == check_owner, Error::<T, I>::NoPermission);
 			}
+			ensure!(!Rescales::<T, I>::contains_key(&id), Error::<T, I>::RescaleInProgress);
 			details.status = AssetStatus::Destroying;
 
 			Self::deposit_event(Event::DestructionStarted { asset_id: id });
@@ -776,6 +818,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					}
 					if let Remove = Self::dead_account(&who, &mut details, &v.reason, false) {
 						Account::<T, I>::remove(&id, &who);
+						MintLockups::<T, I>::remove(&id, &who);
+						BalanceCheckpoints::<T, I>::remove(&id, &who);
+						Vesting::<T, I>::remove(&id, &who);
+						Holds::<T, I>::remove(&id, &who);
+						Freezes::<T, I>::remove(&id, &who);
+						SystemAccounts::<T, I>::remove(&id, &who);
 						dead_accounts.push(who);
 					} else {
 						// deposit may have been released, need to update `Account`
@@ -850,6 +898,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(T::CallbackHandle::destroyed(&id).is_ok(), Error::<T, I>::CallbackFailed);
 
 			let metadata = Metadata::<T, I>::take(&id);
+			Self::remove_related_company(&id);
+			Self::remove_checkpoints(&id);
 			T::Currency::unreserve(
 				&details.owner,
 				details.deposit.saturating_add(metadata.deposit),
@@ -1020,4 +1070,49 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			})
 			.collect::<Vec<_>>()
 	}
+
+	/// Returns details of the asset.
+	pub fn asset_details(
+		id: T::AssetId,
+	) -> Option<AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>> {
+		Asset::<T, I>::get(id)
+	}
+
+	/// Returns metadata of the asset, if it was set.
+	pub fn asset_metadata(
+		id: T::AssetId,
+	) -> Option<AssetMetadata<DepositBalanceOf<T, I>, Vec<u8>>> {
+		Metadata::<T, I>::try_get(id).ok().map(|m| AssetMetadata {
+			deposit: m.deposit,
+			name: m.name.into_inner(),
+			symbol: m.symbol.into_inner(),
+			decimals: m.decimals,
+			is_frozen: m.is_frozen,
+		})
+	}
+
+	/// Returns compliance rules of the asset.
+	pub fn asset_parameters(id: T::AssetId) -> AssetParametersOf<T> {
+		Parameters::<T, I>::get(id)
+	}
+
+	/// Returns up to `limit` holders of the asset with their balances, starting after
+	/// `start_after`. Holders are returned in storage order, so the last returned holder can be
+	/// used as `start_after` of the next page.
+	pub fn holders(
+		id: T::AssetId,
+		start_after: Option<T::AccountId>,
+		limit: u32,
+	) -> Vec<(T::AccountId, T::Balance)> {
+		match start_after {
+			Some(last) => Account::<T, I>::iter_prefix_from(
+				id.clone(),
+				Account::<T, I>::hashed_key_for(&id, &last),
+			),
+			None => Account::<T, I>::iter_prefix(id),
+		}
+		.take(limit as usize)
+		.map(|(who, account)| (who, account.balance))
+		.collect()
+	}
 }
@@ -0,0 +1,181 @@
+/*
+
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+use crate::*;
+use frame_support::traits::tokens::fungibles::{freeze, hold};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Sets the amount frozen on `who` for `reason`. Zero amount removes the freeze.
+	pub fn do_set_freeze(
+		asset: T::AssetId,
+		who: &T::AccountId,
+		reason: T::FreezeIdentifier,
+		amount: T::Balance,
+	) -> DispatchResult {
+		let mut freezes = Freezes::<T, I>::get(&asset, who);
+		freezes.retain(|f| f.id != reason);
+		if !amount.is_zero() {
+			freezes
+				.try_push(IdAmount { id: reason, amount })
+				.map_err(|_| Error::<T, I>::TooManyFreezes)?;
+		}
+		if freezes.is_empty() {
+			Freezes::<T, I>::remove(&asset, who);
+		} else {
+			Freezes::<T, I>::insert(&asset, who, freezes);
+		}
+
+		Self::deposit_event(Event::FreezeSet { asset_id: asset, who: who.clone(), reason, amount });
+		Ok(())
+	}
+
+	/// Sets the amount held from `who` for `reason`. Zero amount removes the hold. Doesn't
+	/// touch the account balance.
+	pub fn do_set_hold(
+		asset: T::AssetId,
+		who: &T::AccountId,
+		reason: T::RuntimeHoldReason,
+		amount: T::Balance,
+	) -> DispatchResult {
+		let mut holds = Holds::<T, I>::get(&asset, who);
+		holds.retain(|h| h.id != reason);
+		if !amount.is_zero() {
+			holds
+				.try_push(IdAmount { id: reason, amount })
+				.map_err(|_| Error::<T, I>::TooManyHolds)?;
+		}
+
+		Self::snapshot_distributions(&asset, who);
+		Self::checkpoint_balance(&asset, who);
+		if holds.is_empty() {
+			Holds::<T, I>::remove(&asset, who);
+		} else {
+			Holds::<T, I>::insert(&asset, who, holds);
+		}
+
+		Self::deposit_event(Event::HoldSet { asset_id: asset, who: who.clone(), reason, amount });
+		Ok(())
+	}
+
+	/// Returns all holds of `who`.
+	pub fn holds(asset: T::AssetId, who: T::AccountId) -> Vec<(T::RuntimeHoldReason, T::Balance)> {
+		Holds::<T, I>::get(asset, who).into_iter().map(|h| (h.id, h.amount)).collect()
+	}
+
+	/// Returns all freezes of `who`.
+	pub fn freezes(asset: T::AssetId, who: T::AccountId) -> Vec<(T::FreezeIdentifier, T::Balance)> {
+		Freezes::<T, I>::get(asset, who).into_iter().map(|f| (f.id, f.amount)).collect()
+	}
+
+	/// Returns the sum of all holds of `who`.
+	pub fn held_balance(asset: T::AssetId, who: &T::AccountId) -> T::Balance {
+		Holds::<T, I>::get(asset, who)
+			.iter()
+			.fold(Zero::zero(), |acc: T::Balance, h| acc.saturating_add(h.amount))
+	}
+
+	/// Returns the balance of `who` including held funds.
+	pub fn balance_with_holds(asset: T::AssetId, who: &T::AccountId) -> T::Balance {
+		Self::balance(asset.clone(), who).saturating_add(Self::held_balance(asset, who))
+	}
+
+	/// Returns the part of the balance of `who` that can't be moved because of freezes. Freezes
+	/// don't stack and apply to held funds first.
+	pub fn freeze_locked_balance(asset: &T::AssetId, who: &T::AccountId) -> T::Balance {
+		let frozen = Freezes::<T, I>::get(asset, who)
+			.iter()
+			.fold(Zero::zero(), |acc: T::Balance, f| acc.max(f.amount));
+		if frozen.is_zero() {
+			return Zero::zero()
+		}
+		frozen.saturating_sub(Self::held_balance(asset.clone(), who))
+	}
+
+	/// Accounts with holds can't be reaped.
+	pub(super) fn has_holds(asset: &T::AssetId, who: &T::AccountId) -> bool {
+		Holds::<T, I>::decode_len(asset, who).unwrap_or(0) > 0
+	}
+}
+
+impl<T: Config<I>, I: 'static> hold::Inspect<T::AccountId> for Pallet<T, I> {
+	type Reason = T::RuntimeHoldReason;
+
+	fn total_balance_on_hold(asset: T::AssetId, who: &T::AccountId) -> T::Balance {
+		Self::held_balance(asset, who)
+	}
+
+	fn balance_on_hold(asset: T::AssetId, reason: &Self::Reason, who: &T::AccountId) -> T::Balance {
+		Holds::<T, I>::get(asset, who)
+			.iter()
+			.find(|h| &h.id == reason)
+			.map_or_else(Zero::zero, |h| h.amount)
+	}
+
+	fn hold_available(asset: T::AssetId, reason: &Self::Reason, who: &T::AccountId) -> bool {
+		let holds = Holds::<T, I>::get(&asset, who);
+		Account::<T, I>::contains_key(&asset, who) &&
+			(holds.len() < T::MaxHolds::get() as usize || holds.iter().any(|h| &h.id == reason))
+	}
+}
+
+impl<T: Config<I>, I: 'static> hold::Unbalanced<T::AccountId> for Pallet<T, I> {
+	fn set_balance_on_hold(
+		asset: T::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		Self::do_set_hold(asset, who, *reason, amount)
+	}
+}
+
+impl<T: Config<I>, I: 'static> hold::Mutate<T::AccountId> for Pallet<T, I> {}
+
+impl<T: Config<I>, I: 'static> freeze::Inspect<T::AccountId> for Pallet<T, I> {
+	type Id = T::FreezeIdentifier;
+
+	fn balance_frozen(asset: T::AssetId, id: &Self::Id, who: &T::AccountId) -> T::Balance {
+		Freezes::<T, I>::get(asset, who)
+			.iter()
+			.find(|f| &f.id == id)
+			.map_or_else(Zero::zero, |f| f.amount)
+	}
+
+	fn can_freeze(asset: T::AssetId, id: &Self::Id, who: &T::AccountId) -> bool {
+		let freezes = Freezes::<T, I>::get(asset, who);
+		freezes.len() < T::MaxFreezes::get() as usize || freezes.iter().any(|f| &f.id == id)
+	}
+}
+
+impl<T: Config<I>, I: 'static> freeze::Mutate<T::AccountId> for Pallet<T, I> {
+	fn set_freeze(
+		asset: T::AssetId,
+		id: &Self::Id,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		Self::do_set_freeze(asset, who, *id, amount)
+	}
+
+	fn extend_freeze(
+		asset: T::AssetId,
+		id: &Self::Id,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		let current =
+			<Self as freeze::Inspect<T::AccountId>>::balance_frozen(asset.clone(), id, who);
+		Self::do_set_freeze(asset, who, *id, current.max(amount))
+	}
+
+	fn thaw(asset: T::AssetId, id: &Self::Id, who: &T::AccountId) -> DispatchResult {
+		Self::do_set_freeze(asset, who, *id, Zero::zero())
+	}
+}
@@ -46,7 +46,7 @@ impl<T: Config<I>, I: 'static> fungibles::Inspect<<T as SystemConfig>::AccountId
 	}
 
 	fn total_balance(asset: Self::AssetId, who: &<T as SystemConfig>::AccountId) -> Self::Balance {
-		Pallet::<T, I>::balance(asset, who)
+		Pallet::<T, I>::balance_with_holds(asset, who)
 	}
 
 	fn reducible_balance(
@@ -82,11 +82,28 @@ impl<T: Config<I>, I: 'static> fungibles::Inspect<<T as SystemConfig>::AccountId
 }
 
 impl<T: Config<I>, I: 'static> fungibles::Mutate<<T as SystemConfig>::AccountId> for Pallet<T, I> {
+	fn transfer(
+		asset: Self::AssetId,
+		source: &<T as SystemConfig>::AccountId,
+		dest: &<T as SystemConfig>::AccountId,
+		amount: Self::Balance,
+		preservation: Preservation,
+	) -> Result<Self::Balance, DispatchError> {
+		// goes through `do_transfer` so that senders to system accounts are checked
+		let f = TransferFlags {
+			keep_alive: preservation != Expendable,
+			best_effort: false,
+			burn_dust: false,
+		};
+		Self::do_transfer(asset, source, dest, amount, None, f)
+	}
+
 	fn done_mint_into(
 		asset_id: Self::AssetId,
 		beneficiary: &<T as SystemConfig>::AccountId,
 		amount: Self::Balance,
 	) {
+		Self::lock_minted(&asset_id, beneficiary, amount);
 		Self::deposit_event(Event::Issued { asset_id, owner: beneficiary.clone(), amount })
 	}
 
@@ -134,6 +151,7 @@ impl<T: Config<I>, I: 'static> fungibles::Unbalanced<T::AccountId> for Pallet<T,
 		Err(DispatchError::Unavailable)
 	}
 	fn set_total_issuance(id: T::AssetId, amount: Self::Balance) {
+		Self::checkpoint_supply(&id);
 		Asset::<T, I>::mutate_exists(id, |maybe_asset| {
 			if let Some(ref mut asset) = maybe_asset {
 				asset.supply = amount
@@ -155,13 +155,20 @@ mod functions;
 mod impl_fungibles;
 mod impl_stored_map;
 mod types;
-mod eresidency;
+mod company;
+mod compliance;
+mod distribution;
+mod checkpoint;
+mod resolution;
+mod vesting;
+mod rescale;
+mod hold;
 pub use types::*;
 
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedSub, Saturating, StaticLookup, Zero},
-	ArithmeticError, DispatchError, TokenError,
+	ArithmeticError, DispatchError, Permill, TokenError,
 };
 use sp_std::prelude::*;
 
@@ -173,7 +180,7 @@ use frame_support::{
 	traits::{
 		tokens::{fungibles, DepositConsequence, WithdrawConsequence},
 		BalanceStatus::Reserved,
-		Currency, EnsureOriginWithArg, ReservableCurrency, StoredMap,
+		Contains, Currency, EnsureOriginWithArg, ReservableCurrency, StoredMap,
 	},
 };
 use frame_system::Config as SystemConfig;
@@ -210,7 +217,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -219,12 +226,17 @@ pub mod pallet {
 	#[cfg(feature = "runtime-benchmarks")]
 	pub trait BenchmarkHelper<AssetIdParameter> {
 		fn create_asset_id_parameter(id: u32) -> AssetIdParameter;
+		/// Returns a company contained in `Config::Companies`, creating it if needed.
+		fn create_company() -> AssetRelatedCompany;
 	}
 	#[cfg(feature = "runtime-benchmarks")]
 	impl<AssetIdParameter: From<u32>> BenchmarkHelper<AssetIdParameter> for () {
 		fn create_asset_id_parameter(id: u32) -> AssetIdParameter {
 			id.into()
 		}
+		fn create_company() -> AssetRelatedCompany {
+			1
+		}
 	}
 
 	#[pallet::config]
@@ -325,6 +337,40 @@ pub mod pallet {
 		type BenchmarkHelper: BenchmarkHelper<Self::AssetIdParameter>;
 
 		type Citizenship: liberland_traits::CitizenshipChecker<Self::AccountId>;
+
+		/// Ids of registered companies that assets can be related to.
+		type Companies: Contains<AssetRelatedCompany>;
+
+		/// Maximum number of distributions of a single asset that weren't closed yet.
+		#[pallet::constant]
+		type MaxActiveDistributions: Get<u32>;
+
+		/// Maximum number of balance checkpoints kept per account. Older checkpoints are
+		/// dropped.
+		#[pallet::constant]
+		type MaxCheckpoints: Get<u32>;
+
+		/// Maximum number of shareholder resolutions of a single asset that weren't closed yet.
+		#[pallet::constant]
+		type MaxOpenResolutions: Get<u32>;
+
+		/// Maximum number of vesting schedules of an account for a single asset.
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+
+		/// Reasons for which funds of an account can be held.
+		type RuntimeHoldReason: Parameter + Member + MaxEncodedLen + Ord + Copy;
+
+		/// Reasons for which funds of an account can be frozen.
+		type FreezeIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
+
+		/// Maximum number of holds of an account for a single asset.
+		#[pallet::constant]
+		type MaxHolds: Get<u32>;
+
+		/// Maximum number of freezes of an account for a single asset.
+		#[pallet::constant]
+		type MaxFreezes: Get<u32>;
 	}
 
 	#[pallet::storage]
@@ -372,15 +418,53 @@ pub mod pallet {
 	>;
 
 	#[pallet::storage]
-	/// Eresidency requirements of asset
+	/// Compliance rules of asset
 	pub(super) type Parameters<T: Config<I>, I: 'static = ()> = StorageMap<
 		_,
 		Blake2_128Concat,
 		T::AssetId,
-		AssetParameters,
+		AssetParametersOf<T>,
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	/// Accounts created through `AccountTouch`, like liquidity pools. They're exempt from holder
+	/// requirements, instead accounts sending funds to them must meet the requirements.
+	pub(super) type SystemAccounts<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Accounts on the compliance list of asset, used as an allowlist or a blocklist depending on
+	/// asset's parameters
+	pub(super) type ComplianceList<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Minted funds that can't be moved yet, if asset has a lockup period
+	pub(super) type MintLockups<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		MintLockupOf<T, I>,
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 		/// Related company of asset
 		pub(super) type RelatedCompany<T: Config<I>, I: 'static = ()> = StorageMap<
@@ -391,6 +475,158 @@ pub mod pallet {
 			ValueQuery,
 		>;
 
+	#[pallet::storage]
+	/// Id of the next distribution
+	pub(super) type NextDistributionId<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, DistributionId, ValueQuery>;
+
+	#[pallet::storage]
+	/// Distributions of funds to asset holders
+	pub(super) type Distributions<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, DistributionId, DistributionOf<T, I>, OptionQuery>;
+
+	#[pallet::storage]
+	/// Distributions of asset that weren't closed yet, with their expiry blocks
+	pub(super) type ActiveDistributions<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		BoundedVec<(DistributionId, BlockNumberFor<T>), T::MaxActiveDistributions>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Snapshot balances and claims of accounts in distributions
+	pub(super) type DistributionAccounts<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		DistributionId,
+		Blake2_128Concat,
+		T::AccountId,
+		DistributionAccount<T::Balance>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Assets related to company, reverse of `RelatedCompany`
+	pub(super) type CompanyAssets<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetRelatedCompany,
+		Blake2_128Concat,
+		T::AssetId,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Assets with balance checkpoints enabled, with the block they were enabled at
+	pub(super) type CheckpointsSince<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::storage]
+	/// Balance history of accounts of checkpointed assets
+	pub(super) type BalanceCheckpoints<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		CheckpointsOf<T, I>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Supply history of checkpointed assets
+	pub(super) type SupplyCheckpoints<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, CheckpointsOf<T, I>, OptionQuery>;
+
+	#[pallet::storage]
+	/// Id of the next shareholder resolution
+	pub(super) type NextResolutionId<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, ResolutionId, ValueQuery>;
+
+	#[pallet::storage]
+	/// Shareholder resolutions
+	pub(super) type Resolutions<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ResolutionId, ResolutionOf<T, I>, OptionQuery>;
+
+	#[pallet::storage]
+	/// Votes on shareholder resolutions: aye and number of votes
+	pub(super) type ResolutionVotes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ResolutionId,
+		Blake2_128Concat,
+		T::AccountId,
+		(bool, T::Balance),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Resolutions of asset that weren't closed yet, with their snapshot blocks
+	pub(super) type OpenResolutions<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		BoundedVec<(ResolutionId, BlockNumberFor<T>), T::MaxOpenResolutions>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Balances at the snapshot block of open resolutions, kept when the balance checkpoint
+	/// holding them was dropped
+	pub(super) type ResolutionSnapshots<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ResolutionId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Vesting schedules of asset accounts
+	pub(super) type Vesting<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<VestingScheduleOf<T, I>, T::MaxVestingSchedules>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Splits and consolidations of assets in progress
+	pub(super) type Rescales<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, RescaleOf<T, I>, OptionQuery>;
+
+	#[pallet::storage]
+	/// Named holds of asset accounts. Held funds aren't part of the account balance.
+	pub(super) type Holds<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		HoldsOf<T, I>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Named freezes of asset accounts. The largest one applies.
+	pub(super) type Freezes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		FreezesOf<T, I>,
+		ValueQuery,
+	>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -550,13 +786,109 @@ pub mod pallet {
 		/// Asset Parameters set
 		ParametersSet {
 			asset_id: T::AssetId,
-			parameters: AssetParameters,
+			parameters: AssetParametersOf<T>,
+		},
+		/// Account was added to or removed from the compliance list
+		ComplianceListUpdated {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			listed: bool,
 		},
 		/// Related company set
 		RelatedCompanySet {
 			asset_id: T::AssetId,
 			company: AssetRelatedCompany,
 		},
+		/// Funds were deposited for distribution to asset holders
+		DistributionCreated {
+			distribution_id: DistributionId,
+			asset_id: T::AssetId,
+			payout: PayoutOf<T, I>,
+			snapshot: BlockNumberFor<T>,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// Holder's share of a distribution was paid out
+		DistributionClaimed {
+			distribution_id: DistributionId,
+			who: T::AccountId,
+			payout: PayoutOf<T, I>,
+		},
+		/// Distribution expired and unclaimed funds were returned to the issuer
+		DistributionClosed {
+			distribution_id: DistributionId,
+			returned: PayoutOf<T, I>,
+		},
+		/// Balance checkpoints were enabled for an asset
+		CheckpointsEnabled {
+			asset_id: T::AssetId,
+		},
+		/// Shareholder resolution was proposed
+		ResolutionProposed {
+			resolution_id: ResolutionId,
+			asset_id: T::AssetId,
+			chair: T::AccountId,
+			snapshot: BlockNumberFor<T>,
+			ends_at: BlockNumberFor<T>,
+		},
+		/// Holder voted on a shareholder resolution
+		ResolutionVoted {
+			resolution_id: ResolutionId,
+			who: T::AccountId,
+			aye: bool,
+			votes: T::Balance,
+		},
+		/// Voting on a shareholder resolution ended
+		ResolutionClosed {
+			resolution_id: ResolutionId,
+			approved: bool,
+			ayes: T::Balance,
+			nays: T::Balance,
+		},
+		/// Funds were granted to an account with a vesting schedule
+		VestingScheduleAdded {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			grantor: T::AccountId,
+			amount: T::Balance,
+		},
+		/// Vesting schedules of an account were vested, merged or split
+		VestingSchedulesUpdated {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			locked: T::Balance,
+		},
+		/// Grantor took back funds that didn't vest yet
+		VestingRevoked {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			returned: T::Balance,
+		},
+		/// Split or consolidation of an asset started. The asset is frozen until it finishes.
+		RescaleStarted {
+			asset_id: T::AssetId,
+			numerator: u32,
+			denominator: u32,
+		},
+		/// Split or consolidation of an asset finished
+		RescaleFinished {
+			asset_id: T::AssetId,
+			supply: T::Balance,
+			min_balance: T::Balance,
+		},
+		/// Amount held from an account for a reason changed
+		HoldSet {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			reason: T::RuntimeHoldReason,
+			amount: T::Balance,
+		},
+		/// Amount frozen on an account for a reason changed. Zero means thawed.
+		FreezeSet {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			reason: T::FreezeIdentifier,
+			amount: T::Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -604,6 +936,54 @@ pub mod pallet {
 		NotFrozen,
 		/// Callback action resulted in error
 		CallbackFailed,
+		/// The company isn't registered.
+		UnknownCompany,
+		/// Distribution doesn't exist.
+		UnknownDistribution,
+		/// Payout, duration and asset supply must be non-zero.
+		InvalidDistribution,
+		/// Asset has too many active distributions.
+		TooManyDistributions,
+		/// Distribution expired.
+		DistributionExpired,
+		/// Distribution didn't expire yet.
+		DistributionNotExpired,
+		/// Payout was already claimed.
+		AlreadyClaimed,
+		/// There's nothing to claim.
+		NothingToClaim,
+		/// Balance checkpoints are already enabled for the asset.
+		CheckpointsAlreadyEnabled,
+		/// Balance checkpoints aren't available for the snapshot block.
+		NoCheckpoints,
+		/// Resolution doesn't exist.
+		UnknownResolution,
+		/// Voting duration must be non-zero.
+		InvalidResolution,
+		/// Asset has too many open resolutions.
+		TooManyResolutions,
+		/// Voting on the resolution ended.
+		ResolutionEnded,
+		/// Voting on the resolution didn't end yet.
+		ResolutionNotEnded,
+		/// Account had no balance at the snapshot block.
+		NoVotingPower,
+		/// Vesting schedule doesn't exist or its amount or terms are invalid.
+		InvalidVestingSchedule,
+		/// Account has too many vesting schedules.
+		TooManyVestingSchedules,
+		/// Only schedules with the same grantor and terms can be merged.
+		VestingScheduleMismatch,
+		/// Ratio must be non-zero and not equal to one.
+		InvalidRatio,
+		/// The asset is being split or consolidated.
+		RescaleInProgress,
+		/// The asset isn't being split or consolidated.
+		NoRescale,
+		/// Account has too many holds.
+		TooManyHolds,
+		/// Account has too many freezes.
+		TooManyFreezes,
 	}
 
 	#[pallet::call(weight(<T as Config<I>>::WeightInfo))]
@@ -1069,6 +1449,7 @@ pub mod pallet {
 				let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
 				ensure!(origin == d.admin, Error::<T, I>::NoPermission);
 				ensure!(d.status == AssetStatus::Frozen, Error::<T, I>::NotFrozen);
+				ensure!(!Rescales::<T, I>::contains_key(&id), Error::<T, I>::RescaleInProgress);
 
 				d.status = AssetStatus::Live;
 
@@ -1682,7 +2063,7 @@ pub mod pallet {
 		pub fn set_parameters(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
-			parameters: AssetParameters,
+			parameters: AssetParametersOf<T>,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
 			let id: T::AssetId = id.into();
@@ -1699,7 +2080,7 @@ pub mod pallet {
 		pub fn force_set_parameters(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
-			parameters: AssetParameters,
+			parameters: AssetParametersOf<T>,
 		) -> DispatchResult {
 			T::ForceOrigin::ensure_origin(origin)?;
 			let id: T::AssetId = id.into();
@@ -1707,13 +2088,14 @@ pub mod pallet {
 		}
 		/// Set the related company for an asset.
 		///
-		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`. The company
+		/// must be registered.
 		///
 		/// Emits `RelatedCompanySet`.
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(102)]
-		#[pallet::weight(T::WeightInfo::set_parameters())]
+		#[pallet::weight(T::WeightInfo::set_related_company())]
 		pub fn set_related_company(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
@@ -1721,21 +2103,18 @@ pub mod pallet {
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
 			let id: T::AssetId = id.into();
-			let asset = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
-			ensure!(signer == asset.owner, Error::<T, I>::NoPermission);
-
-			RelatedCompany::<T, I>::insert(&id, company);
-			Self::deposit_event(Event::RelatedCompanySet { asset_id: id, company });
-			Ok(())
+			Self::do_set_related_company(id, company, Some(signer))
 		}
 
 		/// Force set the related company for an asset.
 		///
-		/// Origin must be ForceOrigin
+		/// Origin must be ForceOrigin. The company must be registered.
+		///
+		/// Emits `RelatedCompanySet`.
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(103)]
-		#[pallet::weight(T::WeightInfo::set_parameters())]
+		#[pallet::weight(T::WeightInfo::force_set_related_company())]
 		pub fn force_set_related_company(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
@@ -1743,13 +2122,469 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::ForceOrigin::ensure_origin(origin)?;
 			let id: T::AssetId = id.into();
-			let asset = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			Self::do_set_related_company(id, company, None)
+		}
 
+		/// Add an account to or remove it from the compliance list of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Issuer of the asset `id`.
+		///
+		/// The list is used as an allowlist or a blocklist, depending on asset's parameters.
+		///
+		/// Emits `ComplianceListUpdated`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(104)]
+		#[pallet::weight(T::WeightInfo::set_compliance_list())]
+		pub fn set_compliance_list(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+			listed: bool,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
 			let id: T::AssetId = id.into();
-			RelatedCompany::<T, I>::insert(&id, company);
-			Ok(())
+			let who = T::Lookup::lookup(who)?;
+			Self::do_set_compliance_list(id, who, listed, Some(signer))
+		}
+
+		/// Deposit funds to be distributed to holders of an asset, pro-rata to their balances at
+		/// the current block.
+		///
+		/// Origin must be Signed and the sender should be the Issuer of the asset `id`.
+		///
+		/// - `id`: The asset whose holders receive the payout.
+		/// - `payout`: Funds to distribute, in the native currency or an asset.
+		/// - `duration`: Number of blocks during which holders can claim the payout. Unclaimed
+		///   funds are returned to the issuer afterwards.
+		///
+		/// Emits `DistributionCreated`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(105)]
+		#[pallet::weight(T::WeightInfo::create_distribution())]
+		pub fn create_distribution(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			payout: PayoutOf<T, I>,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+			Self::do_create_distribution(signer, id, payout, duration)
+		}
+
+		/// Claim sender's share of a distribution.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `distribution_id`: The distribution to claim.
+		///
+		/// Emits `DistributionClaimed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(106)]
+		#[pallet::weight(T::WeightInfo::claim_distribution())]
+		pub fn claim_distribution(
+			origin: OriginFor<T>,
+			distribution_id: DistributionId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_claim_distribution(distribution_id, who)
 		}
 
+		/// Pay out shares of a distribution to current holders of the asset that didn't claim
+		/// them yet. Continues from where the previous call stopped.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `distribution_id`: The distribution to pay out.
+		/// - `max`: Maximum number of holders to process, capped at `RemoveItemsLimit`.
+		///
+		/// Holders whose asset accounts were removed since the snapshot need to claim their
+		/// shares with `claim_distribution`.
+		///
+		/// Emits `DistributionClaimed` for each paid holder.
+		///
+		/// Weight: `O(max)`
+		#[pallet::call_index(107)]
+		#[pallet::weight(T::WeightInfo::payout_distribution(
+			(*max).min(T::RemoveItemsLimit::get())
+		))]
+		pub fn payout_distribution(
+			origin: OriginFor<T>,
+			distribution_id: DistributionId,
+			max: u32,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_payout_distribution(distribution_id, max.min(T::RemoveItemsLimit::get()))
+		}
+
+		/// Return unclaimed funds of an expired distribution to the issuer and clean up its
+		/// storage. If there are more than `RemoveItemsLimit` accounts to clean up, must be
+		/// called multiple times.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `distribution_id`: The expired distribution.
+		///
+		/// Emits `DistributionClosed`.
+		///
+		/// Weight: `O(RemoveItemsLimit)`
+		#[pallet::call_index(108)]
+		#[pallet::weight(T::WeightInfo::close_distribution(T::RemoveItemsLimit::get()))]
+		pub fn close_distribution(
+			origin: OriginFor<T>,
+			distribution_id: DistributionId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_close_distribution(distribution_id)
+		}
+
+		/// Start recording balance checkpoints of an asset. Once enabled, checkpoints can't be
+		/// disabled.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		///
+		/// Emits `CheckpointsEnabled`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(109)]
+		#[pallet::weight(T::WeightInfo::enable_checkpoints())]
+		pub fn enable_checkpoints(origin: OriginFor<T>, id: T::AssetIdParameter) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+			Self::do_enable_checkpoints(id, Some(owner))
+		}
+
+		/// Propose a resolution to holders of an asset. Votes are weighted by balances at the
+		/// end of the previous block, so checkpoints must have been enabled before it. At most
+		/// `MaxOpenResolutions` resolutions of an asset can be open at once.
+		///
+		/// Origin must be Signed and the sender should be the Owner, Issuer, Admin or Freezer of
+		/// the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `description`: Text or hash of the resolution.
+		/// - `duration`: Number of blocks the voting lasts.
+		/// - `quorum`: Minimal part of the supply that must vote for the resolution to pass.
+		///
+		/// Emits `ResolutionProposed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(110)]
+		#[pallet::weight(T::WeightInfo::propose_resolution(description.len() as u32))]
+		pub fn propose_resolution(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			description: Vec<u8>,
+			duration: BlockNumberFor<T>,
+			quorum: Permill,
+		) -> DispatchResult {
+			let chair = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+			Self::do_propose_resolution(chair, id, description, duration, quorum)
+		}
+
+		/// Vote on a shareholder resolution with sender's balance at the snapshot block.
+		/// Replaces previous vote of the sender.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `resolution_id`: The resolution to vote on.
+		/// - `aye`: Whether to vote for the resolution.
+		///
+		/// Emits `ResolutionVoted`.
+		///
+		/// Weight: `O(MaxCheckpoints)`
+		#[pallet::call_index(111)]
+		#[pallet::weight(T::WeightInfo::vote_resolution())]
+		pub fn vote_resolution(
+			origin: OriginFor<T>,
+			resolution_id: ResolutionId,
+			aye: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_vote_resolution(resolution_id, who, aye)
+		}
+
+		/// Tally votes of an ended shareholder resolution and clean up its storage. If there are
+		/// more than `RemoveItemsLimit` votes to clean up, must be called multiple times.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `resolution_id`: The ended resolution.
+		///
+		/// Emits `ResolutionClosed`.
+		///
+		/// Weight: `O(RemoveItemsLimit)`
+		#[pallet::call_index(112)]
+		#[pallet::weight(T::WeightInfo::close_resolution(T::RemoveItemsLimit::get()))]
+		pub fn close_resolution(
+			origin: OriginFor<T>,
+			resolution_id: ResolutionId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_close_resolution(resolution_id)
+		}
+
+		/// Move some assets from the sender account to another, locked with a vesting schedule.
+		///
+		/// Origin must be Signed. Only the Issuer of the asset `id` can make revocable grants.
+		/// Grants from other accounts can only use half of the `MaxVestingSchedules` slots of
+		/// `target`, the rest is kept for the Issuer.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `target`: The account to be credited.
+		/// - `amount`: The amount to transfer and lock.
+		/// - `start`: Block at which the vesting starts.
+		/// - `curve`: How the funds unlock after the start.
+		/// - `revocable`: Whether the sender can take back funds that didn't vest yet.
+		///
+		/// Emits `Transferred` and `VestingScheduleAdded`.
+		///
+		/// Weight: `O(MaxVestingSchedules)`
+		#[pallet::call_index(113)]
+		#[pallet::weight(T::WeightInfo::vested_transfer())]
+		pub fn vested_transfer(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			target: AccountIdLookupOf<T>,
+			#[pallet::compact] amount: T::Balance,
+			start: BlockNumberFor<T>,
+			curve: VestingCurve<BlockNumberFor<T>>,
+			revocable: bool,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(target)?;
+			let id: T::AssetId = id.into();
+			Self::do_vested_transfer(id, origin, dest, amount, start, curve, revocable)
+		}
+
+		/// Remove fully vested schedules of an account.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `target`: The account whose schedules are vested.
+		///
+		/// Emits `VestingSchedulesUpdated`.
+		///
+		/// Weight: `O(MaxVestingSchedules)`
+		#[pallet::call_index(114)]
+		#[pallet::weight(T::WeightInfo::vest())]
+		pub fn vest(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			target: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(target)?;
+			let id: T::AssetId = id.into();
+			Self::do_vest(id, who)
+		}
+
+		/// Merge two vesting schedules of the sender with the same grantor and terms into one.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `schedule1_index`: Index of the first schedule.
+		/// - `schedule2_index`: Index of the second schedule.
+		///
+		/// Emits `VestingSchedulesUpdated`.
+		///
+		/// Weight: `O(MaxVestingSchedules)`
+		#[pallet::call_index(115)]
+		#[pallet::weight(T::WeightInfo::merge_vesting_schedules())]
+		pub fn merge_vesting_schedules(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			schedule1_index: u32,
+			schedule2_index: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+			Self::do_merge_vesting_schedules(id, who, schedule1_index, schedule2_index)
+		}
+
+		/// Split `amount` off a vesting schedule of the sender into a new schedule with the same
+		/// grantor and terms.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `schedule_index`: Index of the schedule to split.
+		/// - `amount`: Amount of the new schedule.
+		///
+		/// Emits `VestingSchedulesUpdated`.
+		///
+		/// Weight: `O(MaxVestingSchedules)`
+		#[pallet::call_index(116)]
+		#[pallet::weight(T::WeightInfo::split_vesting_schedule())]
+		pub fn split_vesting_schedule(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			schedule_index: u32,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+			Self::do_split_vesting_schedule(id, who, schedule_index, amount)
+		}
+
+		/// Take back funds of a revocable vesting schedule that didn't vest yet.
+		///
+		/// Origin must be Signed and the sender should be the grantor of the schedule.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `target`: The account with the schedule.
+		/// - `schedule_index`: Index of the schedule to revoke.
+		///
+		/// Emits `VestingRevoked`.
+		///
+		/// Weight: `O(MaxVestingSchedules)`
+		#[pallet::call_index(117)]
+		#[pallet::weight(T::WeightInfo::revoke_vesting())]
+		pub fn revoke_vesting(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			target: AccountIdLookupOf<T>,
+			schedule_index: u32,
+		) -> DispatchResult {
+			let grantor = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(target)?;
+			let id: T::AssetId = id.into();
+			Self::do_revoke_vesting(id, grantor, who, schedule_index)
+		}
+
+		/// Start a split or consolidation of an asset. Every balance, approval, mint lockup,
+		/// vesting schedule, the supply and `min_balance` are multiplied by
+		/// `numerator / denominator` and rounded down. `min_balance` is at least 1.
+		///
+		/// The asset is frozen until `continue_rescale` processes all accounts and approvals.
+		/// Asset conversion pools hold the asset in regular accounts, so their reserves are
+		/// rescaled as well.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of
+		/// the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `numerator`: Numerator of the ratio, e.g. 2 for a 2-for-1 split.
+		/// - `denominator`: Denominator of the ratio, e.g. 10 for a 1-for-10 consolidation.
+		///
+		/// Emits `RescaleStarted`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(118)]
+		#[pallet::weight(T::WeightInfo::start_rescale())]
+		pub fn start_rescale(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			numerator: u32,
+			denominator: u32,
+		) -> DispatchResult {
+			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			let id: T::AssetId = id.into();
+			Self::do_start_rescale(id, numerator, denominator, maybe_check_owner)
+		}
+
+		/// Rescale up to `RemoveItemsLimit` accounts or approvals of an asset that's being split
+		/// or consolidated. Thaws the asset once everything is rescaled.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset.
+		///
+		/// Emits `RescaleFinished` when done.
+		///
+		/// Weight: `O(RemoveItemsLimit)`
+		#[pallet::call_index(119)]
+		#[pallet::weight(
+			T::WeightInfo::continue_rescale_accounts(T::RemoveItemsLimit::get())
+				.max(T::WeightInfo::continue_rescale_approvals(T::RemoveItemsLimit::get()))
+		)]
+		pub fn continue_rescale(origin: OriginFor<T>, id: T::AssetIdParameter) -> DispatchResult {
+			ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+			Self::do_continue_rescale(id, T::RemoveItemsLimit::get())
+		}
+
+		/// Freeze `amount` of the balance of account `who` for `reason`, e.g. to enforce a court
+		/// order. Replaces the previous freeze for the same reason. Freezes for different reasons
+		/// don't stack - the largest one applies.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account to be frozen.
+		/// - `reason`: The reason of the freeze.
+		/// - `amount`: The amount to freeze.
+		///
+		/// Emits `FreezeSet`.
+		///
+		/// Weight: `O(MaxFreezes)`
+		#[pallet::call_index(120)]
+		#[pallet::weight(T::WeightInfo::freeze_amount())]
+		pub fn freeze_amount(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+			reason: T::FreezeIdentifier,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+
+			let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(
+				d.status == AssetStatus::Live || d.status == AssetStatus::Frozen,
+				Error::<T, I>::AssetNotLive
+			);
+			ensure!(origin == d.freezer, Error::<T, I>::NoPermission);
+			let who = T::Lookup::lookup(who)?;
+			ensure!(Account::<T, I>::contains_key(&id, &who), Error::<T, I>::NoAccount);
+
+			Self::do_set_freeze(id, &who, reason, amount)
+		}
+
+		/// Remove the freeze of account `who` for `reason`.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account to be thawed.
+		/// - `reason`: The reason of the freeze.
+		///
+		/// Emits `FreezeSet`.
+		///
+		/// Weight: `O(MaxFreezes)`
+		#[pallet::call_index(121)]
+		#[pallet::weight(T::WeightInfo::thaw_amount())]
+		pub fn thaw_amount(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+			reason: T::FreezeIdentifier,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+
+			let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(
+				d.status == AssetStatus::Live || d.status == AssetStatus::Frozen,
+				Error::<T, I>::AssetNotLive
+			);
+			ensure!(origin == d.freezer, Error::<T, I>::NoPermission);
+			let who = T::Lookup::lookup(who)?;
+
+			Self::do_set_freeze(id, &who, reason, Zero::zero())
+		}
 	}
 
 	/// Implements [`AccountTouch`] trait.
@@ -1763,7 +2598,15 @@ pub mod pallet {
 		}
 
 		fn touch(asset: T::AssetId, who: T::AccountId, depositor: T::AccountId) -> DispatchResult {
-			Self::do_touch(asset, who, depositor, false)
+			// system accounts don't have to meet holder requirements, so `who` must be one before
+			// its asset account is created
+			let was_system_account = Self::is_system_account(&asset, &who);
+			SystemAccounts::<T, I>::insert(&asset, &who, ());
+			let result = Self::do_touch(asset.clone(), who.clone(), depositor, false);
+			if result.is_err() && !was_system_account {
+				SystemAccounts::<T, I>::remove(&asset, &who);
+			}
+			result
 		}
 	}
 
@@ -136,3 +136,282 @@ pub mod v1 {
 		}
 	}
 }
+
+pub mod v2 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	#[derive(Decode)]
+	pub struct OldAssetParameters {
+		pub eresidency_required: bool,
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct AssetParameters {
+		pub eresidency_required: bool,
+		pub citizenship_required: bool,
+	}
+
+	#[frame_support::storage_alias]
+	pub type Parameters<T: Config<I>, I: 'static> =
+		StorageMap<Pallet<T, I>, Blake2_128Concat, <T as Config<I>>::AssetId, AssetParameters>;
+
+	/// Adds `citizenship_required` to `AssetParameters`, disabled for all existing assets.
+	pub struct MigrateToV2<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV2<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let current_version = Pallet::<T, I>::current_storage_version();
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version == 1 && current_version >= 2 {
+				let mut translated = 0u64;
+				Parameters::<T, I>::translate::<OldAssetParameters, _>(|_key, old_value| {
+					translated.saturating_inc();
+					Some(AssetParameters {
+						eresidency_required: old_value.eresidency_required,
+						citizenship_required: false,
+					})
+				});
+				StorageVersion::new(2).put::<Pallet<T, I>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Upgraded {} asset parameters, storage to version 2",
+					translated,
+				);
+				T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			frame_support::ensure!(
+				Pallet::<T, I>::on_chain_storage_version() == 1,
+				"must upgrade linearly"
+			);
+			let prev_count = Parameters::<T, I>::iter_keys().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = Parameters::<T, I>::iter().count() as u32;
+			ensure!(
+				prev_count == post_count,
+				"the parameters count before and after the migration should be the same"
+			);
+			ensure!(
+				Pallet::<T, I>::on_chain_storage_version() == 2,
+				"after migration, the onchain_version should be 2"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v3 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	use v2::AssetParameters as OldAssetParameters;
+
+	/// Replaces eresidency and citizenship flags of `AssetParameters` with compliance rules.
+	/// Assets requiring citizenship are citizen-only, assets requiring eresidency are
+	/// e-resident-only, other rules are disabled.
+	pub struct MigrateToV3<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV3<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let current_version = Pallet::<T, I>::current_storage_version();
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version == 2 && current_version >= 3 {
+				let mut translated = 0u64;
+				crate::Parameters::<T, I>::translate::<OldAssetParameters, _>(|_key, old_value| {
+					translated.saturating_inc();
+					let holders = if old_value.citizenship_required {
+						HolderRequirement::Citizen
+					} else if old_value.eresidency_required {
+						HolderRequirement::EResident
+					} else {
+						HolderRequirement::Anyone
+					};
+					Some(AssetParametersOf::<T>::new(holders))
+				});
+				StorageVersion::new(3).put::<Pallet<T, I>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Upgraded {} asset parameters, storage to version 3",
+					translated,
+				);
+				T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			frame_support::ensure!(
+				Pallet::<T, I>::on_chain_storage_version() == 2,
+				"must upgrade linearly"
+			);
+			let prev_count = crate::Parameters::<T, I>::iter_keys().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = crate::Parameters::<T, I>::iter().count() as u32;
+			ensure!(
+				prev_count == post_count,
+				"the parameters count before and after the migration should be the same"
+			);
+			ensure!(
+				Pallet::<T, I>::on_chain_storage_version() == 3,
+				"after migration, the onchain_version should be 3"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v4 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// Populates `CompanyAssets` reverse index from `RelatedCompany`.
+	pub struct MigrateToV4<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV4<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let current_version = Pallet::<T, I>::current_storage_version();
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version == 3 && current_version >= 4 {
+				let mut indexed = 0u64;
+				for (asset, company) in RelatedCompany::<T, I>::iter() {
+					indexed.saturating_inc();
+					CompanyAssets::<T, I>::insert(company, asset, ());
+				}
+				StorageVersion::new(4).put::<Pallet<T, I>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Indexed {} related companies, storage to version 4",
+					indexed,
+				);
+				T::DbWeight::get().reads_writes(indexed + 1, indexed + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			frame_support::ensure!(
+				Pallet::<T, I>::on_chain_storage_version() == 3,
+				"must upgrade linearly"
+			);
+			let prev_count = RelatedCompany::<T, I>::iter_keys().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = CompanyAssets::<T, I>::iter_keys().count() as u32;
+			ensure!(
+				prev_count == post_count,
+				"every related company should be indexed after the migration"
+			);
+			ensure!(
+				Pallet::<T, I>::on_chain_storage_version() == 4,
+				"after migration, the onchain_version should be 4"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v5 {
+	use frame_support::{pallet_prelude::*, traits::GetDefault, weights::Weight};
+
+	use super::*;
+
+	/// Registers accounts created through `AccountTouch` before `SystemAccounts` existed, as
+	/// provided by `Accounts`.
+	pub struct MigrateToV5<T, I = (), Accounts = GetDefault>(
+		sp_std::marker::PhantomData<(T, I, Accounts)>,
+	);
+	impl<T: Config<I>, I: 'static, Accounts: Get<Vec<(T::AssetId, T::AccountId)>>> OnRuntimeUpgrade
+		for MigrateToV5<T, I, Accounts>
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let current_version = Pallet::<T, I>::current_storage_version();
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version == 4 && current_version >= 5 {
+				let accounts = Accounts::get();
+				let registered = accounts.len() as u64;
+				for (asset, who) in accounts {
+					SystemAccounts::<T, I>::insert(asset, who, ());
+				}
+				StorageVersion::new(5).put::<Pallet<T, I>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Registered {} system accounts, storage to version 5",
+					registered,
+				);
+				T::DbWeight::get().reads_writes(registered + 1, registered + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			frame_support::ensure!(
+				Pallet::<T, I>::on_chain_storage_version() == 4,
+				"must upgrade linearly"
+			);
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			for (asset, who) in Accounts::get() {
+				ensure!(
+					SystemAccounts::<T, I>::contains_key(asset, who),
+					"every provided account should be registered after the migration"
+				);
+			}
+			ensure!(
+				Pallet::<T, I>::on_chain_storage_version() == 5,
+				"after migration, the onchain_version should be 5"
+			);
+			Ok(())
+		}
+	}
+}
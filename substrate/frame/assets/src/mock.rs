@@ -23,7 +23,7 @@ use crate as pallet_assets;
 use codec::Encode;
 use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64},
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, IsInVec},
 };
 use sp_core::H256;
 use sp_io::storage;
@@ -135,6 +135,7 @@ impl AssetsCallbackHandle {
 parameter_types! {
 	pub MockCitizenOne: AccountId = 100u64;
 	pub MockCitizenTwo: AccountId = 101u64;
+	pub MockCompanies: Vec<u32> = vec![1, 2];
 }
 
 impl Config for Test {
@@ -159,6 +160,15 @@ impl Config for Test {
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type Citizenship = MockCitizenshipChecker<Self::AccountId, MockCitizenOne, MockCitizenTwo>;
+	type Companies = IsInVec<MockCompanies>;
+	type MaxActiveDistributions = ConstU32<10>;
+	type MaxCheckpoints = ConstU32<3>;
+	type MaxOpenResolutions = ConstU32<2>;
+	type MaxVestingSchedules = ConstU32<3>;
+	type RuntimeHoldReason = u8;
+	type FreezeIdentifier = u8;
+	type MaxHolds = ConstU32<2>;
+	type MaxFreezes = ConstU32<2>;
 }
 
 use std::collections::HashMap;
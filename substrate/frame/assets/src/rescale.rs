@@ -0,0 +1,192 @@
+/*
+
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+use crate::*;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{One, UniqueSaturatedFrom, UniqueSaturatedInto},
+	DispatchResult, Rounding,
+};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub fn do_start_rescale(
+		asset: T::AssetId,
+		numerator: u32,
+		denominator: u32,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		ensure!(
+			numerator > 0 && denominator > 0 && numerator != denominator,
+			Error::<T, I>::InvalidRatio
+		);
+		ensure!(!Rescales::<T, I>::contains_key(&asset), Error::<T, I>::RescaleInProgress);
+		Asset::<T, I>::try_mutate(&asset, |maybe_details| -> DispatchResult {
+			let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			if let Some(check_owner) = maybe_check_owner {
+				ensure!(d.owner == check_owner, Error::<T, I>::NoPermission);
+			}
+			ensure!(
+				d.status == AssetStatus::Live || d.status == AssetStatus::Frozen,
+				Error::<T, I>::AssetNotLive
+			);
+			// rescaled balances can't exceed the rescaled supply
+			let supply = multiply_by_rational_with_rounding(
+				d.supply.unique_saturated_into(),
+				numerator.into(),
+				denominator.into(),
+				Rounding::Down,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+			T::Balance::try_from(supply).map_err(|_| ArithmeticError::Overflow)?;
+
+			Rescales::<T, I>::insert(
+				&asset,
+				Rescale {
+					numerator,
+					denominator,
+					was_frozen: d.status == AssetStatus::Frozen,
+					phase: RescalePhase::Accounts(None),
+					supply: Zero::zero(),
+				},
+			);
+			d.status = AssetStatus::Frozen;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::RescaleStarted { asset_id: asset, numerator, denominator });
+		Ok(())
+	}
+
+	pub fn do_continue_rescale(asset: T::AssetId, max_items: u32) -> DispatchResult {
+		let mut rescale = Rescales::<T, I>::get(&asset).ok_or(Error::<T, I>::NoRescale)?;
+		let scale = |value: T::Balance| -> T::Balance {
+			let value = multiply_by_rational_with_rounding(
+				value.unique_saturated_into(),
+				rescale.numerator.into(),
+				rescale.denominator.into(),
+				Rounding::Down,
+			)
+			.unwrap_or_default();
+			T::Balance::unique_saturated_from(value)
+		};
+		let max_items = max_items as usize;
+
+		if let RescalePhase::Accounts(cursor) = &rescale.phase {
+			let accounts: Vec<_> = match cursor {
+				Some(last) => Account::<T, I>::iter_prefix_from(
+					&asset,
+					Account::<T, I>::hashed_key_for(&asset, last),
+				),
+				None => Account::<T, I>::iter_prefix(&asset),
+			}
+			.take(max_items)
+			.collect();
+
+			let mut supply = rescale.supply;
+			for (who, mut account) in accounts.iter().cloned() {
+				Self::snapshot_distributions(&asset, &who);
+				Self::checkpoint_balance(&asset, &who);
+				account.balance = scale(account.balance);
+				supply.saturating_accrue(account.balance);
+				Account::<T, I>::insert(&asset, &who, account);
+				MintLockups::<T, I>::mutate(&asset, &who, |maybe_lockup| {
+					if let Some(lockup) = maybe_lockup {
+						lockup.amount = scale(lockup.amount);
+					}
+				});
+				Vesting::<T, I>::mutate_exists(&asset, &who, |maybe_schedules| {
+					if let Some(schedules) = maybe_schedules {
+						for schedule in schedules.iter_mut() {
+							schedule.amount = scale(schedule.amount);
+						}
+					}
+				});
+				Holds::<T, I>::mutate_exists(&asset, &who, |maybe_holds| {
+					if let Some(holds) = maybe_holds {
+						for hold in holds.iter_mut() {
+							hold.amount = scale(hold.amount);
+							supply.saturating_accrue(hold.amount);
+						}
+					}
+				});
+				Freezes::<T, I>::mutate_exists(&asset, &who, |maybe_freezes| {
+					if let Some(freezes) = maybe_freezes {
+						for freeze in freezes.iter_mut() {
+							freeze.amount = scale(freeze.amount);
+						}
+					}
+				});
+			}
+
+			rescale.supply = supply;
+			rescale.phase = match accounts.last() {
+				Some((last, _)) if accounts.len() == max_items =>
+					RescalePhase::Accounts(Some(last.clone())),
+				_ => RescalePhase::Approvals(None),
+			};
+			Rescales::<T, I>::insert(&asset, rescale);
+			return Ok(())
+		}
+
+		if let RescalePhase::Approvals(cursor) = &rescale.phase {
+			let approvals: Vec<_> = match cursor {
+				Some((owner, delegate)) => Approvals::<T, I>::iter_prefix_from(
+					(asset.clone(),),
+					Approvals::<T, I>::hashed_key_for((asset.clone(), owner, delegate)),
+				),
+				None => Approvals::<T, I>::iter_prefix((asset.clone(),)),
+			}
+			.take(max_items)
+			.collect();
+
+			for ((owner, delegate), mut approval) in approvals.iter().cloned() {
+				approval.amount = scale(approval.amount);
+				Approvals::<T, I>::insert((asset.clone(), &owner, &delegate), approval);
+			}
+
+			if let Some(((owner, delegate), _)) = approvals.last() {
+				if approvals.len() == max_items {
+					rescale.phase = RescalePhase::Approvals(Some((owner.clone(), delegate.clone())));
+					Rescales::<T, I>::insert(&asset, rescale);
+					return Ok(())
+				}
+			}
+		}
+
+		Self::finish_rescale(asset, rescale)
+	}
+
+	fn finish_rescale(asset: T::AssetId, rescale: RescaleOf<T, I>) -> DispatchResult {
+		Self::checkpoint_supply(&asset);
+		let details = Asset::<T, I>::try_mutate(&asset, |maybe_details| {
+			let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			d.supply = rescale.supply;
+			d.min_balance = multiply_by_rational_with_rounding(
+				d.min_balance.unique_saturated_into(),
+				rescale.numerator.into(),
+				rescale.denominator.into(),
+				Rounding::Down,
+			)
+			.map(T::Balance::unique_saturated_from)
+			.unwrap_or_default()
+			.max(One::one());
+			d.status = if rescale.was_frozen { AssetStatus::Frozen } else { AssetStatus::Live };
+			Ok::<_, DispatchError>(d.clone())
+		})?;
+		Rescales::<T, I>::remove(&asset);
+
+		Self::deposit_event(Event::RescaleFinished {
+			asset_id: asset,
+			supply: details.supply,
+			min_balance: details.min_balance,
+		});
+		Ok(())
+	}
+}
@@ -0,0 +1,132 @@
+/*
+
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+use crate::*;
+use sp_runtime::{traits::One, DispatchResult};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub fn do_propose_resolution(
+		chair: T::AccountId,
+		asset: T::AssetId,
+		description: Vec<u8>,
+		duration: BlockNumberFor<T>,
+		quorum: Permill,
+	) -> DispatchResult {
+		let d = Asset::<T, I>::get(&asset).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+		ensure!(
+			[&d.owner, &d.issuer, &d.admin, &d.freezer].contains(&&chair),
+			Error::<T, I>::NoPermission
+		);
+		ensure!(!duration.is_zero(), Error::<T, I>::InvalidResolution);
+		let description: BoundedVec<u8, T::StringLimit> =
+			description.try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+
+		// balances in the current block can still change
+		let now = frame_system::Pallet::<T>::block_number();
+		let snapshot = now.saturating_sub(One::one());
+		let supply =
+			Self::total_supply_at(asset.clone(), snapshot).ok_or(Error::<T, I>::NoCheckpoints)?;
+
+		let resolution_id = NextResolutionId::<T, I>::get();
+		let next_id = resolution_id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+		OpenResolutions::<T, I>::try_mutate(&asset, |open| {
+			open.try_push((resolution_id, snapshot))
+		})
+		.map_err(|_| Error::<T, I>::TooManyResolutions)?;
+		NextResolutionId::<T, I>::put(next_id);
+		let ends_at = now.saturating_add(duration);
+		Resolutions::<T, I>::insert(
+			resolution_id,
+			Resolution {
+				asset: asset.clone(),
+				chair: chair.clone(),
+				description,
+				snapshot,
+				ends_at,
+				quorum,
+				supply,
+				ayes: Zero::zero(),
+				nays: Zero::zero(),
+				approved: None,
+			},
+		);
+		Self::deposit_event(Event::ResolutionProposed {
+			resolution_id,
+			asset_id: asset,
+			chair,
+			snapshot,
+			ends_at,
+		});
+		Ok(())
+	}
+
+	pub fn do_vote_resolution(
+		resolution_id: ResolutionId,
+		who: T::AccountId,
+		aye: bool,
+	) -> DispatchResult {
+		let mut r =
+			Resolutions::<T, I>::get(resolution_id).ok_or(Error::<T, I>::UnknownResolution)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(r.approved.is_none() && now < r.ends_at, Error::<T, I>::ResolutionEnded);
+
+		let votes = match ResolutionSnapshots::<T, I>::get(resolution_id, &who) {
+			Some(balance) => balance,
+			None => Self::balance_at(r.asset.clone(), &who, r.snapshot)
+				.ok_or(Error::<T, I>::NoCheckpoints)?,
+		};
+		ensure!(!votes.is_zero(), Error::<T, I>::NoVotingPower);
+
+		if let Some((prev_aye, prev_votes)) = ResolutionVotes::<T, I>::get(resolution_id, &who) {
+			let tally = if prev_aye { &mut r.ayes } else { &mut r.nays };
+			tally.saturating_reduce(prev_votes);
+		}
+		let tally = if aye { &mut r.ayes } else { &mut r.nays };
+		tally.saturating_accrue(votes);
+
+		ResolutionVotes::<T, I>::insert(resolution_id, &who, (aye, votes));
+		Resolutions::<T, I>::insert(resolution_id, r);
+		Self::deposit_event(Event::ResolutionVoted { resolution_id, who, aye, votes });
+		Ok(())
+	}
+
+	pub fn do_close_resolution(resolution_id: ResolutionId) -> DispatchResult {
+		let mut r =
+			Resolutions::<T, I>::get(resolution_id).ok_or(Error::<T, I>::UnknownResolution)?;
+
+		if r.approved.is_none() {
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= r.ends_at, Error::<T, I>::ResolutionNotEnded);
+			let turnout = r.ayes.saturating_add(r.nays);
+			let approved = r.ayes > r.nays && turnout >= r.quorum.mul_ceil(r.supply);
+			r.approved = Some(approved);
+			OpenResolutions::<T, I>::mutate(&r.asset, |open| {
+				open.retain(|(id, _)| *id != resolution_id)
+			});
+			Self::deposit_event(Event::ResolutionClosed {
+				resolution_id,
+				approved,
+				ayes: r.ayes,
+				nays: r.nays,
+			});
+		}
+
+		let limit = T::RemoveItemsLimit::get();
+		let votes = ResolutionVotes::<T, I>::clear_prefix(resolution_id, limit, None);
+		let snapshots = ResolutionSnapshots::<T, I>::clear_prefix(resolution_id, limit, None);
+		if votes.maybe_cursor.is_none() && snapshots.maybe_cursor.is_none() {
+			Resolutions::<T, I>::remove(resolution_id);
+		} else {
+			Resolutions::<T, I>::insert(resolution_id, r);
+		}
+		Ok(())
+	}
+}
@@ -27,7 +27,7 @@ use frame_support::{
 };
 use pallet_balances::Error as BalancesError;
 use sp_io::storage;
-use sp_runtime::{traits::ConvertInto, TokenError};
+use sp_runtime::{traits::ConvertInto, Permill, TokenError};
 
 fn asset_ids() -> Vec<u32> {
 	let mut s: Vec<_> = Assets::asset_ids().collect();
@@ -1782,11 +1782,11 @@ fn only_owner_can_set_parameters() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
 		let admin_origin = RuntimeOrigin::signed(1);
-		assert_ok!(Assets::set_parameters(admin_origin, 0, AssetParameters { eresidency_required: true }));
+		assert_ok!(Assets::set_parameters(admin_origin, 0, AssetParameters::new(HolderRequirement::EResident)));
 
 		let nonadmin_origin = RuntimeOrigin::signed(2);
 		assert_noop!(
-			Assets::set_parameters(nonadmin_origin, 0, AssetParameters { eresidency_required: true }),
+			Assets::set_parameters(nonadmin_origin, 0, AssetParameters::new(HolderRequirement::EResident)),
 			Error::<Test>::NoPermission
 		);
 	});
@@ -1797,11 +1797,11 @@ fn only_root_can_force_set_parameters() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
 		let root_origin = RuntimeOrigin::root();
-		assert_ok!(Assets::force_set_parameters(root_origin, 0, AssetParameters { eresidency_required: true }));
+		assert_ok!(Assets::force_set_parameters(root_origin, 0, AssetParameters::new(HolderRequirement::EResident)));
 
 		let admin_origin = RuntimeOrigin::signed(1);
 		assert_noop!(
-			Assets::force_set_parameters(admin_origin, 0, AssetParameters { eresidency_required: true }),
+			Assets::force_set_parameters(admin_origin, 0, AssetParameters::new(HolderRequirement::EResident)),
 			BadOrigin
 		);
 	});
@@ -1812,7 +1812,7 @@ fn eresident_can_receive_eresidency_only_asset() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
 		let root_origin = RuntimeOrigin::root();
-		assert_ok!(Assets::force_set_parameters(root_origin, 0, AssetParameters { eresidency_required: true }));
+		assert_ok!(Assets::force_set_parameters(root_origin, 0, AssetParameters::new(HolderRequirement::EResident)));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 100, 100));
 		assert_ok!(Assets::transfer(RuntimeOrigin::signed(100), 0, 101, 100));
 	});
@@ -1822,7 +1822,7 @@ fn eresident_can_receive_eresidency_only_asset() {
 fn non_eresident_cant_receive_eresidency_only_asset() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
-		assert_ok!(Assets::force_set_parameters(RuntimeOrigin::root(), 0, AssetParameters { eresidency_required: true }));
+		assert_ok!(Assets::force_set_parameters(RuntimeOrigin::root(), 0, AssetParameters::new(HolderRequirement::EResident)));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 100, 100));
 
 		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100), TokenError::Blocked);
@@ -1834,4 +1834,817 @@ fn non_eresident_cant_receive_eresidency_only_asset() {
 		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(100), 0, 1, 50));
 		assert_noop!(Assets::transfer_approved(RuntimeOrigin::signed(1), 0, 100, 1, 50), TokenError::Blocked);
 	});
-}
\ No newline at end of file
+}
+#[test]
+fn only_citizen_can_receive_citizenship_only_asset() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_set_parameters(RuntimeOrigin::root(), 0, AssetParameters::new(HolderRequirement::Citizen)));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 100, 100));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(100), 0, 101, 50));
+
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100), TokenError::Blocked);
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(100), 0, 1, 50), TokenError::Blocked);
+	});
+}
+
+#[test]
+fn compliance_list_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		let parameters = AssetParameters { list: ComplianceListMode::Allowlist, ..Default::default() };
+		assert_ok!(Assets::force_set_parameters(RuntimeOrigin::root(), 0, parameters));
+		assert_noop!(Assets::set_compliance_list(RuntimeOrigin::signed(2), 0, 2, true), Error::<Test>::NoPermission);
+
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100), TokenError::Blocked);
+		assert_ok!(Assets::set_compliance_list(RuntimeOrigin::signed(1), 0, 2, true));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ComplianceListUpdated { asset_id: 0, who: 2, listed: true }));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 50), TokenError::Blocked);
+
+		let parameters = AssetParameters { list: ComplianceListMode::Blocklist, ..Default::default() };
+		assert_ok!(Assets::force_set_parameters(RuntimeOrigin::root(), 0, parameters));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 50));
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(3), 0, 2, 50), TokenError::Blocked);
+		assert_ok!(Assets::set_compliance_list(RuntimeOrigin::signed(1), 0, 2, false));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(3), 0, 2, 50));
+	});
+}
+
+#[test]
+fn max_holders_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		let parameters = AssetParameters { max_holders: Some(2), ..Default::default() };
+		assert_ok!(Assets::force_set_parameters(RuntimeOrigin::root(), 0, parameters));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 0, 3, 50), TokenError::Blocked);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 0, 3, 50), TokenError::Blocked);
+		assert_noop!(Assets::touch_other(RuntimeOrigin::signed(1), 0, 3), TokenError::Blocked);
+
+		// existing holders can still receive
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		// holder leaving frees a slot
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 1, 60));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 3, 50));
+	});
+}
+
+#[test]
+fn max_holding_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		let parameters =
+			AssetParameters { max_holding: Some(Permill::from_percent(10)), ..Default::default() };
+		assert_ok!(Assets::force_set_parameters(RuntimeOrigin::root(), 0, parameters));
+
+		// owner and issuer aren't limited
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 1000));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 1), TokenError::Blocked);
+		// cap is relative to supply after mint
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 0, 3, 112), TokenError::Blocked);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 3, 111));
+	});
+}
+
+#[test]
+fn mint_lockup_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		let parameters = AssetParameters { lockup: Some(10), ..Default::default() };
+		assert_ok!(Assets::force_set_parameters(RuntimeOrigin::root(), 0, parameters));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_eq!(Assets::locked_balance(&0, &2), 100);
+
+		// received funds aren't locked
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 50));
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 1), Error::<Test>::BalanceLow);
+
+		System::set_block_number(11);
+		assert_eq!(Assets::locked_balance(&0, &2), 0);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 100));
+	});
+}
+
+#[test]
+fn set_related_company_works() {
+	new_test_ext().execute_with(|| {
+		let company_assets = |company| {
+			let mut assets = Assets::company_assets(company);
+			assets.sort();
+			assets
+		};
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
+		assert_noop!(Assets::set_related_company(RuntimeOrigin::signed(2), 0, 1), Error::<Test>::NoPermission);
+		assert_noop!(Assets::set_related_company(RuntimeOrigin::signed(1), 0, 3), Error::<Test>::UnknownCompany);
+		assert_noop!(Assets::force_set_related_company(RuntimeOrigin::root(), 0, 3), Error::<Test>::UnknownCompany);
+
+		assert_ok!(Assets::set_related_company(RuntimeOrigin::signed(1), 0, 1));
+		assert_ok!(Assets::force_set_related_company(RuntimeOrigin::root(), 1, 1));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::RelatedCompanySet { asset_id: 1, company: 1 }));
+		assert_eq!(company_assets(1), vec![0, 1]);
+
+		// changing company updates index
+		assert_ok!(Assets::set_related_company(RuntimeOrigin::signed(1), 0, 2));
+		assert_eq!(company_assets(1), vec![1]);
+		assert_eq!(company_assets(2), vec![0]);
+
+		// destroying asset removes it from index
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 1));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 1));
+		assert_eq!(company_assets(1), Vec::<u32>::new());
+	});
+}
+
+#[test]
+fn cap_table_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 2, 1, true, 1));
+		assert_ok!(Assets::set_related_company(RuntimeOrigin::signed(1), 0, 1));
+		assert_ok!(Assets::set_related_company(RuntimeOrigin::signed(1), 1, 1));
+		assert_ok!(Assets::set_related_company(RuntimeOrigin::signed(1), 2, 2));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 75));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 25));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 1, 3, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 2, 3, 10));
+
+		let mut cap_table = Assets::cap_table(1);
+		cap_table.sort();
+		assert_eq!(
+			cap_table,
+			vec![
+				(0, 1, 75, Permill::from_percent(75)),
+				(0, 2, 25, Permill::from_percent(25)),
+				(1, 3, 10, Permill::from_percent(100)),
+			]
+		);
+	});
+}
+
+#[test]
+fn distribution_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 60));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 40));
+		Balances::make_free_balance_be(&1, 1000);
+		Balances::make_free_balance_be(&2, 100);
+
+		assert_noop!(
+			Assets::create_distribution(RuntimeOrigin::signed(2), 0, Payout::Native(100), 10),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Assets::create_distribution(RuntimeOrigin::signed(1), 0, Payout::Native(100), 0),
+			Error::<Test>::InvalidDistribution
+		);
+		assert_ok!(Assets::create_distribution(
+			RuntimeOrigin::signed(1),
+			0,
+			Payout::Native(100),
+			10
+		));
+		let pot = Assets::distribution_account(0);
+		assert_eq!(Balances::free_balance(&1), 900);
+		assert_eq!(Balances::free_balance(&pot), 100);
+
+		// changes after the snapshot don't affect shares
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 20));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 4, 100));
+		assert_eq!(Assets::claimable_distribution(0, &2), Some(Payout::Native(40)));
+
+		assert_ok!(Assets::claim_distribution(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Balances::free_balance(&2), 140);
+		assert_noop!(
+			Assets::claim_distribution(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::AlreadyClaimed
+		);
+		assert_noop!(
+			Assets::claim_distribution(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::NothingToClaim
+		);
+		assert_noop!(
+			Assets::claim_distribution(RuntimeOrigin::signed(4), 0),
+			Error::<Test>::NothingToClaim
+		);
+
+		// batch skips holders that claimed already or have nothing to claim
+		assert_ok!(Assets::payout_distribution(RuntimeOrigin::signed(5), 0, 10));
+		assert_eq!(Balances::free_balance(&1), 960);
+		assert_eq!(Balances::free_balance(&pot), 0);
+		assert_noop!(
+			Assets::payout_distribution(RuntimeOrigin::signed(5), 0, 10),
+			Error::<Test>::NothingToClaim
+		);
+
+		assert_noop!(
+			Assets::close_distribution(RuntimeOrigin::signed(5), 0),
+			Error::<Test>::DistributionNotExpired
+		);
+		System::set_block_number(11);
+		assert_noop!(
+			Assets::claim_distribution(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::DistributionExpired
+		);
+		assert_ok!(Assets::close_distribution(RuntimeOrigin::signed(5), 0));
+		assert!(Distributions::<Test>::get(0).is_none());
+		assert!(ActiveDistributions::<Test>::get(0).is_empty());
+		assert_eq!(DistributionAccounts::<Test>::iter_prefix(0).count(), 0);
+	});
+}
+
+#[test]
+fn expired_distribution_returns_to_issuer() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 50));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 1, 1, 1000));
+
+		assert_ok!(Assets::create_distribution(
+			RuntimeOrigin::signed(1),
+			0,
+			Payout::Asset(1, 100),
+			5
+		));
+		assert_eq!(Assets::balance(1, 1), 900);
+		assert_ok!(Assets::claim_distribution(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Assets::balance(1, 2), 50);
+
+		System::set_block_number(6);
+		assert_ok!(Assets::close_distribution(RuntimeOrigin::signed(2), 0));
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::DistributionClosed {
+			distribution_id: 0,
+			returned: Payout::Asset(1, 50),
+		}));
+		assert_eq!(Assets::balance(1, 1), 950);
+		assert_eq!(Assets::balance(1, Assets::distribution_account(0)), 0);
+		assert!(Distributions::<Test>::get(0).is_none());
+	});
+}
+
+#[test]
+fn checkpoints_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance_at(0, &1, 1), None);
+
+		assert_noop!(
+			Assets::enable_checkpoints(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Assets::enable_checkpoints(RuntimeOrigin::signed(1), 0));
+		assert_noop!(
+			Assets::enable_checkpoints(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::CheckpointsAlreadyEnabled
+		);
+		assert_eq!(Assets::balance_at(0, &1, 0), None);
+		assert_eq!(Assets::balance_at(0, &1, 1), Some(100));
+
+		System::set_block_number(2);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 30));
+		System::set_block_number(3);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_eq!(Assets::balance_at(0, &1, 1), Some(100));
+		assert_eq!(Assets::balance_at(0, &1, 2), Some(70));
+		assert_eq!(Assets::balance_at(0, &2, 1), Some(0));
+		assert_eq!(Assets::balance_at(0, &2, 2), Some(30));
+		assert_eq!(Assets::balance_at(0, &2, 3), Some(80));
+		assert_eq!(Assets::balance_at(0, &2, 4), None);
+		assert_eq!(Assets::total_supply_at(0, 2), Some(100));
+		assert_eq!(Assets::total_supply_at(0, 3), Some(150));
+
+		// oldest checkpoints are dropped
+		for block in 4..=6 {
+			System::set_block_number(block);
+			assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 3, 10));
+		}
+		assert_eq!(Assets::balance_at(0, &1, 1), None);
+		assert_eq!(Assets::balance_at(0, &1, 2), Some(70));
+		assert_eq!(Assets::balance_at(0, &1, 5), Some(50));
+		assert_eq!(Assets::balance_at(0, &1, 6), Some(40));
+	});
+}
+
+#[test]
+fn resolution_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 60));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 40));
+		assert_ok!(Assets::enable_checkpoints(RuntimeOrigin::signed(1), 0));
+		let propose = |who, quorum| {
+			Assets::propose_resolution(
+				RuntimeOrigin::signed(who),
+				0,
+				b"resolution".to_vec(),
+				10,
+				Permill::from_percent(quorum),
+			)
+		};
+		assert_noop!(propose(1, 50), Error::<Test>::NoCheckpoints);
+
+		System::set_block_number(2);
+		assert_noop!(propose(3, 50), Error::<Test>::NoPermission);
+		assert_ok!(propose(1, 50));
+
+		// balances after the snapshot don't count
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 3, 60));
+		assert_noop!(
+			Assets::vote_resolution(RuntimeOrigin::signed(3), 0, true),
+			Error::<Test>::NoVotingPower
+		);
+		assert_ok!(Assets::vote_resolution(RuntimeOrigin::signed(1), 0, true));
+		assert_ok!(Assets::vote_resolution(RuntimeOrigin::signed(2), 0, true));
+		assert_ok!(Assets::vote_resolution(RuntimeOrigin::signed(2), 0, false));
+		assert_noop!(
+			Assets::close_resolution(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::ResolutionNotEnded
+		);
+
+		System::set_block_number(12);
+		assert_noop!(
+			Assets::vote_resolution(RuntimeOrigin::signed(1), 0, true),
+			Error::<Test>::ResolutionEnded
+		);
+		assert_ok!(Assets::close_resolution(RuntimeOrigin::signed(3), 0));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ResolutionClosed {
+			resolution_id: 0,
+			approved: true,
+			ayes: 60,
+			nays: 40,
+		}));
+		assert!(Resolutions::<Test>::get(0).is_none());
+		assert_eq!(ResolutionVotes::<Test>::iter_prefix(0).count(), 0);
+
+		// quorum not reached
+		assert_ok!(propose(1, 50));
+		assert_ok!(Assets::vote_resolution(RuntimeOrigin::signed(2), 1, true));
+		System::set_block_number(22);
+		assert_ok!(Assets::close_resolution(RuntimeOrigin::signed(3), 1));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ResolutionClosed {
+			resolution_id: 1,
+			approved: false,
+			ayes: 40,
+			nays: 0,
+		}));
+	});
+}
+
+#[test]
+fn resolution_keeps_snapshot_of_dropped_checkpoints() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 60));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 40));
+		assert_ok!(Assets::enable_checkpoints(RuntimeOrigin::signed(1), 0));
+		let propose = || {
+			Assets::propose_resolution(
+				RuntimeOrigin::signed(1),
+				0,
+				b"resolution".to_vec(),
+				10,
+				Permill::from_percent(50),
+			)
+		};
+		System::set_block_number(2);
+		assert_ok!(propose());
+
+		// dust sent every block drops the checkpoint of the snapshot block
+		for block in 3..=6 {
+			System::set_block_number(block);
+			assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 1));
+		}
+		assert_eq!(Assets::balance_at(0, &2, 1), None);
+		assert_eq!(SupplyCheckpoints::<Test>::get(0), None);
+		assert_ok!(Assets::vote_resolution(RuntimeOrigin::signed(2), 0, false));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ResolutionVoted {
+			resolution_id: 0,
+			who: 2,
+			aye: false,
+			votes: 40,
+		}));
+		assert_ok!(Assets::vote_resolution(RuntimeOrigin::signed(1), 0, true));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ResolutionVoted {
+			resolution_id: 0,
+			who: 1,
+			aye: true,
+			votes: 60,
+		}));
+
+		assert_ok!(propose());
+		assert_noop!(propose(), Error::<Test>::TooManyResolutions);
+
+		System::set_block_number(12);
+		assert_ok!(Assets::close_resolution(RuntimeOrigin::signed(3), 0));
+		assert_eq!(ResolutionSnapshots::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(OpenResolutions::<Test>::get(0).len(), 1);
+		assert_ok!(propose());
+	});
+}
+
+#[test]
+fn vesting_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 1000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		let linear = VestingCurve::Linear { duration: 10, cliff: 4 };
+		let step = VestingCurve::Step { period: 5, steps: 4 };
+
+		assert_noop!(
+			Assets::vested_transfer(
+				RuntimeOrigin::signed(2),
+				0,
+				3,
+				10,
+				1,
+				VestingCurve::Cliff,
+				true
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Assets::vested_transfer(
+				RuntimeOrigin::signed(1),
+				0,
+				2,
+				10,
+				1,
+				VestingCurve::Linear { duration: 0, cliff: 0 },
+				false
+			),
+			Error::<Test>::InvalidVestingSchedule
+		);
+		assert_ok!(Assets::vested_transfer(
+			RuntimeOrigin::signed(1),
+			0,
+			2,
+			100,
+			5,
+			VestingCurve::Cliff,
+			false
+		));
+		assert_ok!(Assets::vested_transfer(RuntimeOrigin::signed(1), 0, 2, 100, 1, linear, false));
+		assert_ok!(Assets::vested_transfer(RuntimeOrigin::signed(1), 0, 2, 100, 1, step, false));
+		assert_eq!(Assets::balance(0, 2), 400);
+		assert_eq!(Assets::locked_balance(&0, &2), 300);
+
+		System::set_block_number(4);
+		assert_eq!(Assets::locked_balance(&0, &2), 300);
+		System::set_block_number(5);
+		assert_eq!(Assets::locked_balance(&0, &2), 160);
+		System::set_block_number(6);
+		assert_eq!(Assets::locked_balance(&0, &2), 125);
+
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(2), 0, 4, 276),
+			Error::<Test>::BalanceLow
+		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 4, 275));
+
+		assert_ok!(Assets::vest(RuntimeOrigin::signed(3), 0, 2));
+		assert_eq!(Vesting::<Test>::get(0, 2).len(), 2);
+		System::set_block_number(21);
+		assert_ok!(Assets::vest(RuntimeOrigin::signed(3), 0, 2));
+		assert!(!Vesting::<Test>::contains_key(0, 2));
+	});
+}
+
+#[test]
+fn vesting_merge_split_and_revoke_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 1000));
+		let linear = VestingCurve::Linear { duration: 10, cliff: 0 };
+		let grant = |amount, curve, revocable| {
+			Assets::vested_transfer(RuntimeOrigin::signed(1), 0, 2, amount, 1, curve, revocable)
+		};
+		assert_ok!(grant(100, linear, true));
+		assert_ok!(grant(50, linear, true));
+		assert_ok!(grant(10, VestingCurve::Cliff, false));
+		assert_noop!(grant(10, VestingCurve::Cliff, false), Error::<Test>::TooManyVestingSchedules);
+
+		assert_noop!(
+			Assets::merge_vesting_schedules(RuntimeOrigin::signed(2), 0, 0, 2),
+			Error::<Test>::VestingScheduleMismatch
+		);
+		assert_noop!(
+			Assets::merge_vesting_schedules(RuntimeOrigin::signed(2), 0, 0, 0),
+			Error::<Test>::InvalidVestingSchedule
+		);
+		assert_ok!(Assets::merge_vesting_schedules(RuntimeOrigin::signed(2), 0, 1, 0));
+		assert_eq!(Vesting::<Test>::get(0, 2)[0].amount, 150);
+		assert_eq!(Vesting::<Test>::get(0, 2).len(), 2);
+
+		assert_noop!(
+			Assets::split_vesting_schedule(RuntimeOrigin::signed(2), 0, 0, 150),
+			Error::<Test>::InvalidVestingSchedule
+		);
+		assert_ok!(Assets::split_vesting_schedule(RuntimeOrigin::signed(2), 0, 0, 50));
+		let amounts: Vec<_> = Vesting::<Test>::get(0, 2).iter().map(|s| s.amount).collect();
+		assert_eq!(amounts, vec![100, 10, 50]);
+
+		System::set_block_number(6);
+		assert_eq!(Assets::locked_balance(&0, &2), 75);
+		assert_noop!(
+			Assets::revoke_vesting(RuntimeOrigin::signed(2), 0, 2, 0),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Assets::revoke_vesting(RuntimeOrigin::signed(1), 0, 2, 1),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Assets::revoke_vesting(RuntimeOrigin::signed(1), 0, 2, 0));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::VestingRevoked {
+			asset_id: 0,
+			who: 2,
+			returned: 50,
+		}));
+		assert_eq!(Assets::balance(0, 1), 890);
+		assert_eq!(Assets::balance(0, 2), 110);
+		assert_eq!(Assets::locked_balance(&0, &2), 25);
+	});
+}
+
+#[test]
+fn vesting_checks_holder_requirements() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_set_parameters(
+			RuntimeOrigin::root(),
+			0,
+			AssetParameters::new(HolderRequirement::EResident)
+		));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 100, 100));
+
+		assert_noop!(
+			Assets::vested_transfer(
+				RuntimeOrigin::signed(100),
+				0,
+				2,
+				10,
+				1,
+				VestingCurve::Cliff,
+				false
+			),
+			TokenError::Blocked
+		);
+		assert_ok!(Assets::vested_transfer(
+			RuntimeOrigin::signed(100),
+			0,
+			101,
+			10,
+			5,
+			VestingCurve::Cliff,
+			false
+		));
+		assert_eq!(Assets::locked_balance(&0, &101), 10);
+	});
+}
+
+#[test]
+fn vesting_slots_are_kept_for_the_issuer() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 1000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		let grant = |from, start| {
+			Assets::vested_transfer(
+				RuntimeOrigin::signed(from),
+				0,
+				3,
+				1,
+				start,
+				VestingCurve::Cliff,
+				false,
+			)
+		};
+
+		// dust grants from others can't take more than half of the slots
+		assert_ok!(grant(2, 10));
+		assert_noop!(grant(2, 11), Error::<Test>::TooManyVestingSchedules);
+
+		// issuer can still use the remaining ones
+		assert_ok!(grant(1, 10));
+		assert_ok!(grant(1, 11));
+		assert_eq!(Vesting::<Test>::get(0, 3).len(), 3);
+		assert_noop!(grant(1, 12), Error::<Test>::TooManyVestingSchedules);
+		assert_noop!(grant(2, 12), Error::<Test>::TooManyVestingSchedules);
+	});
+}
+
+#[test]
+fn rescale_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 55));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 3, 25));
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 4, 30));
+
+		assert_noop!(
+			Assets::start_rescale(RuntimeOrigin::signed(2), 0, 2, 3),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Assets::start_rescale(RuntimeOrigin::signed(1), 0, 3, 3),
+			Error::<Test>::InvalidRatio
+		);
+		assert_ok!(Assets::start_rescale(RuntimeOrigin::signed(1), 0, 2, 3));
+
+		// asset is frozen until the rescale finishes
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10),
+			Error::<Test>::AssetNotLive
+		);
+		assert_noop!(
+			Assets::mint(RuntimeOrigin::signed(1), 0, 1, 10),
+			Error::<Test>::RescaleInProgress
+		);
+		assert_noop!(
+			Assets::thaw_asset(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::RescaleInProgress
+		);
+
+		// one item per call: 3 accounts, an approval and 2 calls finishing the phases
+		let mut calls = 0;
+		while Rescales::<Test>::contains_key(0) {
+			assert_ok!(Assets::do_continue_rescale(0, 1));
+			calls += 1;
+		}
+		assert_eq!(calls, 6);
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::RescaleFinished {
+			asset_id: 0,
+			supply: 118,
+			min_balance: 6,
+		}));
+
+		assert_eq!(Assets::balance(0, 1), 66);
+		assert_eq!(Assets::balance(0, 2), 36);
+		assert_eq!(Assets::balance(0, 3), 16);
+		assert_eq!(Assets::total_supply(0), 118);
+		assert_eq!(Approvals::<Test>::get((0, 1, 4)).unwrap().amount, 20);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_noop!(
+			Assets::continue_rescale(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::NoRescale
+		);
+	});
+}
+
+#[test]
+fn holds_and_freezes_work() {
+	use frame_support::traits::tokens::{
+		fungibles::{
+			freeze::Mutate as FreezeMutate,
+			hold::{Inspect as HoldInspect, Mutate as HoldMutate},
+			Inspect,
+		},
+		Precision::Exact,
+	};
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+
+		assert_ok!(Assets::hold(0, &1, &2, 30));
+		assert_ok!(Assets::hold(0, &2, &2, 20));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::HoldSet {
+			asset_id: 0,
+			who: 2,
+			reason: 2,
+			amount: 20,
+		}));
+		assert_noop!(Assets::hold(0, &3, &2, 10), TokenError::CannotCreateHold);
+		assert_eq!(Assets::balance(0, 2), 50);
+		assert_eq!(<Assets as Inspect<_>>::total_balance(0, &2), 100);
+		assert_eq!(Assets::total_balance_on_hold(0, &2), 50);
+		assert_eq!(Assets::holds(0, 2), vec![(1, 30), (2, 20)]);
+		// held funds keep the account alive
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 50),
+			Error::<Test>::BalanceLow
+		);
+
+		assert_ok!(Assets::release(0, &1, &2, 10, Exact));
+		assert_eq!(Assets::balance_on_hold(0, &1, &2), 20);
+		assert_eq!(Assets::balance(0, 2), 60);
+
+		assert_noop!(
+			Assets::freeze_amount(RuntimeOrigin::signed(2), 0, 2, 1, 40),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Assets::freeze_amount(RuntimeOrigin::signed(1), 0, 2, 1, 40));
+		assert_ok!(Assets::set_freeze(0, &2, &2, 70));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::FreezeSet {
+			asset_id: 0,
+			who: 2,
+			reason: 2,
+			amount: 70,
+		}));
+		assert_noop!(Assets::set_freeze(0, &3, &2, 10), Error::<Test>::TooManyFreezes);
+		assert_eq!(Assets::freezes(0, 2), vec![(1, 40), (2, 70)]);
+
+		// the largest freeze applies, held funds are frozen first
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 31),
+			Error::<Test>::BalanceLow
+		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 30));
+		assert_ok!(Assets::thaw_amount(RuntimeOrigin::signed(1), 0, 2, 2));
+		assert_eq!(Assets::freezes(0, 2), vec![(1, 40)]);
+		assert_eq!(Assets::freeze_locked_balance(&0, &2), 0);
+
+		assert_ok!(Assets::release(0, &1, &2, 20, Exact));
+		assert_ok!(Assets::release(0, &2, &2, 20, Exact));
+		assert!(!Holds::<Test>::contains_key(0, 2));
+		assert_eq!(Assets::freeze_locked_balance(&0, &2), 40);
+		assert_ok!(Assets::thaw_amount(RuntimeOrigin::signed(1), 0, 2, 1));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 70));
+		assert!(!Account::<Test>::contains_key(0, 2));
+	});
+}
+
+#[test]
+fn asset_queries_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_eq!(Assets::asset_metadata(0), None);
+		assert_ok!(Assets::set_metadata(RuntimeOrigin::signed(1), 0, vec![0], vec![1], 12));
+		let metadata = Assets::asset_metadata(0).unwrap();
+		assert_eq!((metadata.name, metadata.symbol, metadata.decimals), (vec![0], vec![1], 12));
+		assert_eq!(Assets::asset_details(0).unwrap().owner, 1);
+		assert_eq!(Assets::asset_parameters(0), AssetParameters::default());
+		assert_eq!(Assets::related_company(0), None);
+
+		for who in 1..=5 {
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, who, who * 10));
+		}
+		let first = Assets::holders(0, None, 3);
+		assert_eq!(first.len(), 3);
+		let rest = Assets::holders(0, first.last().map(|(who, _)| *who), 3);
+		assert_eq!(rest.len(), 2);
+		let mut all: Vec<_> = first.into_iter().chain(rest).collect();
+		all.sort();
+		assert_eq!(all, vec![(1, 10), (2, 20), (3, 30), (4, 40), (5, 50)]);
+	});
+}
+
+#[test]
+fn system_accounts_check_senders_instead() {
+	use frame_support::traits::{
+		tokens::{fungibles::Mutate, Preservation::Expendable},
+		AccountTouch,
+	};
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		let parameters = AssetParameters::new(HolderRequirement::EResident);
+		assert_ok!(Assets::force_set_parameters(RuntimeOrigin::root(), 0, parameters));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 100, 100));
+
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(100), 0, 50, 10), TokenError::Blocked);
+		// 50 isn't an e-resident, but system accounts don't have to be
+		assert_ok!(<Assets as AccountTouch<_, _>>::touch(0, 50, 1));
+		assert!(Assets::is_system_account(&0, &50));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(100), 0, 50, 10));
+
+		// failed touches don't leave system accounts behind
+		assert_noop!(<Assets as AccountTouch<_, _>>::touch(1, 51, 1), Error::<Test>::Unknown);
+		assert!(!Assets::is_system_account(&1, &51));
+		assert_noop!(<Assets as AccountTouch<_, _>>::touch(0, 50, 1), Error::<Test>::AlreadyExists);
+		assert!(Assets::is_system_account(&0, &50));
+
+		// receivers are checked as usual
+		assert_noop!(
+			<Assets as Mutate<_>>::transfer(0, &50, &2, 5, Expendable),
+			TokenError::Blocked
+		);
+		assert_ok!(<Assets as Mutate<_>>::transfer(0, &50, &101, 5, Expendable));
+
+		// senders must be allowed to hold the asset
+		let parameters = AssetParameters {
+			list: ComplianceListMode::Blocklist,
+			..AssetParameters::new(HolderRequirement::EResident)
+		};
+		assert_ok!(Assets::force_set_parameters(RuntimeOrigin::root(), 0, parameters));
+		assert_ok!(Assets::set_compliance_list(RuntimeOrigin::signed(1), 0, 100, true));
+		assert_noop!(
+			<Assets as Mutate<_>>::transfer(0, &100, &50, 10, Expendable),
+			TokenError::Blocked
+		);
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(100), 0, 50, 10), TokenError::Blocked);
+		assert_ok!(<Assets as Mutate<_>>::transfer(0, &101, &50, 5, Expendable));
+	});
+}
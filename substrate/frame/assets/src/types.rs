@@ -22,7 +22,10 @@ use frame_support::{
 	pallet_prelude::*,
 	traits::{fungible, tokens::ConversionToAssetBalance},
 };
-use sp_runtime::{traits::Convert, FixedPointNumber, FixedU128};
+use frame_system::pallet_prelude::BlockNumberFor;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{traits::Convert, FixedPointNumber, FixedU128, Permill};
 
 pub(super) type DepositBalanceOf<T, I = ()> =
 	<<T as Config<I>>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
@@ -38,7 +41,8 @@ pub(super) type ExistenceReasonOf<T, I> =
 /// AssetStatus holds the current state of the asset. It could either be Live and available for use,
 /// or in a Destroying state.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-pub(super) enum AssetStatus {
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AssetStatus {
 	/// The asset is active and able to be used.
 	Live,
 	/// Whether the asset is frozen for non-admin transfers.
@@ -49,6 +53,7 @@ pub(super) enum AssetStatus {
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct AssetDetails<Balance, AccountId, DepositBalance> {
 	/// Can change `owner`, `issuer`, `freezer` and `admin` accounts.
 	pub(super) owner: AccountId,
@@ -185,6 +190,7 @@ pub struct AssetAccount<Balance, DepositBalance, Extra, AccountId> {
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct AssetMetadata<DepositBalance, BoundedString> {
 	/// The balance deposited for this metadata.
 	///
@@ -318,10 +324,248 @@ where
 	}
 }
 
+/// Accounts allowed to hold an asset.
 #[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-pub struct AssetParameters {
-	/// Is eresidency required to receive the asset
-	pub(super) eresidency_required: bool,
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum HolderRequirement {
+	/// Anyone can hold the asset
+	#[default]
+	Anyone,
+	/// Only e-residents and citizens can hold the asset
+	EResident,
+	/// Only citizens can hold the asset
+	Citizen,
 }
 
+/// How the compliance list maintained by the asset's issuer is used.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ComplianceListMode {
+	/// List is ignored
+	#[default]
+	Disabled,
+	/// Only listed accounts can receive the asset
+	Allowlist,
+	/// Listed accounts can't receive the asset
+	Blocklist,
+}
+
+/// Compliance rules of an asset, enforced whenever an account's balance is increased.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetParameters<BlockNumber> {
+	/// Accounts allowed to receive the asset
+	pub(super) holders: HolderRequirement,
+	/// Maximum number of accounts holding the asset
+	pub(super) max_holders: Option<u32>,
+	/// Maximum balance of a single account, as a fraction of the supply. Doesn't apply to the
+	/// asset's owner and issuer
+	pub(super) max_holding: Option<Permill>,
+	/// Number of blocks after mint during which the minted funds can't be moved
+	pub(super) lockup: Option<BlockNumber>,
+	/// How the compliance list is used
+	pub(super) list: ComplianceListMode,
+}
+
+impl<BlockNumber: Default> AssetParameters<BlockNumber> {
+	pub fn new(holders: HolderRequirement) -> Self {
+		Self { holders, ..Default::default() }
+	}
+}
+
+pub type AssetParametersOf<T> = AssetParameters<BlockNumberFor<T>>;
+
+/// Funds minted to an account that can't be moved until `until`.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct MintLockup<Balance, BlockNumber> {
+	/// Locked amount
+	pub(super) amount: Balance,
+	/// Block at which the funds are unlocked
+	pub(super) until: BlockNumber,
+}
+
+pub(super) type MintLockupOf<T, I> = MintLockup<<T as Config<I>>::Balance, BlockNumberFor<T>>;
+
 pub type AssetRelatedCompany = u32;
+
+pub type DistributionId = u32;
+
+/// Funds paid out by a distribution.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum Payout<Balance, AssetId, AssetBalance> {
+	/// Paid in the native currency
+	Native(Balance),
+	/// Paid in the given asset
+	Asset(AssetId, AssetBalance),
+}
+
+pub(super) type PayoutOf<T, I> =
+	Payout<DepositBalanceOf<T, I>, <T as Config<I>>::AssetId, <T as Config<I>>::Balance>;
+
+/// Funds deposited by an asset's issuer, claimable by holders pro-rata to their balance at the
+/// snapshot block.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Distribution<AccountId, AssetId, Balance, Payout, BlockNumber> {
+	/// Asset whose holders receive the payout
+	pub(super) asset: AssetId,
+	/// Issuer that deposited the payout, receives unclaimed funds
+	pub(super) issuer: AccountId,
+	/// Total payout
+	pub(super) payout: Payout,
+	/// Supply of the asset at the snapshot block
+	pub(super) supply: Balance,
+	/// Block at which balances of holders were taken
+	pub(super) snapshot: BlockNumber,
+	/// Block after which payout can no longer be claimed
+	pub(super) expires_at: BlockNumber,
+	/// Last holder paid by `payout_distribution`
+	pub(super) payout_cursor: Option<AccountId>,
+	/// All current holders were paid by `payout_distribution`
+	pub(super) payout_complete: bool,
+	/// Unclaimed funds were returned to the issuer
+	pub(super) closed: bool,
+}
+
+pub(super) type DistributionOf<T, I> = Distribution<
+	<T as SystemConfig>::AccountId,
+	<T as Config<I>>::AssetId,
+	<T as Config<I>>::Balance,
+	PayoutOf<T, I>,
+	BlockNumberFor<T>,
+>;
+
+/// State of an account in a distribution.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum DistributionAccount<Balance> {
+	/// Balance at the snapshot block, recorded when it changed afterwards
+	Snapshot(Balance),
+	/// Payout was claimed
+	Claimed,
+}
+
+/// Recorded history of a balance or supply of an asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Checkpoints<BlockNumber, Entries> {
+	/// First block for which the history is known
+	pub(super) since: BlockNumber,
+	/// `(block, value)` pairs, sorted by block. `value` is the value before the first change
+	/// in `block`.
+	pub(super) entries: Entries,
+}
+
+pub(super) type CheckpointsOf<T, I> = Checkpoints<
+	BlockNumberFor<T>,
+	BoundedVec<(BlockNumberFor<T>, <T as Config<I>>::Balance), <T as Config<I>>::MaxCheckpoints>,
+>;
+
+pub type ResolutionId = u32;
+
+/// Resolution voted on by holders of an asset, weighted by their balances at the snapshot block.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Resolution<AccountId, AssetId, Balance, BlockNumber, BoundedString> {
+	/// Asset whose holders vote
+	pub(super) asset: AssetId,
+	/// Owner or team member of the asset that proposed the resolution
+	pub(super) chair: AccountId,
+	/// Text or hash of the resolution
+	pub(super) description: BoundedString,
+	/// Block at which balances of holders are taken
+	pub(super) snapshot: BlockNumber,
+	/// Block at which voting ends
+	pub(super) ends_at: BlockNumber,
+	/// Minimal part of the snapshot supply that must vote for the resolution to pass
+	pub(super) quorum: Permill,
+	/// Supply of the asset at the snapshot block
+	pub(super) supply: Balance,
+	/// Votes for the resolution
+	pub(super) ayes: Balance,
+	/// Votes against the resolution
+	pub(super) nays: Balance,
+	/// Outcome, set once the resolution is closed
+	pub(super) approved: Option<bool>,
+}
+
+pub(super) type ResolutionOf<T, I> = Resolution<
+	<T as SystemConfig>::AccountId,
+	<T as Config<I>>::AssetId,
+	<T as Config<I>>::Balance,
+	BlockNumberFor<T>,
+	BoundedVec<u8, <T as Config<I>>::StringLimit>,
+>;
+
+/// How funds of a vesting schedule unlock over time.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum VestingCurve<BlockNumber> {
+	/// Everything unlocks at the start
+	Cliff,
+	/// Unlocks continuously over `duration` blocks from the start, but nothing unlocks before
+	/// `cliff` blocks pass
+	Linear { duration: BlockNumber, cliff: BlockNumber },
+	/// Unlocks in `steps` equal parts, one every `period` blocks from the start
+	Step { period: BlockNumber, steps: u32 },
+}
+
+/// Funds granted to an account that can't be moved until they vest.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct VestingSchedule<AccountId, Balance, BlockNumber> {
+	/// Account that granted the funds
+	pub(super) grantor: AccountId,
+	/// Granted amount
+	pub(super) amount: Balance,
+	/// Block at which the vesting starts
+	pub(super) start: BlockNumber,
+	/// How the funds unlock
+	pub(super) curve: VestingCurve<BlockNumber>,
+	/// Whether the grantor can take back funds that didn't vest yet
+	pub(super) revocable: bool,
+}
+
+pub(super) type VestingScheduleOf<T, I> = VestingSchedule<
+	<T as SystemConfig>::AccountId,
+	<T as Config<I>>::Balance,
+	BlockNumberFor<T>,
+>;
+
+/// Part of an asset that's being rescaled, with the last rescaled key.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum RescalePhase<AccountId> {
+	/// Balances of accounts, with the last rescaled account
+	Accounts(Option<AccountId>),
+	/// Approved amounts, with the last rescaled owner and delegate
+	Approvals(Option<(AccountId, AccountId)>),
+}
+
+/// Split or consolidation of an asset in progress. Every balance is multiplied by
+/// `numerator / denominator` and rounded down.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Rescale<AccountId, Balance> {
+	pub(super) numerator: u32,
+	pub(super) denominator: u32,
+	/// Whether the asset was frozen before the rescale started
+	pub(super) was_frozen: bool,
+	pub(super) phase: RescalePhase<AccountId>,
+	/// Sum of rescaled balances, becomes the new supply
+	pub(super) supply: Balance,
+}
+
+pub(super) type RescaleOf<T, I> =
+	Rescale<<T as SystemConfig>::AccountId, <T as Config<I>>::Balance>;
+
+/// Amount of a named hold or freeze of an account.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct IdAmount<Id, Balance> {
+	/// Reason of the hold or freeze
+	pub id: Id,
+	/// Amount held or frozen
+	pub amount: Balance,
+}
+
+pub(super) type HoldsOf<T, I> = BoundedVec<
+	IdAmount<<T as Config<I>>::RuntimeHoldReason, <T as Config<I>>::Balance>,
+	<T as Config<I>>::MaxHolds,
+>;
+
+pub(super) type FreezesOf<T, I> = BoundedVec<
+	IdAmount<<T as Config<I>>::FreezeIdentifier, <T as Config<I>>::Balance>,
+	<T as Config<I>>::MaxFreezes,
+>;
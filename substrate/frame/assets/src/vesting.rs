@@ -0,0 +1,202 @@
+/*
+
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+use crate::*;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{UniqueSaturatedFrom, UniqueSaturatedInto},
+	DispatchResult, Rounding,
+};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub fn do_vested_transfer(
+		asset: T::AssetId,
+		grantor: T::AccountId,
+		who: T::AccountId,
+		amount: T::Balance,
+		start: BlockNumberFor<T>,
+		curve: VestingCurve<BlockNumberFor<T>>,
+		revocable: bool,
+	) -> DispatchResult {
+		let d = Asset::<T, I>::get(&asset).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(!revocable || d.issuer == grantor, Error::<T, I>::NoPermission);
+		let valid_curve = match curve {
+			VestingCurve::Cliff => true,
+			VestingCurve::Linear { duration, cliff } => !duration.is_zero() && cliff <= duration,
+			VestingCurve::Step { period, steps } => !period.is_zero() && steps > 0,
+		};
+		ensure!(valid_curve && !amount.is_zero(), Error::<T, I>::InvalidVestingSchedule);
+		// anyone can make a grant, so only the issuer may fill the second half of the slots -
+		// otherwise dust grants could block the issuer's grants to `who`
+		let schedules = Vesting::<T, I>::decode_len(&asset, &who).unwrap_or_default();
+		let max_schedules = if d.issuer == grantor {
+			T::MaxVestingSchedules::get()
+		} else {
+			T::MaxVestingSchedules::get() / 2
+		};
+		ensure!(schedules < max_schedules as usize, Error::<T, I>::TooManyVestingSchedules);
+
+		// holder requirements of the asset are checked by the transfer
+		let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+		Self::do_transfer(asset.clone(), &grantor, &who, amount, None, f)?;
+		Vesting::<T, I>::try_append(
+			&asset,
+			&who,
+			VestingSchedule { grantor: grantor.clone(), amount, start, curve, revocable },
+		)
+		.map_err(|_| Error::<T, I>::TooManyVestingSchedules)?;
+
+		Self::deposit_event(Event::VestingScheduleAdded { asset_id: asset, who, grantor, amount });
+		Ok(())
+	}
+
+	pub fn do_vest(asset: T::AssetId, who: T::AccountId) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		Vesting::<T, I>::mutate_exists(&asset, &who, |maybe_schedules| {
+			if let Some(schedules) = maybe_schedules {
+				schedules.retain(|s| !Self::schedule_locked(s, now).is_zero());
+				if schedules.is_empty() {
+					*maybe_schedules = None;
+				}
+			}
+		});
+		Self::deposit_vesting_updated(asset, who);
+		Ok(())
+	}
+
+	pub fn do_merge_vesting_schedules(
+		asset: T::AssetId,
+		who: T::AccountId,
+		schedule1_index: u32,
+		schedule2_index: u32,
+	) -> DispatchResult {
+		ensure!(schedule1_index != schedule2_index, Error::<T, I>::InvalidVestingSchedule);
+		Vesting::<T, I>::try_mutate(&asset, &who, |schedules| -> DispatchResult {
+			let (i1, i2) = (schedule1_index as usize, schedule2_index as usize);
+			ensure!(i1.max(i2) < schedules.len(), Error::<T, I>::InvalidVestingSchedule);
+			let (s1, s2) = (&schedules[i1], &schedules[i2]);
+			ensure!(
+				s1.grantor == s2.grantor &&
+					s1.start == s2.start &&
+					s1.curve == s2.curve &&
+					s1.revocable == s2.revocable,
+				Error::<T, I>::VestingScheduleMismatch
+			);
+			let removed = schedules.remove(i2);
+			// `i1` moved if it was after the removed schedule
+			let i1 = if i1 > i2 { i1 - 1 } else { i1 };
+			schedules[i1].amount = schedules[i1]
+				.amount
+				.checked_add(&removed.amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			Ok(())
+		})?;
+		Self::deposit_vesting_updated(asset, who);
+		Ok(())
+	}
+
+	pub fn do_split_vesting_schedule(
+		asset: T::AssetId,
+		who: T::AccountId,
+		schedule_index: u32,
+		amount: T::Balance,
+	) -> DispatchResult {
+		Vesting::<T, I>::try_mutate(&asset, &who, |schedules| -> DispatchResult {
+			let schedule = schedules
+				.get_mut(schedule_index as usize)
+				.ok_or(Error::<T, I>::InvalidVestingSchedule)?;
+			ensure!(
+				!amount.is_zero() && amount < schedule.amount,
+				Error::<T, I>::InvalidVestingSchedule
+			);
+			schedule.amount = schedule.amount.saturating_sub(amount);
+			let new_schedule = VestingSchedule { amount, ..schedule.clone() };
+			schedules
+				.try_push(new_schedule)
+				.map_err(|_| Error::<T, I>::TooManyVestingSchedules.into())
+		})?;
+		Self::deposit_vesting_updated(asset, who);
+		Ok(())
+	}
+
+	pub fn do_revoke_vesting(
+		asset: T::AssetId,
+		grantor: T::AccountId,
+		who: T::AccountId,
+		schedule_index: u32,
+	) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		let schedule = Vesting::<T, I>::try_mutate_exists(
+			&asset,
+			&who,
+			|maybe_schedules| -> Result<VestingScheduleOf<T, I>, DispatchError> {
+				let schedules =
+					maybe_schedules.as_mut().ok_or(Error::<T, I>::InvalidVestingSchedule)?;
+				let schedule = schedules
+					.get(schedule_index as usize)
+					.ok_or(Error::<T, I>::InvalidVestingSchedule)?;
+				ensure!(
+					schedule.revocable && schedule.grantor == grantor,
+					Error::<T, I>::NoPermission
+				);
+				let schedule = schedules.remove(schedule_index as usize);
+				if schedules.is_empty() {
+					*maybe_schedules = None;
+				}
+				Ok(schedule)
+			},
+		)?;
+
+		let returned = Self::schedule_locked(&schedule, now);
+		let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+		Self::do_transfer(asset.clone(), &who, &grantor, returned, None, f)?;
+		Self::deposit_event(Event::VestingRevoked { asset_id: asset, who, returned });
+		Ok(())
+	}
+
+	/// Returns the balance of `who` that didn't vest yet.
+	pub fn vesting_locked_balance(asset: &T::AssetId, who: &T::AccountId) -> T::Balance {
+		let now = frame_system::Pallet::<T>::block_number();
+		Vesting::<T, I>::get(asset, who)
+			.iter()
+			.fold(Zero::zero(), |acc: T::Balance, s| {
+				acc.saturating_add(Self::schedule_locked(s, now))
+			})
+	}
+
+	fn schedule_locked(schedule: &VestingScheduleOf<T, I>, now: BlockNumberFor<T>) -> T::Balance {
+		if now < schedule.start {
+			return schedule.amount
+		}
+		let elapsed = now.saturating_sub(schedule.start);
+		let (vested, total): (BlockNumberFor<T>, BlockNumberFor<T>) = match schedule.curve {
+			VestingCurve::Cliff => return Zero::zero(),
+			VestingCurve::Linear { cliff, .. } if elapsed < cliff => return schedule.amount,
+			VestingCurve::Linear { duration, .. } => (elapsed.min(duration), duration),
+			VestingCurve::Step { period, steps } => {
+				let steps = BlockNumberFor::<T>::from(steps);
+				((elapsed / period).min(steps), steps)
+			},
+		};
+		let vested = multiply_by_rational_with_rounding(
+			schedule.amount.unique_saturated_into(),
+			vested.unique_saturated_into(),
+			total.unique_saturated_into(),
+			Rounding::Down,
+		)
+		.unwrap_or_default();
+		schedule.amount.saturating_sub(T::Balance::unique_saturated_from(vested))
+	}
+
+	fn deposit_vesting_updated(asset: T::AssetId, who: T::AccountId) {
+		let locked = Self::vesting_locked_balance(&asset, &who);
+		Self::deposit_event(Event::VestingSchedulesUpdated { asset_id: asset, who, locked });
+	}
+}
@@ -86,6 +86,27 @@ pub trait WeightInfo {
 	fn block() -> Weight;
 	fn set_parameters() -> Weight;
 	fn force_set_parameters() -> Weight;
+	fn set_compliance_list() -> Weight;
+	fn set_related_company() -> Weight;
+	fn force_set_related_company() -> Weight;
+	fn create_distribution() -> Weight;
+	fn claim_distribution() -> Weight;
+	fn payout_distribution(n: u32, ) -> Weight;
+	fn close_distribution(c: u32, ) -> Weight;
+	fn enable_checkpoints() -> Weight;
+	fn propose_resolution(s: u32, ) -> Weight;
+	fn vote_resolution() -> Weight;
+	fn close_resolution(c: u32, ) -> Weight;
+	fn vested_transfer() -> Weight;
+	fn revoke_vesting() -> Weight;
+	fn vest() -> Weight;
+	fn merge_vesting_schedules() -> Weight;
+	fn split_vesting_schedule() -> Weight;
+	fn start_rescale() -> Weight;
+	fn continue_rescale_accounts(c: u32, ) -> Weight;
+	fn continue_rescale_approvals(a: u32, ) -> Weight;
+	fn freeze_amount() -> Weight;
+	fn thaw_amount() -> Weight;
 }
 
 /// Weights for pallet_assets using the Substrate node and recommended hardware.
@@ -117,13 +138,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Rescales (r:1 w:0)
+	/// Proof: Assets Rescales (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
 	fn start_destroy() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `385`
 		//  Estimated: `3675`
 		// Minimum execution time: 14_437_000 picoseconds.
-		Weight::from_parts(14_833_000, 3675)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		Weight::from_parts(16_833_000, 6261)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
@@ -183,27 +206,77 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1 w:1)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:10 w:10)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:1 w:1)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets SupplyCheckpoints (r:1 w:1)
+	/// Proof: Assets SupplyCheckpoints (max_values: None, max_size: Some(2026), added: 4501, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:1 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:10 w:10)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets Rescales (r:1 w:0)
+	/// Proof: Assets Rescales (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
 		//  Estimated: `3675`
 		// Minimum execution time: 26_653_000 picoseconds.
-		Weight::from_parts(27_260_000, 3675)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		// Includes the estimated weight of the asset hooks, not benchmarked yet.
+		Weight::from_parts(43_260_000, 86913)
+			.saturating_add(T::DbWeight::get().reads(33_u64))
+			.saturating_add(T::DbWeight::get().writes(25_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1 w:0)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:10 w:10)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:1 w:1)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets SupplyCheckpoints (r:1 w:1)
+	/// Proof: Assets SupplyCheckpoints (max_values: None, max_size: Some(2026), added: 4501, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:1 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:10 w:10)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets Vesting (r:1 w:0)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	fn burn() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `459`
 		//  Estimated: `3675`
 		// Minimum execution time: 33_625_000 picoseconds.
-		Weight::from_parts(34_474_000, 3675)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		// Includes the estimated weight of the asset hooks, not benchmarked yet.
+		Weight::from_parts(53_974_000, 83637)
+			.saturating_add(T::DbWeight::get().reads(31_u64))
+			.saturating_add(T::DbWeight::get().writes(24_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
@@ -211,14 +284,41 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1 w:0)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:20 w:20)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:2 w:2)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:2 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:20 w:20)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets Vesting (r:1 w:0)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:2 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `498`
 		//  Estimated: `6208`
 		// Minimum execution time: 47_609_000 picoseconds.
-		Weight::from_parts(48_476_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		// Includes the estimated weight of the asset hooks, not benchmarked yet.
+		Weight::from_parts(77_476_000, 150182)
+			.saturating_add(T::DbWeight::get().reads(58_u64))
+			.saturating_add(T::DbWeight::get().writes(46_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
@@ -226,14 +326,41 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1 w:0)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:20 w:20)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:2 w:2)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:2 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:20 w:20)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets Vesting (r:1 w:0)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:2 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	fn transfer_keep_alive() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `498`
 		//  Estimated: `6208`
 		// Minimum execution time: 41_625_000 picoseconds.
-		Weight::from_parts(43_030_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		// Includes the estimated weight of the asset hooks, not benchmarked yet.
+		Weight::from_parts(72_030_000, 150182)
+			.saturating_add(T::DbWeight::get().reads(58_u64))
+			.saturating_add(T::DbWeight::get().writes(46_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
@@ -241,14 +368,41 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1 w:0)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:20 w:20)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:2 w:2)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:2 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:20 w:20)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets Vesting (r:1 w:0)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:2 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	fn force_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `498`
 		//  Estimated: `6208`
 		// Minimum execution time: 47_661_000 picoseconds.
-		Weight::from_parts(48_469_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		// Includes the estimated weight of the asset hooks, not benchmarked yet.
+		Weight::from_parts(77_469_000, 150182)
+			.saturating_add(T::DbWeight::get().reads(58_u64))
+			.saturating_add(T::DbWeight::get().writes(46_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
@@ -289,13 +443,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Rescales (r:1 w:0)
+	/// Proof: Assets Rescales (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
 	fn thaw_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `385`
 		//  Estimated: `3675`
 		// Minimum execution time: 13_653_000 picoseconds.
-		Weight::from_parts(14_263_000, 3675)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		Weight::from_parts(16_263_000, 6261)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
@@ -412,14 +568,41 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1 w:0)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:20 w:20)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:2 w:2)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:2 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:20 w:20)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets Vesting (r:1 w:0)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:2 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	fn transfer_approved() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `668`
 		//  Estimated: `6208`
 		// Minimum execution time: 67_712_000 picoseconds.
-		Weight::from_parts(69_946_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+		// Includes the estimated weight of the asset hooks, not benchmarked yet.
+		Weight::from_parts(98_946_000, 150182)
+			.saturating_add(T::DbWeight::get().reads(59_u64))
+			.saturating_add(T::DbWeight::get().writes(47_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
@@ -464,26 +647,40 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn touch() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `453`
 		//  Estimated: `3675`
 		// Minimum execution time: 34_874_000 picoseconds.
-		Weight::from_parts(36_330_000, 3675)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Includes the estimated weight of the asset hooks, not benchmarked yet.
+		Weight::from_parts(37_330_000, 11257)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn touch_other() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
 		//  Estimated: `3675`
 		// Minimum execution time: 33_278_000 picoseconds.
-		Weight::from_parts(34_104_000, 3675)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		// Includes the estimated weight of the asset hooks, not benchmarked yet.
+		Weight::from_parts(35_104_000, 11257)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Account (r:1 w:1)
@@ -550,6 +747,371 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(12_263_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:0 w:1)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn set_compliance_list() -> Weight {
+		// Estimated from `set_parameters`, not benchmarked yet.
+		Weight::from_parts(18_495_000, 3675)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry EntityOwner (r:1 w:0)
+	/// Proof: CompanyRegistry EntityOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Assets RelatedCompany (r:1 w:1)
+	/// Proof: Assets RelatedCompany (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets CompanyAssets (r:0 w:2)
+	/// Proof: Assets CompanyAssets (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn set_related_company() -> Weight {
+		// Estimated from `set_parameters` plus the storage above, not benchmarked yet.
+		Weight::from_parts(24_512_000, 7711)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry EntityOwner (r:1 w:0)
+	/// Proof: CompanyRegistry EntityOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Assets RelatedCompany (r:1 w:1)
+	/// Proof: Assets RelatedCompany (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets CompanyAssets (r:0 w:2)
+	/// Proof: Assets CompanyAssets (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn force_set_related_company() -> Weight {
+		// Estimated from `force_set_parameters` plus the storage above, not benchmarked yet.
+		Weight::from_parts(22_148_000, 7711)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Assets Asset (r:3 w:2)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets NextDistributionId (r:1 w:1)
+	/// Proof: Assets NextDistributionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:1)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets Distributions (r:0 w:1)
+	/// Proof: Assets Distributions (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn create_distribution() -> Weight {
+		// Estimated from `transfer` plus the storage above, not benchmarked yet.
+		Weight::from_parts(65_000_000, 24716)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: Assets Distributions (r:1 w:0)
+	/// Proof: Assets Distributions (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:1 w:1)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:3 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	fn claim_distribution() -> Weight {
+		// Estimated from `transfer` plus the storage above, not benchmarked yet.
+		Weight::from_parts(63_500_000, 26749)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Assets Distributions (r:1 w:1)
+	/// Proof: Assets Distributions (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:1000 w:1000)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1000 w:1000)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:3000 w:2000)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 1000]`.
+	fn payout_distribution(n: u32, ) -> Weight {
+		// Estimated from `claim_distribution` for each holder, not benchmarked yet.
+		Weight::from_parts(15_000_000, 2611)
+			.saturating_add(Weight::from_parts(60_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 21522).saturating_mul(n.into()))
+	}
+	/// Storage: Assets Distributions (r:1 w:1)
+	/// Proof: Assets Distributions (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:1)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:0 w:1000)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 1000]`.
+	fn close_distribution(c: u32, ) -> Weight {
+		// Estimated from `transfer` plus the storage above, not benchmarked yet.
+		Weight::from_parts(65_000_000, 24143)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:1)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	fn enable_checkpoints() -> Weight {
+		// Estimated from `set_parameters`, not benchmarked yet.
+		Weight::from_parts(19_021_000, 3675)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets SupplyCheckpoints (r:1 w:0)
+	/// Proof: Assets SupplyCheckpoints (max_values: None, max_size: Some(2026), added: 4501, mode: MaxEncodedLen)
+	/// Storage: Assets NextResolutionId (r:1 w:1)
+	/// Proof: Assets NextResolutionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:1 w:1)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets Resolutions (r:0 w:1)
+	/// Proof: Assets Resolutions (max_values: None, max_size: Some(182), added: 2657, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 50]`.
+	fn propose_resolution(s: u32, ) -> Weight {
+		// Estimated from `set_metadata` plus the storage above, not benchmarked yet.
+		Weight::from_parts(31_420_000, 9787)
+			.saturating_add(Weight::from_parts(2_720, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Assets Resolutions (r:1 w:1)
+	/// Proof: Assets Resolutions (max_values: None, max_size: Some(182), added: 2657, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:1 w:0)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:1 w:0)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionVotes (r:1 w:1)
+	/// Proof: Assets ResolutionVotes (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	fn vote_resolution() -> Weight {
+		// Estimated from `transfer` for the decoded checkpoints, not benchmarked yet.
+		Weight::from_parts(37_500_000, 17357)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Assets Resolutions (r:1 w:1)
+	/// Proof: Assets Resolutions (max_values: None, max_size: Some(182), added: 2657, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:1 w:1)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionVotes (r:0 w:1000)
+	/// Proof: Assets ResolutionVotes (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:0 w:1000)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 1000]`.
+	fn close_resolution(c: u32, ) -> Weight {
+		// Estimated from `destroy_accounts`, not benchmarked yet.
+		Weight::from_parts(24_000_000, 5233)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1 w:0)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:20 w:20)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:2 w:2)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:2 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:20 w:20)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets Vesting (r:2 w:1)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:2 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	fn vested_transfer() -> Weight {
+		// Estimated from `transfer` plus the vesting schedules of the target, not benchmarked yet.
+		Weight::from_parts(87_476_000, 154462)
+			.saturating_add(T::DbWeight::get().reads(59_u64))
+			.saturating_add(T::DbWeight::get().writes(47_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1 w:0)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:20 w:20)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:2 w:2)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:2 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:20 w:20)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets Vesting (r:2 w:1)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:2 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	fn revoke_vesting() -> Weight {
+		// Estimated from `transfer` plus the vesting schedules of the target, not benchmarked yet.
+		Weight::from_parts(87_476_000, 154462)
+			.saturating_add(T::DbWeight::get().reads(59_u64))
+			.saturating_add(T::DbWeight::get().writes(47_u64))
+	}
+	/// Storage: Assets Vesting (r:1 w:1)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	fn vest() -> Weight {
+		// Estimated from `set_min_balance` for the decoded schedules, not benchmarked yet.
+		Weight::from_parts(24_000_000, 5270)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Vesting (r:1 w:1)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	fn merge_vesting_schedules() -> Weight {
+		// Estimated from `set_min_balance` for the decoded schedules, not benchmarked yet.
+		Weight::from_parts(24_000_000, 5270)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Vesting (r:1 w:1)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	fn split_vesting_schedule() -> Weight {
+		// Estimated from `set_min_balance` for the decoded schedules, not benchmarked yet.
+		Weight::from_parts(24_000_000, 5270)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Rescales (r:1 w:1)
+	/// Proof: Assets Rescales (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	fn start_rescale() -> Weight {
+		// Estimated from `freeze_asset`, not benchmarked yet.
+		Weight::from_parts(19_000_000, 6261)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Assets Rescales (r:1 w:1)
+	/// Proof: Assets Rescales (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1000 w:1000)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1000 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:10000 w:10000)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1000 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:1000 w:1000)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:1000 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:10000 w:10000)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1000 w:1000)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets Vesting (r:1000 w:1000)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:2000 w:1000)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1000 w:1000)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// The range of component `c` is `[1, 1000]`.
+	fn continue_rescale_accounts(c: u32, ) -> Weight {
+		// Estimated from `destroy_accounts` plus the asset hooks, not benchmarked yet.
+		Weight::from_parts(19_000_000, 2586)
+			.saturating_add(Weight::from_parts(55_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((30_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((26_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 80686).saturating_mul(c.into()))
+	}
+	/// Storage: Assets Rescales (r:1 w:1)
+	/// Proof: Assets Rescales (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
+	/// Storage: Assets Approvals (r:1001 w:1000)
+	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets SupplyCheckpoints (r:1 w:1)
+	/// Proof: Assets SupplyCheckpoints (max_values: None, max_size: Some(2026), added: 4501, mode: MaxEncodedLen)
+	/// The range of component `a` is `[0, 999]`.
+	fn continue_rescale_approvals(a: u32, ) -> Weight {
+		// Estimated from `destroy_approvals`, not benchmarked yet.
+		Weight::from_parts(31_000_000, 14830)
+			.saturating_add(Weight::from_parts(16_397_299, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2623).saturating_mul(a.into()))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:1)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	fn freeze_amount() -> Weight {
+		// Estimated from `freeze` for the decoded freezes, not benchmarked yet.
+		Weight::from_parts(23_000_000, 7906)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:1)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	fn thaw_amount() -> Weight {
+		// Estimated from `thaw` for the decoded freezes, not benchmarked yet.
+		Weight::from_parts(22_000_000, 5297)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -580,13 +1142,15 @@ impl WeightInfo for () {
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Rescales (r:1 w:0)
+	/// Proof: Assets Rescales (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
 	fn start_destroy() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `385`
 		//  Estimated: `3675`
 		// Minimum execution time: 14_437_000 picoseconds.
-		Weight::from_parts(14_833_000, 3675)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		Weight::from_parts(16_833_000, 6261)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
@@ -646,27 +1210,77 @@ impl WeightInfo for () {
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1 w:1)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:10 w:10)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:1 w:1)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets SupplyCheckpoints (r:1 w:1)
+	/// Proof: Assets SupplyCheckpoints (max_values: None, max_size: Some(2026), added: 4501, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:1 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:10 w:10)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets Rescales (r:1 w:0)
+	/// Proof: Assets Rescales (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
 		//  Estimated: `3675`
 		// Minimum execution time: 26_653_000 picoseconds.
-		Weight::from_parts(27_260_000, 3675)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		// Includes the estimated weight of the asset hooks, not benchmarked yet.
+		Weight::from_parts(43_260_000, 86913)
+			.saturating_add(RocksDbWeight::get().reads(33_u64))
+			.saturating_add(RocksDbWeight::get().writes(25_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1 w:0)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:10 w:10)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:1 w:1)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets SupplyCheckpoints (r:1 w:1)
+	/// Proof: Assets SupplyCheckpoints (max_values: None, max_size: Some(2026), added: 4501, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:1 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:10 w:10)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets Vesting (r:1 w:0)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	fn burn() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `459`
 		//  Estimated: `3675`
 		// Minimum execution time: 33_625_000 picoseconds.
-		Weight::from_parts(34_474_000, 3675)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		// Includes the estimated weight of the asset hooks, not benchmarked yet.
+		Weight::from_parts(53_974_000, 83637)
+			.saturating_add(RocksDbWeight::get().reads(31_u64))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
@@ -674,14 +1288,41 @@ impl WeightInfo for () {
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1 w:0)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:20 w:20)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:2 w:2)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:2 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:20 w:20)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets Vesting (r:1 w:0)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:2 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `498`
 		//  Estimated: `6208`
 		// Minimum execution time: 47_609_000 picoseconds.
-		Weight::from_parts(48_476_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		// Includes the estimated weight of the asset hooks, not benchmarked yet.
+		Weight::from_parts(77_476_000, 150182)
+			.saturating_add(RocksDbWeight::get().reads(58_u64))
+			.saturating_add(RocksDbWeight::get().writes(46_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
@@ -689,14 +1330,41 @@ impl WeightInfo for () {
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1 w:0)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:20 w:20)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:2 w:2)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:2 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:20 w:20)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets Vesting (r:1 w:0)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:2 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	fn transfer_keep_alive() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `498`
 		//  Estimated: `6208`
 		// Minimum execution time: 41_625_000 picoseconds.
-		Weight::from_parts(43_030_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		// Includes the estimated weight of the asset hooks, not benchmarked yet.
+		Weight::from_parts(72_030_000, 150182)
+			.saturating_add(RocksDbWeight::get().reads(58_u64))
+			.saturating_add(RocksDbWeight::get().writes(46_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
@@ -704,14 +1372,41 @@ impl WeightInfo for () {
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1 w:0)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:20 w:20)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:2 w:2)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:2 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:20 w:20)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets Vesting (r:1 w:0)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:2 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	fn force_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `498`
 		//  Estimated: `6208`
 		// Minimum execution time: 47_661_000 picoseconds.
-		Weight::from_parts(48_469_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		// Includes the estimated weight of the asset hooks, not benchmarked yet.
+		Weight::from_parts(77_469_000, 150182)
+			.saturating_add(RocksDbWeight::get().reads(58_u64))
+			.saturating_add(RocksDbWeight::get().writes(46_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
@@ -752,13 +1447,15 @@ impl WeightInfo for () {
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Rescales (r:1 w:0)
+	/// Proof: Assets Rescales (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
 	fn thaw_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `385`
 		//  Estimated: `3675`
 		// Minimum execution time: 13_653_000 picoseconds.
-		Weight::from_parts(14_263_000, 3675)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		Weight::from_parts(16_263_000, 6261)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
@@ -875,14 +1572,41 @@ impl WeightInfo for () {
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1 w:0)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:20 w:20)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:2 w:2)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:2 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:20 w:20)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets Vesting (r:1 w:0)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:2 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	fn transfer_approved() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `668`
 		//  Estimated: `6208`
 		// Minimum execution time: 67_712_000 picoseconds.
-		Weight::from_parts(69_946_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+		// Includes the estimated weight of the asset hooks, not benchmarked yet.
+		Weight::from_parts(98_946_000, 150182)
+			.saturating_add(RocksDbWeight::get().reads(59_u64))
+			.saturating_add(RocksDbWeight::get().writes(47_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
@@ -927,26 +1651,40 @@ impl WeightInfo for () {
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn touch() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `453`
 		//  Estimated: `3675`
 		// Minimum execution time: 34_874_000 picoseconds.
-		Weight::from_parts(36_330_000, 3675)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Includes the estimated weight of the asset hooks, not benchmarked yet.
+		Weight::from_parts(37_330_000, 11257)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn touch_other() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
 		//  Estimated: `3675`
 		// Minimum execution time: 33_278_000 picoseconds.
-		Weight::from_parts(34_104_000, 3675)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		// Includes the estimated weight of the asset hooks, not benchmarked yet.
+		Weight::from_parts(35_104_000, 11257)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Account (r:1 w:1)
@@ -1013,4 +1751,369 @@ impl WeightInfo for () {
 		Weight::from_parts(12_263_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:0 w:1)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn set_compliance_list() -> Weight {
+		// Estimated from `set_parameters`, not benchmarked yet.
+		Weight::from_parts(18_495_000, 3675)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry EntityOwner (r:1 w:0)
+	/// Proof: CompanyRegistry EntityOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Assets RelatedCompany (r:1 w:1)
+	/// Proof: Assets RelatedCompany (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets CompanyAssets (r:0 w:2)
+	/// Proof: Assets CompanyAssets (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn set_related_company() -> Weight {
+		// Estimated from `set_parameters` plus the storage above, not benchmarked yet.
+		Weight::from_parts(24_512_000, 7711)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry EntityOwner (r:1 w:0)
+	/// Proof: CompanyRegistry EntityOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Assets RelatedCompany (r:1 w:1)
+	/// Proof: Assets RelatedCompany (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets CompanyAssets (r:0 w:2)
+	/// Proof: Assets CompanyAssets (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn force_set_related_company() -> Weight {
+		// Estimated from `force_set_parameters` plus the storage above, not benchmarked yet.
+		Weight::from_parts(22_148_000, 7711)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Assets Asset (r:3 w:2)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets NextDistributionId (r:1 w:1)
+	/// Proof: Assets NextDistributionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:1)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets Distributions (r:0 w:1)
+	/// Proof: Assets Distributions (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn create_distribution() -> Weight {
+		// Estimated from `transfer` plus the storage above, not benchmarked yet.
+		Weight::from_parts(65_000_000, 24716)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: Assets Distributions (r:1 w:0)
+	/// Proof: Assets Distributions (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:1 w:1)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:3 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	fn claim_distribution() -> Weight {
+		// Estimated from `transfer` plus the storage above, not benchmarked yet.
+		Weight::from_parts(63_500_000, 26749)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Assets Distributions (r:1 w:1)
+	/// Proof: Assets Distributions (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:1000 w:1000)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1000 w:1000)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:3000 w:2000)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 1000]`.
+	fn payout_distribution(n: u32, ) -> Weight {
+		// Estimated from `claim_distribution` for each holder, not benchmarked yet.
+		Weight::from_parts(15_000_000, 2611)
+			.saturating_add(Weight::from_parts(60_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((10_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 21522).saturating_mul(n.into()))
+	}
+	/// Storage: Assets Distributions (r:1 w:1)
+	/// Proof: Assets Distributions (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:1)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:0 w:1000)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 1000]`.
+	fn close_distribution(c: u32, ) -> Weight {
+		// Estimated from `transfer` plus the storage above, not benchmarked yet.
+		Weight::from_parts(65_000_000, 24143)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:1)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	fn enable_checkpoints() -> Weight {
+		// Estimated from `set_parameters`, not benchmarked yet.
+		Weight::from_parts(19_021_000, 3675)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets SupplyCheckpoints (r:1 w:0)
+	/// Proof: Assets SupplyCheckpoints (max_values: None, max_size: Some(2026), added: 4501, mode: MaxEncodedLen)
+	/// Storage: Assets NextResolutionId (r:1 w:1)
+	/// Proof: Assets NextResolutionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:1 w:1)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets Resolutions (r:0 w:1)
+	/// Proof: Assets Resolutions (max_values: None, max_size: Some(182), added: 2657, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 50]`.
+	fn propose_resolution(s: u32, ) -> Weight {
+		// Estimated from `set_metadata` plus the storage above, not benchmarked yet.
+		Weight::from_parts(31_420_000, 9787)
+			.saturating_add(Weight::from_parts(2_720, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Assets Resolutions (r:1 w:1)
+	/// Proof: Assets Resolutions (max_values: None, max_size: Some(182), added: 2657, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:1 w:0)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:1 w:0)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionVotes (r:1 w:1)
+	/// Proof: Assets ResolutionVotes (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	fn vote_resolution() -> Weight {
+		// Estimated from `transfer` for the decoded checkpoints, not benchmarked yet.
+		Weight::from_parts(37_500_000, 17357)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Assets Resolutions (r:1 w:1)
+	/// Proof: Assets Resolutions (max_values: None, max_size: Some(182), added: 2657, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:1 w:1)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionVotes (r:0 w:1000)
+	/// Proof: Assets ResolutionVotes (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:0 w:1000)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 1000]`.
+	fn close_resolution(c: u32, ) -> Weight {
+		// Estimated from `destroy_accounts`, not benchmarked yet.
+		Weight::from_parts(24_000_000, 5233)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1 w:0)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:20 w:20)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:2 w:2)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:2 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:20 w:20)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets Vesting (r:2 w:1)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:2 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	fn vested_transfer() -> Weight {
+		// Estimated from `transfer` plus the vesting schedules of the target, not benchmarked yet.
+		Weight::from_parts(87_476_000, 154462)
+			.saturating_add(RocksDbWeight::get().reads(59_u64))
+			.saturating_add(RocksDbWeight::get().writes(47_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Parameters (r:1 w:0)
+	/// Proof: Assets Parameters (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Assets SystemAccounts (r:1 w:0)
+	/// Proof: Assets SystemAccounts (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets ComplianceList (r:1 w:0)
+	/// Proof: Assets ComplianceList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1 w:0)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:20 w:20)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:2 w:2)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:2 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:20 w:20)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets Vesting (r:2 w:1)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:2 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	fn revoke_vesting() -> Weight {
+		// Estimated from `transfer` plus the vesting schedules of the target, not benchmarked yet.
+		Weight::from_parts(87_476_000, 154462)
+			.saturating_add(RocksDbWeight::get().reads(59_u64))
+			.saturating_add(RocksDbWeight::get().writes(47_u64))
+	}
+	/// Storage: Assets Vesting (r:1 w:1)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	fn vest() -> Weight {
+		// Estimated from `set_min_balance` for the decoded schedules, not benchmarked yet.
+		Weight::from_parts(24_000_000, 5270)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Vesting (r:1 w:1)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	fn merge_vesting_schedules() -> Weight {
+		// Estimated from `set_min_balance` for the decoded schedules, not benchmarked yet.
+		Weight::from_parts(24_000_000, 5270)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Vesting (r:1 w:1)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	fn split_vesting_schedule() -> Weight {
+		// Estimated from `set_min_balance` for the decoded schedules, not benchmarked yet.
+		Weight::from_parts(24_000_000, 5270)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Rescales (r:1 w:1)
+	/// Proof: Assets Rescales (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	fn start_rescale() -> Weight {
+		// Estimated from `freeze_asset`, not benchmarked yet.
+		Weight::from_parts(19_000_000, 6261)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Assets Rescales (r:1 w:1)
+	/// Proof: Assets Rescales (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1000 w:1000)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets ActiveDistributions (r:1000 w:0)
+	/// Proof: Assets ActiveDistributions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets DistributionAccounts (r:10000 w:10000)
+	/// Proof: Assets DistributionAccounts (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1000 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets BalanceCheckpoints (r:1000 w:1000)
+	/// Proof: Assets BalanceCheckpoints (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets OpenResolutions (r:1000 w:0)
+	/// Proof: Assets OpenResolutions (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets ResolutionSnapshots (r:10000 w:10000)
+	/// Proof: Assets ResolutionSnapshots (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Assets MintLockups (r:1000 w:1000)
+	/// Proof: Assets MintLockups (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Assets Vesting (r:1000 w:1000)
+	/// Proof: Assets Vesting (max_values: None, max_size: Some(1805), added: 4280, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:2000 w:1000)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1000 w:1000)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// The range of component `c` is `[1, 1000]`.
+	fn continue_rescale_accounts(c: u32, ) -> Weight {
+		// Estimated from `destroy_accounts` plus the asset hooks, not benchmarked yet.
+		Weight::from_parts(19_000_000, 2586)
+			.saturating_add(Weight::from_parts(55_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((30_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((26_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 80686).saturating_mul(c.into()))
+	}
+	/// Storage: Assets Rescales (r:1 w:1)
+	/// Proof: Assets Rescales (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
+	/// Storage: Assets Approvals (r:1001 w:1000)
+	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets CheckpointsSince (r:1 w:0)
+	/// Proof: Assets CheckpointsSince (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Assets SupplyCheckpoints (r:1 w:1)
+	/// Proof: Assets SupplyCheckpoints (max_values: None, max_size: Some(2026), added: 4501, mode: MaxEncodedLen)
+	/// The range of component `a` is `[0, 999]`.
+	fn continue_rescale_approvals(a: u32, ) -> Weight {
+		// Estimated from `destroy_approvals`, not benchmarked yet.
+		Weight::from_parts(31_000_000, 14830)
+			.saturating_add(Weight::from_parts(16_397_299, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2623).saturating_mul(a.into()))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:1)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	fn freeze_amount() -> Weight {
+		// Estimated from `freeze` for the decoded freezes, not benchmarked yet.
+		Weight::from_parts(23_000_000, 7906)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:1)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	fn thaw_amount() -> Weight {
+		// Estimated from `thaw` for the decoded freezes, not benchmarked yet.
+		Weight::from_parts(22_000_000, 5297)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
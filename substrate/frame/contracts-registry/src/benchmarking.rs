@@ -22,6 +22,21 @@ fn get_data<T: Config<I>, I: 'static>(b: u8, s: usize) -> BoundedVec<u8, T::MaxC
 	vec![b; s].try_into().unwrap()
 }
 
+fn get_content_hash(data: &[u8]) -> ContentHash {
+	ContentHash { algorithm: HashAlgorithm::Blake2_256, hash: sp_io::hashing::blake2_256(data) }
+}
+
+fn get_blobs<T: Config<I>, I: 'static>(
+) -> BoundedVec<BoundedVec<u8, T::MaxBlobReferenceLen>, T::MaxBlobReferences> {
+	let blob: BoundedVec<u8, T::MaxBlobReferenceLen> =
+		vec![1u8; T::MaxBlobReferenceLen::get() as usize].try_into().unwrap();
+	vec![blob; T::MaxBlobReferences::get() as usize].try_into().unwrap()
+}
+
+fn get_info<T: Config<I>, I: 'static>() -> BoundedVec<u8, T::MaxJudgeInfoLen> {
+	vec![1u8; T::MaxJudgeInfoLen::get() as usize].try_into().unwrap()
+}
+
 benchmarks_instance_pallet! {
 	add_judge {
 		let origin =
@@ -105,6 +120,85 @@ benchmarks_instance_pallet! {
 	verify {
 		assert!(Contracts::<T, I>::get(0).is_none());
 	}
+
+	create_hashed_contract {
+		let s in 0 .. T::MaxContractContentLen::get() - 1;
+
+		let acc: T::AccountId = account("a", 1, SEED);
+		let origin = RawOrigin::Signed(acc.clone());
+
+		let summary = get_data::<T, I>(1, s as usize);
+		let content_hash = get_content_hash(&[1u8; 32]);
+		let parties: BoundedVec<T::AccountId, T::MaxParties> = vec![acc.clone(); T::MaxParties::get() as usize].try_into().unwrap();
+		let _ = T::Currency::make_free_balance_be(&acc, BalanceOf::<T, I>::max_value()/ 2u32.into());
+	}: _<T::RuntimeOrigin>(origin.into(), content_hash, get_blobs::<T, I>(), Some(summary), Some(parties))
+	verify {
+		assert!(ContentCommitments::<T, I>::get(0).is_some());
+	}
+
+	judge_sign_contract_hash {
+		let s in 0 .. T::MaxContractContentLen::get() - 1;
+
+		let acc: T::AccountId = account("a", 1, SEED);
+		let origin = RawOrigin::Signed(acc.clone());
+		let data = get_data::<T, I>(1, s as usize);
+		let content_hash = get_content_hash(&data[..]);
+		let _ = T::Currency::make_free_balance_be(&acc, BalanceOf::<T, I>::max_value() / 2u32.into());
+		assert_ok!(ContractsRegistry::<T, I>::create_contract(origin.clone().into(), data, None));
+
+		let acc: T::AccountId = account("a", 1, 1);
+		let origin = RawOrigin::Signed(acc.clone());
+		assert_ok!(ContractsRegistry::<T, I>::add_judge(RawOrigin::Root.into(), acc.clone()));
+	}: _<T::RuntimeOrigin>(origin.clone().into(), 0, content_hash)
+	verify {
+		ensure!(JudgesSignatures::<T, I>::get::<u32, T::AccountId>(0, acc), "Judges signed");
+	}
+
+	party_sign_contract_hash {
+		let s in 0 .. T::MaxContractContentLen::get() - 1;
+
+		let acc: T::AccountId = account("a", 1, SEED);
+		let origin = RawOrigin::Signed(acc.clone());
+		let data = get_data::<T, I>(1, s as usize);
+		let content_hash = get_content_hash(&data[..]);
+
+		let last_acc: T::AccountId = account("a", 1, 1);
+		let parties: BoundedVec<T::AccountId, T::MaxParties> = vec![last_acc.clone()].try_into().unwrap();
+		let _ = T::Currency::make_free_balance_be(&acc, BalanceOf::<T, I>::max_value()/ 2u32.into());
+		assert_ok!(ContractsRegistry::<T, I>::create_contract(origin.clone().into(), data, Some(parties)));
+
+		let origin = RawOrigin::Signed(last_acc.clone());
+	}: _<T::RuntimeOrigin>(origin.clone().into(), 0, content_hash)
+	verify {
+		ensure!(PartiesSignatures::<T, I>::get::<u32, T::AccountId>(0, last_acc), "Parties signed");
+	}
+
+	appoint_judge {
+		let origin =
+			T::AddJudgeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let user: T::AccountId = account("user", 0, SEED);
+		let info = JudgeInfo {
+			court: get_info::<T, I>(),
+			jurisdiction: get_info::<T, I>(),
+			term_end: Some(1u32.into()),
+			signature_weight: 1,
+		};
+	}: _<T::RuntimeOrigin>(origin, user.clone(), info)
+	verify {
+		ensure!(JudgesInfo::<T, I>::get(user).is_some(), "Judge not appointed");
+	}
+
+	set_judge_requirements {
+		let acc: T::AccountId = account("a", 1, SEED);
+		let origin = RawOrigin::Signed(acc.clone());
+		let data = get_data::<T, I>(1, 1 as usize);
+		let _ = T::Currency::make_free_balance_be(&acc, BalanceOf::<T, I>::max_value() / 2u32.into());
+		assert_ok!(ContractsRegistry::<T, I>::create_contract(origin.clone().into(), data, None));
+		let requirements = JudgeRequirements { court: Some(get_info::<T, I>()), required_signatures: 2 };
+	}: _<T::RuntimeOrigin>(origin.into(), 0, requirements)
+	verify {
+		ensure!(ContractJudgeRequirements::<T, I>::get(0).is_some(), "Requirements not set");
+	}
 }
 
 impl_benchmark_test_suite!(ContractsRegistry, crate::mock::new_test_ext(), crate::mock::Test,);
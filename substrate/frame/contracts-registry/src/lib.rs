@@ -10,7 +10,10 @@ pub mod tests;
 pub mod types;
 pub mod weights;
 
-pub use types::{ContractDataStorage, ContractIndex};
+pub use types::{
+	ContentCommitment, ContentHash, ContractDataStorage, ContractIndex, HashAlgorithm, JudgeInfo,
+	JudgeRequirements,
+};
 pub use weights::WeightInfo;
 
 use frame_support::traits::{Currency, NamedReservableCurrency};
@@ -24,9 +27,20 @@ pub mod pallet {
 
 	use frame_support::pallet_prelude::*;
 	use frame_support::StorageDoubleMap as StorageDoubleMapTrait;
-	use frame_system::pallet_prelude::OriginFor;
+	use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 	use sp_runtime::Saturating;
 
+	pub type ContentCommitmentOf<T, I> = ContentCommitment<
+		<T as Config<I>>::MaxContractContentLen,
+		<T as Config<I>>::MaxBlobReferences,
+		<T as Config<I>>::MaxBlobReferenceLen,
+	>;
+
+	pub type JudgeInfoOf<T, I> =
+		JudgeInfo<<T as Config<I>>::MaxJudgeInfoLen, BlockNumberFor<T>>;
+
+	pub type JudgeRequirementsOf<T, I> = JudgeRequirements<<T as Config<I>>::MaxJudgeInfoLen>;
+
 	type ReserveIdentifierOf<T, I> = <<T as Config<I>>::Currency as NamedReservableCurrency<
 		<T as frame_system::Config>::AccountId,
 	>>::ReserveIdentifier;
@@ -67,6 +81,18 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxParties: Get<u32>;
 
+		/// Maximum number of encrypted blob references per hash-committed contract
+		#[pallet::constant]
+		type MaxBlobReferences: Get<u32>;
+
+		/// Maximum length of a single encrypted blob reference
+		#[pallet::constant]
+		type MaxBlobReferenceLen: Get<u32>;
+
+		/// Maximum length of judge's court and jurisdiction
+		#[pallet::constant]
+		type MaxJudgeInfoLen: Get<u32>;
+
 		/// Origin from which a judge may be added
 		type AddJudgeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -93,6 +119,14 @@ pub mod pallet {
 		NotCreator,
 		/// Given contract is already in use
 		ContractInUse,
+		/// Given hash doesn't match contract content
+		ContentHashMismatch,
+		/// Judge's term has ended
+		JudgeTermEnded,
+		/// Judge doesn't belong to the court required by the contract
+		WrongCourt,
+		/// Judge's signature weight must be greater than zero
+		ZeroSignatureWeight,
 	}
 
 	#[pallet::event]
@@ -110,6 +144,18 @@ pub mod pallet {
 		ContractCreated { contract_id: ContractIndex, creator: T::AccountId },
 		/// Remove contract
 		ContractRemoved { contract_id: ContractIndex },
+		/// Created new contract with off-chain content
+		HashedContractCreated {
+			contract_id: ContractIndex,
+			creator: T::AccountId,
+			content_hash: ContentHash,
+		},
+		/// A judge has been appointed to a court.
+		JudgeAppointed { judge: T::AccountId, info: JudgeInfoOf<T, I> },
+		/// Judge requirements of a contract were set
+		JudgeRequirementsSet { contract_id: ContractIndex, requirements: JudgeRequirementsOf<T, I> },
+		/// All parties and required judges signed a contract
+		ContractFullyExecuted { contract_id: ContractIndex },
 	}
 
 	/// Contracts storage containing a content of contracts
@@ -125,6 +171,19 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Content commitments of contracts with off-chain content
+	///
+	/// TWOX-NOTE: Safe, as increasing integer keys are safe.
+	#[pallet::storage]
+	#[pallet::getter(fn content_commitments)]
+	pub type ContentCommitments<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		ContractIndex,
+		ContentCommitmentOf<T, I>,
+		OptionQuery,
+	>;
+
 	/// Vec of parties signatures for each contract
 	///
 	/// TWOX-NOTE: Safe, as increasing integer keys are safe.
@@ -160,6 +219,36 @@ pub mod pallet {
 	pub type Judges<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Twox64Concat, T::AccountId, bool, ValueQuery>;
 
+	/// Metadata of appointed judges
+	#[pallet::storage]
+	#[pallet::getter(fn judges_info)]
+	pub type JudgesInfo<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, JudgeInfoOf<T, I>, OptionQuery>;
+
+	/// Judge signatures required by contracts
+	///
+	/// TWOX-NOTE: Safe, as increasing integer keys are safe.
+	#[pallet::storage]
+	#[pallet::getter(fn judge_requirements)]
+	pub type ContractJudgeRequirements<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ContractIndex, JudgeRequirementsOf<T, I>, OptionQuery>;
+
+	/// Sum of signature weights of judges that signed each contract
+	///
+	/// TWOX-NOTE: Safe, as increasing integer keys are safe.
+	#[pallet::storage]
+	#[pallet::getter(fn judge_signatures_weight)]
+	pub type JudgeSignaturesWeight<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ContractIndex, u32, ValueQuery>;
+
+	/// Block at which contract was signed by all parties and required judges
+	///
+	/// TWOX-NOTE: Safe, as increasing integer keys are safe.
+	#[pallet::storage]
+	#[pallet::getter(fn fully_executed_at)]
+	pub type FullyExecuted<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ContractIndex, BlockNumberFor<T>, OptionQuery>;
+
 	/// The number of contracts that have been made so far.
 	#[pallet::storage]
 	#[pallet::getter(fn contracts_count)]
@@ -200,18 +289,7 @@ pub mod pallet {
 			contract_id: ContractIndex,
 		) -> DispatchResult {
 			let who = T::SubmitOrigin::ensure_origin(origin)?;
-			let is_judge = Judges::<T, I>::get(&who);
-			ensure!(is_judge, Error::<T, I>::NotJudge);
-
-			Contracts::<T, I>::get(contract_id).ok_or(Error::<T, I>::ContractNotFound)?;
-
-			let already_signed = JudgesSignatures::<T, I>::get(contract_id, who.clone());
-			ensure!(!already_signed, Error::<T, I>::AlreadySigned);
-
-			JudgesSignatures::<T, I>::insert(contract_id, who.clone(), true);
-
-			Self::deposit_event(Event::JudgeSigned { contract_id, signer: who });
-			Ok(())
+			Self::do_judge_sign(who, contract_id)
 		}
 
 		/// Any caller can create contract
@@ -281,21 +359,7 @@ pub mod pallet {
 			contract_id: ContractIndex,
 		) -> DispatchResult {
 			let who = T::SubmitOrigin::ensure_origin(origin)?;
-
-			let contracts =
-				Contracts::<T, I>::get(contract_id).ok_or(Error::<T, I>::ContractNotFound)?;
-
-			if let Some(parties) = contracts.parties {
-				ensure!(parties.contains(&who), Error::<T, I>::NotParty);
-			}
-
-			let already_signed = PartiesSignatures::<T, I>::get(contract_id, who.clone());
-			ensure!(!already_signed, Error::<T, I>::AlreadySigned);
-
-			PartiesSignatures::<T, I>::insert(contract_id, who.clone(), true);
-
-			Self::deposit_event(Event::PartySigned { contract_id, signer: who });
-			Ok(())
+			Self::do_party_sign(who, contract_id)
 		}
 
 		/// Remove judge
@@ -311,6 +375,7 @@ pub mod pallet {
 			T::AddJudgeOrigin::ensure_origin(origin)?;
 
 			Judges::<T, I>::remove(&judge);
+			JudgesInfo::<T, I>::remove(&judge);
 			Self::deposit_event(Event::RemovedJudge { judge });
 			Ok(())
 		}
@@ -343,11 +408,200 @@ pub mod pallet {
 
 			JudgesSignatures::<T, I>::drain_prefix(contract_id);
 			PartiesSignatures::<T, I>::drain_prefix(contract_id);
+			ContentCommitments::<T, I>::remove(contract_id);
+			ContractJudgeRequirements::<T, I>::remove(contract_id);
 			Contracts::<T, I>::remove(contract_id);
 
 			Self::deposit_event(Event::ContractRemoved { contract_id });
 			Ok(())
 		}
+
+		/// Create contract that stores only a hash of its content on-chain
+		///
+		/// The dispatch origin of this call must be _Signed_ and the sender must
+		/// have funds to cover the deposit.
+		///
+		/// - `content_hash`: Hash of the full contract content and algorithm used
+		/// - `encrypted_blobs`: References to encrypted copies of the content
+		/// - `summary`: Optional public summary of the contract
+		/// - `parties`: Parties that may sign the contract
+		///
+		/// Emits `HashedContractCreated`.
+		///
+		/// # <weight>
+		/// - `O(S)`
+		///   - where `S` summary len
+		/// # </weight>
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::create_hashed_contract(
+			T::MaxContractContentLen::get() // S
+		))]
+		pub fn create_hashed_contract(
+			origin: OriginFor<T>,
+			content_hash: ContentHash,
+			encrypted_blobs: BoundedVec<
+				BoundedVec<u8, T::MaxBlobReferenceLen>,
+				T::MaxBlobReferences,
+			>,
+			summary: Option<BoundedVec<u8, T::MaxContractContentLen>>,
+			parties: Option<BoundedVec<T::AccountId, T::MaxParties>>,
+		) -> DispatchResultWithPostInfo {
+			let who = T::SubmitOrigin::ensure_origin(origin)?;
+
+			let summary_len = summary.as_ref().map(|s| s.len() as u32).unwrap_or(0);
+			let commitment = ContentCommitmentOf::<T, I> { content_hash, encrypted_blobs, summary };
+
+			let required_deposit = Self::calculate_commitment_deposit(&commitment);
+			T::Currency::reserve_named(T::ReserveIdentifier::get(), &who, required_deposit)?;
+
+			let index = Self::contracts_count();
+			Contracts::<T, I>::insert(
+				index,
+				ContractDataStorage::<
+					T::MaxContractContentLen,
+					T::MaxParties,
+					T::AccountId,
+					BalanceOf<T, I>,
+				> {
+					data: Default::default(),
+					parties,
+					creator: who.clone(),
+					deposit: required_deposit,
+				},
+			);
+			ContentCommitments::<T, I>::insert(index, commitment);
+
+			NextContractsId::<T, I>::put(index + 1);
+
+			Self::deposit_event(Event::HashedContractCreated {
+				contract_id: index,
+				creator: who,
+				content_hash,
+			});
+			Ok(Some(T::WeightInfo::create_hashed_contract(
+				summary_len, // S
+			))
+			.into())
+		}
+
+		/// Sign contract as judge, confirming the hash of signed content
+		///
+		/// The dispatch origin of this call must be _Signed_
+		///
+		/// - `contract_id`: The `id` of a contract that should be signed
+		/// - `content_hash`: Hash of the content that the judge signs
+		///
+		/// Emits `JudgeSigned`.
+		///
+		/// # <weight>
+		/// - `O(S)`
+		///   - where `S` on-chain data len
+		/// # </weight>
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::judge_sign_contract_hash(
+			T::MaxContractContentLen::get() // S
+		))]
+		pub fn judge_sign_contract_hash(
+			origin: OriginFor<T>,
+			contract_id: ContractIndex,
+			content_hash: ContentHash,
+		) -> DispatchResultWithPostInfo {
+			let who = T::SubmitOrigin::ensure_origin(origin)?;
+			let hashed_len = Self::ensure_content_hash(contract_id, &content_hash)?;
+			Self::do_judge_sign(who, contract_id)?;
+			Ok(Some(T::WeightInfo::judge_sign_contract_hash(
+				hashed_len, // S
+			))
+			.into())
+		}
+
+		/// Sign contract as party, confirming the hash of signed content
+		///
+		/// The dispatch origin of this call must be _Signed_
+		///
+		/// - `contract_id`: The `id` of a contract that should be signed
+		/// - `content_hash`: Hash of the content that the party signs
+		///
+		/// Emits `PartySigned`.
+		///
+		/// # <weight>
+		/// - `O(S)`
+		///   - where `S` on-chain data len
+		/// # </weight>
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::party_sign_contract_hash(
+			T::MaxContractContentLen::get() // S
+		))]
+		pub fn party_sign_contract_hash(
+			origin: OriginFor<T>,
+			contract_id: ContractIndex,
+			content_hash: ContentHash,
+		) -> DispatchResultWithPostInfo {
+			let who = T::SubmitOrigin::ensure_origin(origin)?;
+			let hashed_len = Self::ensure_content_hash(contract_id, &content_hash)?;
+			Self::do_party_sign(who, contract_id)?;
+			Ok(Some(T::WeightInfo::party_sign_contract_hash(
+				hashed_len, // S
+			))
+			.into())
+		}
+
+		/// Appoint judge to a court
+		///
+		/// The dispatch origin of this call must be `AddJudgeOrigin`
+		///
+		/// - `judge`: Account that will be appointed as a judge
+		/// - `info`: Court, jurisdiction, term end and signature weight of the judge
+		///
+		/// Emits `JudgeAppointed`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::appoint_judge())]
+		pub fn appoint_judge(
+			origin: OriginFor<T>,
+			judge: T::AccountId,
+			info: JudgeInfoOf<T, I>,
+		) -> DispatchResult {
+			T::AddJudgeOrigin::ensure_origin(origin)?;
+			ensure!(info.signature_weight > 0, Error::<T, I>::ZeroSignatureWeight);
+
+			Judges::<T, I>::insert(&judge, true);
+			JudgesInfo::<T, I>::insert(&judge, info.clone());
+
+			Self::deposit_event(Event::JudgeAppointed { judge, info });
+			Ok(())
+		}
+
+		/// Set judge signatures required for the contract to be fully executed
+		///
+		/// The dispatch origin of this call must be _Signed_ by the contract
+		/// creator. Requirements can't be changed once anyone signed the contract.
+		///
+		/// - `contract_id`: ID of contract
+		/// - `requirements`: Required court and number of judge signatures
+		///
+		/// Emits `JudgeRequirementsSet`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_judge_requirements())]
+		pub fn set_judge_requirements(
+			origin: OriginFor<T>,
+			contract_id: ContractIndex,
+			requirements: JudgeRequirementsOf<T, I>,
+		) -> DispatchResult {
+			let who = T::SubmitOrigin::ensure_origin(origin)?;
+
+			let contract =
+				Contracts::<T, I>::get(contract_id).ok_or(Error::<T, I>::ContractNotFound)?;
+			ensure!(contract.creator == who, Error::<T, I>::NotCreator);
+
+			let contains_judges = JudgesSignatures::<T, I>::contains_prefix(contract_id);
+			let contains_parties = PartiesSignatures::<T, I>::contains_prefix(contract_id);
+			ensure!(!contains_judges && !contains_parties, Error::<T, I>::ContractInUse);
+
+			ContractJudgeRequirements::<T, I>::insert(contract_id, requirements.clone());
+
+			Self::deposit_event(Event::JudgeRequirementsSet { contract_id, requirements });
+			Ok(())
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -357,5 +611,141 @@ pub mod pallet {
 				.saturating_add(T::ByteDeposit::get().saturating_mul(data_len.into()));
 			required_deposit
 		}
+
+		fn calculate_commitment_deposit(commitment: &ContentCommitmentOf<T, I>) -> BalanceOf<T, I> {
+			let data_len = commitment.encoded_size() as u32;
+			T::BaseDeposit::get()
+				.saturating_add(T::ByteDeposit::get().saturating_mul(data_len.into()))
+		}
+
+		/// Checks `content_hash` against the contract. Returns length of the
+		/// on-chain data that had to be hashed.
+		fn ensure_content_hash(
+			contract_id: ContractIndex,
+			content_hash: &ContentHash,
+		) -> Result<u32, DispatchError> {
+			let commitment = ContentCommitments::<T, I>::get(contract_id);
+			let (expected, hashed_len) = match commitment {
+				Some(commitment) => (commitment.content_hash, 0),
+				None => {
+					let contract = Contracts::<T, I>::get(contract_id)
+						.ok_or(Error::<T, I>::ContractNotFound)?;
+					let hash = ContentHash {
+						algorithm: content_hash.algorithm,
+						hash: content_hash.algorithm.hash(&contract.data[..]),
+					};
+					(hash, contract.data.len() as u32)
+				},
+			};
+			ensure!(&expected == content_hash, Error::<T, I>::ContentHashMismatch);
+			Ok(hashed_len)
+		}
+
+		fn do_judge_sign(who: T::AccountId, contract_id: ContractIndex) -> DispatchResult {
+			let is_judge = Judges::<T, I>::get(&who);
+			ensure!(is_judge, Error::<T, I>::NotJudge);
+
+			Contracts::<T, I>::get(contract_id).ok_or(Error::<T, I>::ContractNotFound)?;
+
+			let info = JudgesInfo::<T, I>::get(&who);
+			if let Some(term_end) = info.as_ref().and_then(|i| i.term_end) {
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(now <= term_end, Error::<T, I>::JudgeTermEnded);
+			}
+
+			if let Some(court) = ContractJudgeRequirements::<T, I>::get(contract_id)
+				.and_then(|r| r.court)
+			{
+				let judge_court = info.as_ref().map(|i| &i.court);
+				ensure!(judge_court == Some(&court), Error::<T, I>::WrongCourt);
+			}
+
+			let already_signed = JudgesSignatures::<T, I>::get(contract_id, who.clone());
+			ensure!(!already_signed, Error::<T, I>::AlreadySigned);
+
+			JudgesSignatures::<T, I>::insert(contract_id, who.clone(), true);
+			let signature_weight = info.map(|i| i.signature_weight).unwrap_or(1);
+			JudgeSignaturesWeight::<T, I>::mutate(contract_id, |w| {
+				*w = w.saturating_add(signature_weight)
+			});
+
+			Self::deposit_event(Event::JudgeSigned { contract_id, signer: who });
+			Self::maybe_mark_fully_executed(contract_id);
+			Ok(())
+		}
+
+		fn do_party_sign(who: T::AccountId, contract_id: ContractIndex) -> DispatchResult {
+			let contracts =
+				Contracts::<T, I>::get(contract_id).ok_or(Error::<T, I>::ContractNotFound)?;
+
+			if let Some(parties) = contracts.parties {
+				ensure!(parties.contains(&who), Error::<T, I>::NotParty);
+			}
+
+			let already_signed = PartiesSignatures::<T, I>::get(contract_id, who.clone());
+			ensure!(!already_signed, Error::<T, I>::AlreadySigned);
+
+			PartiesSignatures::<T, I>::insert(contract_id, who.clone(), true);
+
+			Self::deposit_event(Event::PartySigned { contract_id, signer: who });
+			Self::maybe_mark_fully_executed(contract_id);
+			Ok(())
+		}
+
+		fn maybe_mark_fully_executed(contract_id: ContractIndex) {
+			if FullyExecuted::<T, I>::contains_key(contract_id) {
+				return
+			}
+
+			let contract = match Contracts::<T, I>::get(contract_id) {
+				Some(contract) => contract,
+				None => return,
+			};
+
+			// contracts without explicit parties need at least one party signature
+			let parties_signed = match contract.parties {
+				Some(parties) => parties
+					.iter()
+					.all(|party| PartiesSignatures::<T, I>::get(contract_id, party)),
+				None => PartiesSignatures::<T, I>::contains_prefix(contract_id),
+			};
+			if !parties_signed {
+				return
+			}
+
+			let required_signatures = ContractJudgeRequirements::<T, I>::get(contract_id)
+				.map(|r| r.required_signatures)
+				.unwrap_or(0);
+			if JudgeSignaturesWeight::<T, I>::get(contract_id) < required_signatures {
+				return
+			}
+
+			FullyExecuted::<T, I>::insert(contract_id, frame_system::Pallet::<T>::block_number());
+			Self::deposit_event(Event::ContractFullyExecuted { contract_id });
+		}
+
+		/// Check if contract was signed by all its parties and by judges
+		/// required by its `JudgeRequirements`.
+		pub fn is_fully_executed(contract_id: ContractIndex) -> bool {
+			FullyExecuted::<T, I>::contains_key(contract_id)
+		}
+
+		/// Content hash registered for given contract, if it was created with
+		/// off-chain content.
+		pub fn content_hash(contract_id: ContractIndex) -> Option<ContentHash> {
+			ContentCommitments::<T, I>::get(contract_id).map(|c| c.content_hash)
+		}
+
+		/// Check if `document` matches the content registered for given
+		/// contract. For contracts with on-chain content, the document is
+		/// compared with stored data directly.
+		pub fn verify_content(contract_id: ContractIndex, document: &[u8]) -> bool {
+			if let Some(commitment) = ContentCommitments::<T, I>::get(contract_id) {
+				return commitment.content_hash.matches(document)
+			}
+			Contracts::<T, I>::get(contract_id)
+				.map(|contract| &contract.data[..] == document)
+				.unwrap_or(false)
+		}
 	}
 }
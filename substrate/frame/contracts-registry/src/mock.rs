@@ -79,6 +79,9 @@ impl pallet_contracts_registry::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxContractContentLen = ConstU32<{ 2u32 * 1024u32 * 1024u32 }>;
 	type MaxParties = ConstU32<16u32>;
+	type MaxBlobReferences = ConstU32<4u32>;
+	type MaxBlobReferenceLen = ConstU32<64u32>;
+	type MaxJudgeInfoLen = ConstU32<32u32>;
 	type AddJudgeOrigin = EnsureRoot<Self::AccountId>;
 	type SubmitOrigin = EnsureSigned<Self::AccountId>;
 	type WeightInfo = ();
@@ -5,8 +5,38 @@ use frame_support::{assert_noop, assert_ok};
 use frame_system::RawOrigin;
 use sp_std::prelude::*;
 
-use crate::{mock::*, Error};
-use crate::{Contracts, Event, Judges};
+use crate::{mock::*, ContentHash, Error, HashAlgorithm, JudgeInfo, JudgeRequirements, WeightInfo};
+use crate::{ContentCommitments, Contracts, Event, Judges, JudgesInfo};
+
+fn content_hash(document: &[u8]) -> ContentHash {
+	ContentHash { algorithm: HashAlgorithm::Blake2_256, hash: sp_io::hashing::blake2_256(document) }
+}
+
+fn judge_info(court: &[u8], term_end: Option<u64>, signature_weight: u32) -> crate::JudgeInfoOf<Test, ()> {
+	JudgeInfo {
+		court: court.to_vec().try_into().unwrap(),
+		jurisdiction: b"Liberland".to_vec().try_into().unwrap(),
+		term_end,
+		signature_weight,
+	}
+}
+
+fn requirements(court: Option<&[u8]>, required_signatures: u32) -> crate::JudgeRequirementsOf<Test, ()> {
+	JudgeRequirements {
+		court: court.map(|c| c.to_vec().try_into().unwrap()),
+		required_signatures,
+	}
+}
+
+fn create_hashed_contract(creator: u64, document: &[u8], parties: Option<Vec<u64>>) {
+	assert_ok!(ContractsRegistry::create_hashed_contract(
+		RuntimeOrigin::signed(creator),
+		content_hash(document),
+		vec![vec![1, 2, 3].try_into().unwrap()].try_into().unwrap(),
+		Some(vec![4, 5].try_into().unwrap()),
+		parties.map(|p| p.try_into().unwrap()),
+	));
+}
 
 #[test]
 fn anyone_can_create_contract() {
@@ -476,3 +506,320 @@ fn anyone_can_sign_contract_without_party() {
 		assert_ok!(ContractsRegistry::party_sign_contract(RawOrigin::Signed(1).into(), 0));
 	});
 }
+
+#[test]
+fn anyone_can_create_hashed_contract() {
+	new_test_ext().execute_with(|| {
+		create_hashed_contract(2, b"secret", None);
+
+		let contract = Contracts::<Test>::get(0).unwrap();
+		assert!(contract.data.is_empty());
+		let commitment = ContentCommitments::<Test>::get(0).unwrap();
+		assert_eq!(commitment.content_hash, content_hash(b"secret"));
+		assert_eq!(commitment.summary, Some(vec![4, 5].try_into().unwrap()));
+		assert_eq!(Balances::reserved_balance(2), contract.deposit);
+		System::assert_last_event(
+			Event::<Test>::HashedContractCreated {
+				contract_id: 0,
+				creator: 2,
+				content_hash: content_hash(b"secret"),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn party_can_sign_contract_hash() {
+	new_test_ext().execute_with(|| {
+		create_hashed_contract(2, b"secret", Some(vec![1]));
+
+		assert_noop!(
+			ContractsRegistry::party_sign_contract_hash(
+				RuntimeOrigin::signed(1),
+				0,
+				content_hash(b"other")
+			),
+			Error::<Test>::ContentHashMismatch
+		);
+		assert_ok!(ContractsRegistry::party_sign_contract_hash(
+			RuntimeOrigin::signed(1),
+			0,
+			content_hash(b"secret")
+		));
+		System::assert_last_event(Event::<Test>::PartySigned { contract_id: 0, signer: 1 }.into());
+	});
+}
+
+#[test]
+fn judge_can_sign_contract_hash() {
+	new_test_ext().execute_with(|| {
+		create_hashed_contract(2, b"secret", None);
+		assert_ok!(ContractsRegistry::add_judge(RuntimeOrigin::root(), 1));
+
+		assert_noop!(
+			ContractsRegistry::judge_sign_contract_hash(
+				RuntimeOrigin::signed(1),
+				0,
+				content_hash(b"other")
+			),
+			Error::<Test>::ContentHashMismatch
+		);
+		assert_ok!(ContractsRegistry::judge_sign_contract_hash(
+			RuntimeOrigin::signed(1),
+			0,
+			content_hash(b"secret")
+		));
+		System::assert_last_event(Event::<Test>::JudgeSigned { contract_id: 0, signer: 1 }.into());
+	});
+}
+
+#[test]
+fn sign_contract_hash_works_for_on_chain_content() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ContractsRegistry::create_contract(
+			RuntimeOrigin::signed(2),
+			vec![3, 4, 5].try_into().unwrap(),
+			None
+		));
+
+		assert_noop!(
+			ContractsRegistry::party_sign_contract_hash(
+				RuntimeOrigin::signed(1),
+				0,
+				content_hash(&[3, 4])
+			),
+			Error::<Test>::ContentHashMismatch
+		);
+		let post_info = ContractsRegistry::party_sign_contract_hash(
+			RuntimeOrigin::signed(1),
+			0,
+			content_hash(&[3, 4, 5]),
+		)
+		.unwrap();
+		// only the stored data is charged for
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::party_sign_contract_hash(3)));
+	});
+}
+
+#[test]
+fn verify_content_works() {
+	new_test_ext().execute_with(|| {
+		create_hashed_contract(2, b"secret", None);
+		assert_ok!(ContractsRegistry::create_contract(
+			RuntimeOrigin::signed(2),
+			vec![3, 4, 5].try_into().unwrap(),
+			None
+		));
+
+		assert!(ContractsRegistry::verify_content(0, b"secret"));
+		assert!(!ContractsRegistry::verify_content(0, b"other"));
+		assert!(ContractsRegistry::verify_content(1, &[3, 4, 5]));
+		assert!(!ContractsRegistry::verify_content(1, &[3, 4]));
+		assert!(!ContractsRegistry::verify_content(2, &[]));
+	});
+}
+
+#[test]
+fn remove_hashed_contract_clears_commitment() {
+	new_test_ext().execute_with(|| {
+		create_hashed_contract(2, b"secret", None);
+		assert_ok!(ContractsRegistry::remove_contract(RuntimeOrigin::signed(2), 0));
+		assert!(ContentCommitments::<Test>::get(0).is_none());
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn root_can_appoint_judge() {
+	new_test_ext().execute_with(|| {
+		let info = judge_info(b"civil", Some(10), 2);
+		assert_noop!(
+			ContractsRegistry::appoint_judge(RuntimeOrigin::signed(1), 1, info.clone()),
+			frame_support::error::BadOrigin
+		);
+		assert_noop!(
+			ContractsRegistry::appoint_judge(
+				RuntimeOrigin::root(),
+				1,
+				judge_info(b"civil", Some(10), 0)
+			),
+			Error::<Test>::ZeroSignatureWeight
+		);
+		assert_ok!(ContractsRegistry::appoint_judge(RuntimeOrigin::root(), 1, info.clone()));
+		assert!(Judges::<Test>::get(1));
+		assert_eq!(JudgesInfo::<Test>::get(1), Some(info.clone()));
+		System::assert_last_event(Event::<Test>::JudgeAppointed { judge: 1, info }.into());
+	});
+}
+
+#[test]
+fn remove_judge_clears_judge_info() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ContractsRegistry::appoint_judge(
+			RuntimeOrigin::root(),
+			1,
+			judge_info(b"civil", None, 1)
+		));
+		assert_ok!(ContractsRegistry::remove_judge(RuntimeOrigin::root(), 1));
+		assert!(!Judges::<Test>::get(1));
+		assert!(JudgesInfo::<Test>::get(1).is_none());
+	});
+}
+
+#[test]
+fn judge_can_not_sign_after_term_end() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ContractsRegistry::create_contract(
+			RuntimeOrigin::signed(2),
+			vec![].try_into().unwrap(),
+			None
+		));
+		assert_ok!(ContractsRegistry::appoint_judge(
+			RuntimeOrigin::root(),
+			1,
+			judge_info(b"civil", Some(5), 1)
+		));
+
+		System::set_block_number(6);
+		assert_noop!(
+			ContractsRegistry::judge_sign_contract(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::JudgeTermEnded
+		);
+
+		System::set_block_number(5);
+		assert_ok!(ContractsRegistry::judge_sign_contract(RuntimeOrigin::signed(1), 0));
+	});
+}
+
+#[test]
+fn judge_must_be_from_required_court() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ContractsRegistry::create_contract(
+			RuntimeOrigin::signed(2),
+			vec![].try_into().unwrap(),
+			None
+		));
+		assert_ok!(ContractsRegistry::set_judge_requirements(
+			RuntimeOrigin::signed(2),
+			0,
+			requirements(Some(b"civil"), 1)
+		));
+		assert_ok!(ContractsRegistry::add_judge(RuntimeOrigin::root(), 1));
+		assert_ok!(ContractsRegistry::appoint_judge(
+			RuntimeOrigin::root(),
+			3,
+			judge_info(b"criminal", None, 1)
+		));
+		assert_ok!(ContractsRegistry::appoint_judge(
+			RuntimeOrigin::root(),
+			4,
+			judge_info(b"civil", None, 1)
+		));
+
+		assert_noop!(
+			ContractsRegistry::judge_sign_contract(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::WrongCourt
+		);
+		assert_noop!(
+			ContractsRegistry::judge_sign_contract(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::WrongCourt
+		);
+		assert_ok!(ContractsRegistry::judge_sign_contract(RuntimeOrigin::signed(4), 0));
+	});
+}
+
+#[test]
+fn only_creator_can_set_judge_requirements_before_signing() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ContractsRegistry::set_judge_requirements(
+				RuntimeOrigin::signed(2),
+				0,
+				requirements(None, 1)
+			),
+			Error::<Test>::ContractNotFound
+		);
+		assert_ok!(ContractsRegistry::create_contract(
+			RuntimeOrigin::signed(2),
+			vec![].try_into().unwrap(),
+			None
+		));
+		assert_noop!(
+			ContractsRegistry::set_judge_requirements(
+				RuntimeOrigin::signed(1),
+				0,
+				requirements(None, 1)
+			),
+			Error::<Test>::NotCreator
+		);
+		assert_ok!(ContractsRegistry::set_judge_requirements(
+			RuntimeOrigin::signed(2),
+			0,
+			requirements(None, 1)
+		));
+		System::assert_last_event(
+			Event::<Test>::JudgeRequirementsSet {
+				contract_id: 0,
+				requirements: requirements(None, 1),
+			}
+			.into(),
+		);
+
+		assert_ok!(ContractsRegistry::party_sign_contract(RuntimeOrigin::signed(1), 0));
+		assert_noop!(
+			ContractsRegistry::set_judge_requirements(
+				RuntimeOrigin::signed(2),
+				0,
+				requirements(None, 2)
+			),
+			Error::<Test>::ContractInUse
+		);
+	});
+}
+
+#[test]
+fn contract_is_fully_executed_with_enough_judge_signatures() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ContractsRegistry::create_contract(
+			RuntimeOrigin::signed(2),
+			vec![].try_into().unwrap(),
+			Some(vec![1, 2].try_into().unwrap())
+		));
+		assert_ok!(ContractsRegistry::set_judge_requirements(
+			RuntimeOrigin::signed(2),
+			0,
+			requirements(None, 3)
+		));
+		assert_ok!(ContractsRegistry::add_judge(RuntimeOrigin::root(), 3));
+		assert_ok!(ContractsRegistry::appoint_judge(
+			RuntimeOrigin::root(),
+			4,
+			judge_info(b"civil", None, 2)
+		));
+
+		assert_ok!(ContractsRegistry::party_sign_contract(RuntimeOrigin::signed(1), 0));
+		assert_ok!(ContractsRegistry::party_sign_contract(RuntimeOrigin::signed(2), 0));
+		assert_ok!(ContractsRegistry::judge_sign_contract(RuntimeOrigin::signed(3), 0));
+		assert!(!ContractsRegistry::is_fully_executed(0));
+
+		assert_ok!(ContractsRegistry::judge_sign_contract(RuntimeOrigin::signed(4), 0));
+		assert!(ContractsRegistry::is_fully_executed(0));
+		System::assert_last_event(Event::<Test>::ContractFullyExecuted { contract_id: 0 }.into());
+	});
+}
+
+#[test]
+fn contract_is_not_fully_executed_until_all_parties_sign() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ContractsRegistry::create_contract(
+			RuntimeOrigin::signed(2),
+			vec![].try_into().unwrap(),
+			Some(vec![1, 2].try_into().unwrap())
+		));
+		assert_ok!(ContractsRegistry::party_sign_contract(RuntimeOrigin::signed(1), 0));
+		assert!(!ContractsRegistry::is_fully_executed(0));
+		assert_ok!(ContractsRegistry::party_sign_contract(RuntimeOrigin::signed(2), 0));
+		assert!(ContractsRegistry::is_fully_executed(0));
+	});
+}
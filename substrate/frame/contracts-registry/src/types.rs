@@ -1,5 +1,8 @@
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{sp_runtime::RuntimeDebug, traits::Get, BoundedVec};
+use frame_support::{
+	sp_runtime::RuntimeDebug, traits::Get, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound,
+	RuntimeDebugNoBound,
+};
 use scale_info::TypeInfo;
 
 pub type ContractIndex = u32;
@@ -8,10 +11,116 @@ pub type ContractIndex = u32;
 #[scale_info(skip_type_params(MaxContractsLen, MaxParties))]
 pub struct ContractDataStorage<MaxContractsLen: Get<u32>, MaxParties: Get<u32>, AccountId, Balance>
 {
-	// Content of contract
+	// Content of contract. Empty for contracts registered with a content hash only.
 	pub data: BoundedVec<u8, MaxContractsLen>,
 	// Vec of parties that may sign following contract
 	pub parties: Option<BoundedVec<AccountId, MaxParties>>,
 	pub creator: AccountId,
 	pub deposit: Balance,
 }
+
+/// Hashing algorithm used to commit to an off-chain contract content.
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum HashAlgorithm {
+	Blake2_256,
+	Sha2_256,
+	Keccak256,
+}
+
+impl HashAlgorithm {
+	/// Hash `data` with this algorithm.
+	pub fn hash(&self, data: &[u8]) -> [u8; 32] {
+		match self {
+			Self::Blake2_256 => sp_io::hashing::blake2_256(data),
+			Self::Sha2_256 => sp_io::hashing::sha2_256(data),
+			Self::Keccak256 => sp_io::hashing::keccak_256(data),
+		}
+	}
+}
+
+/// Hash of a contract content, tagged with the algorithm that produced it.
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ContentHash {
+	pub algorithm: HashAlgorithm,
+	pub hash: [u8; 32],
+}
+
+impl ContentHash {
+	/// Check if `document` matches this hash.
+	pub fn matches(&self, document: &[u8]) -> bool {
+		self.algorithm.hash(document) == self.hash
+	}
+}
+
+/// Commitment to a contract content that is kept off-chain.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+)]
+#[codec(mel_bound())]
+#[scale_info(skip_type_params(MaxSummaryLen, MaxBlobReferences, MaxBlobReferenceLen))]
+pub struct ContentCommitment<
+	MaxSummaryLen: Get<u32>,
+	MaxBlobReferences: Get<u32>,
+	MaxBlobReferenceLen: Get<u32>,
+> {
+	// Hash of the full contract content
+	pub content_hash: ContentHash,
+	// References to encrypted copies of the content (i.e. IPFS CIDs)
+	pub encrypted_blobs: BoundedVec<BoundedVec<u8, MaxBlobReferenceLen>, MaxBlobReferences>,
+	// Public summary of the contract
+	pub summary: Option<BoundedVec<u8, MaxSummaryLen>>,
+}
+
+/// Metadata of an appointed judge.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+)]
+#[codec(mel_bound(BlockNumber: MaxEncodedLen))]
+#[scale_info(skip_type_params(MaxInfoLen))]
+pub struct JudgeInfo<
+	MaxInfoLen: Get<u32>,
+	BlockNumber: Clone + PartialEq + Eq + core::fmt::Debug,
+> {
+	// Court the judge belongs to
+	pub court: BoundedVec<u8, MaxInfoLen>,
+	// Jurisdiction of the judge
+	pub jurisdiction: BoundedVec<u8, MaxInfoLen>,
+	// Block after which judge can't sign contracts anymore
+	pub term_end: Option<BlockNumber>,
+	// How many signatures is a single signature of this judge worth
+	pub signature_weight: u32,
+}
+
+/// Judge signatures required for a contract to be fully executed.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+)]
+#[codec(mel_bound())]
+#[scale_info(skip_type_params(MaxInfoLen))]
+pub struct JudgeRequirements<MaxInfoLen: Get<u32>> {
+	// If set, only judges of this court may sign the contract
+	pub court: Option<BoundedVec<u8, MaxInfoLen>>,
+	// Required sum of signature weights of judges that signed the contract
+	pub required_signatures: u32,
+}
@@ -36,6 +36,11 @@ pub trait WeightInfo {
 	fn party_sign_contract() -> Weight;
 	fn remove_judge() -> Weight;
 	fn remove_contract() -> Weight;
+	fn create_hashed_contract(s: u32, ) -> Weight;
+	fn judge_sign_contract_hash(s: u32, ) -> Weight;
+	fn party_sign_contract_hash(s: u32, ) -> Weight;
+	fn appoint_judge() -> Weight;
+	fn set_judge_requirements() -> Weight;
 }
 
 /// Weights for pallet_contracts_registry using the Substrate node and recommended hardware.
@@ -128,6 +133,95 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Balances::Reserves` (r:1 w:1)
+	/// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::NextContractsId` (r:1 w:1)
+	/// Proof: `ContractsRegistry::NextContractsId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::ContentCommitments` (r:0 w:1)
+	/// Proof: `ContractsRegistry::ContentCommitments` (`max_values`: None, `max_size`: Some(2105398), added: 2107873, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::Contracts` (r:0 w:1)
+	/// Proof: `ContractsRegistry::Contracts` (`max_values`: None, `max_size`: Some(2097730), added: 2100205, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 2097151]`.
+	fn create_hashed_contract(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `283`
+		//  Estimated: `4714`
+		// Minimum execution time: 49_412_000 picoseconds.
+		Weight::from_parts(50_137_000, 4714)
+			// Standard Error: 3
+			.saturating_add(Weight::from_parts(716, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ContractsRegistry::ContentCommitments` (r:1 w:0)
+	/// Proof: `ContractsRegistry::ContentCommitments` (`max_values`: None, `max_size`: Some(2105398), added: 2107873, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::Judges` (r:1 w:0)
+	/// Proof: `ContractsRegistry::Judges` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::Contracts` (r:1 w:0)
+	/// Proof: `ContractsRegistry::Contracts` (`max_values`: None, `max_size`: Some(2097730), added: 2100205, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::JudgesSignatures` (r:1 w:1)
+	/// Proof: `ContractsRegistry::JudgesSignatures` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 2097151]`.
+	fn judge_sign_contract_hash(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `2108863`
+		// Minimum execution time: 33_563_000 picoseconds.
+		Weight::from_parts(34_105_000, 2108863)
+			// Estimated from `create_contract` plus blake2 hashing, not benchmarked yet.
+			.saturating_add(Weight::from_parts(1_850, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ContractsRegistry::ContentCommitments` (r:1 w:0)
+	/// Proof: `ContractsRegistry::ContentCommitments` (`max_values`: None, `max_size`: Some(2105398), added: 2107873, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::Contracts` (r:1 w:0)
+	/// Proof: `ContractsRegistry::Contracts` (`max_values`: None, `max_size`: Some(2097730), added: 2100205, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::PartiesSignatures` (r:1 w:1)
+	/// Proof: `ContractsRegistry::PartiesSignatures` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 2097151]`.
+	fn party_sign_contract_hash(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `385`
+		//  Estimated: `2108863`
+		// Minimum execution time: 29_871_000 picoseconds.
+		Weight::from_parts(30_352_000, 2108863)
+			// Estimated from `create_contract` plus blake2 hashing, not benchmarked yet.
+			.saturating_add(Weight::from_parts(1_850, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ContractsRegistry::JudgesInfo` (r:0 w:1)
+	/// Proof: `ContractsRegistry::JudgesInfo` (`max_values`: None, `max_size`: Some(339), added: 2814, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::Judges` (r:0 w:1)
+	/// Proof: `ContractsRegistry::Judges` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	fn appoint_judge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 15_239_000 picoseconds.
+		Weight::from_parts(15_640_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ContractsRegistry::Contracts` (r:1 w:0)
+	/// Proof: `ContractsRegistry::Contracts` (`max_values`: None, `max_size`: Some(2097730), added: 2100205, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::JudgesSignatures` (r:1 w:0)
+	/// Proof: `ContractsRegistry::JudgesSignatures` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::PartiesSignatures` (r:1 w:0)
+	/// Proof: `ContractsRegistry::PartiesSignatures` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::ContractJudgeRequirements` (r:0 w:1)
+	/// Proof: `ContractsRegistry::ContractJudgeRequirements` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	fn set_judge_requirements() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `310`
+		//  Estimated: `2101195`
+		// Minimum execution time: 27_824_000 picoseconds.
+		Weight::from_parts(28_436_000, 2101195)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -219,4 +313,93 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Balances::Reserves` (r:1 w:1)
+	/// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::NextContractsId` (r:1 w:1)
+	/// Proof: `ContractsRegistry::NextContractsId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::ContentCommitments` (r:0 w:1)
+	/// Proof: `ContractsRegistry::ContentCommitments` (`max_values`: None, `max_size`: Some(2105398), added: 2107873, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::Contracts` (r:0 w:1)
+	/// Proof: `ContractsRegistry::Contracts` (`max_values`: None, `max_size`: Some(2097730), added: 2100205, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 2097151]`.
+	fn create_hashed_contract(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `283`
+		//  Estimated: `4714`
+		// Minimum execution time: 49_412_000 picoseconds.
+		Weight::from_parts(50_137_000, 4714)
+			// Standard Error: 3
+			.saturating_add(Weight::from_parts(716, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ContractsRegistry::ContentCommitments` (r:1 w:0)
+	/// Proof: `ContractsRegistry::ContentCommitments` (`max_values`: None, `max_size`: Some(2105398), added: 2107873, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::Judges` (r:1 w:0)
+	/// Proof: `ContractsRegistry::Judges` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::Contracts` (r:1 w:0)
+	/// Proof: `ContractsRegistry::Contracts` (`max_values`: None, `max_size`: Some(2097730), added: 2100205, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::JudgesSignatures` (r:1 w:1)
+	/// Proof: `ContractsRegistry::JudgesSignatures` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 2097151]`.
+	fn judge_sign_contract_hash(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `2108863`
+		// Minimum execution time: 33_563_000 picoseconds.
+		Weight::from_parts(34_105_000, 2108863)
+			// Estimated from `create_contract` plus blake2 hashing, not benchmarked yet.
+			.saturating_add(Weight::from_parts(1_850, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ContractsRegistry::ContentCommitments` (r:1 w:0)
+	/// Proof: `ContractsRegistry::ContentCommitments` (`max_values`: None, `max_size`: Some(2105398), added: 2107873, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::Contracts` (r:1 w:0)
+	/// Proof: `ContractsRegistry::Contracts` (`max_values`: None, `max_size`: Some(2097730), added: 2100205, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::PartiesSignatures` (r:1 w:1)
+	/// Proof: `ContractsRegistry::PartiesSignatures` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 2097151]`.
+	fn party_sign_contract_hash(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `385`
+		//  Estimated: `2108863`
+		// Minimum execution time: 29_871_000 picoseconds.
+		Weight::from_parts(30_352_000, 2108863)
+			// Estimated from `create_contract` plus blake2 hashing, not benchmarked yet.
+			.saturating_add(Weight::from_parts(1_850, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ContractsRegistry::JudgesInfo` (r:0 w:1)
+	/// Proof: `ContractsRegistry::JudgesInfo` (`max_values`: None, `max_size`: Some(339), added: 2814, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::Judges` (r:0 w:1)
+	/// Proof: `ContractsRegistry::Judges` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	fn appoint_judge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 15_239_000 picoseconds.
+		Weight::from_parts(15_640_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ContractsRegistry::Contracts` (r:1 w:0)
+	/// Proof: `ContractsRegistry::Contracts` (`max_values`: None, `max_size`: Some(2097730), added: 2100205, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::JudgesSignatures` (r:1 w:0)
+	/// Proof: `ContractsRegistry::JudgesSignatures` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::PartiesSignatures` (r:1 w:0)
+	/// Proof: `ContractsRegistry::PartiesSignatures` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `ContractsRegistry::ContractJudgeRequirements` (r:0 w:1)
+	/// Proof: `ContractsRegistry::ContractJudgeRequirements` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	fn set_judge_requirements() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `310`
+		//  Estimated: `2101195`
+		// Minimum execution time: 27_824_000 picoseconds.
+		Weight::from_parts(28_436_000, 2101195)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
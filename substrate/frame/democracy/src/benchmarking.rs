@@ -53,11 +53,20 @@ fn make_proposal<T: Config>(n: u32) -> BoundedCallOf<T> {
 	<T as Config>::Preimages::bound(call).unwrap()
 }
 
+fn root_track<T: Config>() -> TrackId {
+	T::Tracks::tracks()
+		.iter()
+		.map(|(track, _)| *track)
+		.find(|track| T::RootTracks::contains(track))
+		.expect("at least one track allows Root proposals")
+}
+
 fn add_proposal<T: Config>(n: u32) -> Result<H256, &'static str> {
 	let other = funded_account::<T>("proposer", n);
 	let value = T::MinimumDeposit::get();
 	let proposal = make_proposal::<T>(n);
-	Democracy::<T>::propose(RawOrigin::Signed(other).into(), proposal.clone(), value)?;
+	let track = root_track::<T>();
+	Democracy::<T>::propose(RawOrigin::Signed(other).into(), track, proposal.clone(), value)?;
 	Ok(proposal.hash())
 }
 
@@ -114,8 +123,9 @@ benchmarks! {
 		let caller = funded_account::<T>("caller", 0);
 		let proposal = make_proposal::<T>(0);
 		let value = T::MinimumDeposit::get();
+		let track = root_track::<T>();
 		whitelist_account!(caller);
-	}: _(RawOrigin::Signed(caller), proposal, value)
+	}: _(RawOrigin::Signed(caller), track, proposal, value)
 	verify {
 		assert_eq!(Democracy::<T>::public_props().len(), p as usize, "Proposals not created.");
 	}
@@ -190,10 +190,10 @@ pub use conviction::Conviction;
 pub use pallet::*;
 pub use types::{
 	Delegations, MetadataOwner, PropIndex, ReferendumIndex, ReferendumInfo, ReferendumStatus, DispatchOrigin,
-	Tally, UnvoteScope,
+	Tally, TrackId, TrackInfo, TracksInfo, UnvoteScope,
 };
 pub use vote::{AccountVote, Vote, Voting};
-pub use vote_threshold::{Approved, VoteThreshold};
+pub use vote_threshold::{Approved, Quorum, VoteThreshold};
 pub use weights::WeightInfo;
 
 #[cfg(test)]
@@ -216,6 +216,8 @@ type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup
 pub enum RawOrigin<Balance> {
 	// Tally of passed referendum, total electorate
 	Referendum(Tally<Balance>, Balance),
+	// Track, tally of passed referendum, total electorate
+	Track(TrackId, Tally<Balance>, Balance),
 }
 
 #[frame_support::pallet]
@@ -322,8 +324,12 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxAdditionalFields: Get<u32>;
 
-		#[pallet::constant]
-		type ProposalFeeAmount: Get<BalanceOf<Self>>;
+		/// Tracks public proposals are made on, with their voting parameters.
+		type Tracks: TracksInfo<BalanceOf<Self>, BlockNumberFor<Self>>;
+
+		/// Tracks on which proposals dispatched as Root may be made. Proposals on other tracks
+		/// can only dispatch with `RawOrigin::Track`.
+		type RootTracks: Contains<TrackId>;
 
 		/// Origin from which the next tabled referendum may be forced; this allows for the tabling
 		/// of a negative-turnout-bias (default-carries) referendum.
@@ -364,6 +370,15 @@ pub mod pallet {
 		type DelegateeFilter: Contains<Self::AccountId>;
 		type LLInitializer: LLInitializer<Self::AccountId>;
 
+		/// Quorum for referenda dispatched as Root that aren't on a track.
+		#[pallet::constant]
+		type RootQuorum: Get<Quorum>;
+
+		/// Quorum for referenda dispatched with `RawOrigin::Referendum`, i.e. rich referenda that
+		/// aren't on a track.
+		#[pallet::constant]
+		type RichQuorum: Get<Quorum>;
+
 		type ProposalFee: OnUnbalanced<<<Self as Config>::Currency as Currency<<Self as frame_system::Config>::AccountId>>::NegativeImbalance>;
 	}
 
@@ -381,6 +396,18 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Tracks of the public proposals. Proposals made before tracks were introduced have none.
+	#[pallet::storage]
+	pub type ProposalTracks<T> = StorageMap<_, Twox64Concat, PropIndex, TrackId>;
+
+	/// Tracks of the ongoing referenda started from public proposals.
+	#[pallet::storage]
+	pub type ReferendumTracks<T> = StorageMap<_, Twox64Concat, ReferendumIndex, TrackId>;
+
+	/// Number of ongoing referenda on each track.
+	#[pallet::storage]
+	pub type DecidingCount<T> = StorageMap<_, Twox64Concat, TrackId, u32, ValueQuery>;
+
 	/// Those who have locked a deposit.
 	///
 	/// TWOX-NOTE: Safe, as increasing integer keys are safe.
@@ -594,6 +621,10 @@ pub mod pallet {
 		InvalidDelegateTarget,
 		/// The preimage does not exist.
 		PreimageNotExist,
+		/// The track does not exist.
+		BadTrack,
+		/// Proposals on this track can't be dispatched as Root.
+		RootNotAllowed,
 	}
 
 	#[pallet::hooks]
@@ -611,19 +642,23 @@ pub mod pallet {
 		/// The dispatch origin of this call must be _Signed_ and the sender must
 		/// have funds to cover the deposit.
 		///
+		/// - `track`: The track to propose on.
 		/// - `proposal_hash`: The hash of the proposal preimage.
 		/// - `value`: The amount of deposit (must be at least `MinimumDeposit`).
 		///
+		/// Action will be dispatched as Root, so `track` must be one of `RootTracks`.
+		///
 		/// Emits `Proposed`.
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::propose())]
 		pub fn propose(
 			origin: OriginFor<T>,
+			track: TrackId,
 			proposal: BoundedCallOf<T>,
 			#[pallet::compact] value: BalanceOf<T>,
 		) -> DispatchResult {
 			let who = T::SubmitOrigin::ensure_origin(origin)?;
-			Self::do_propose(who, proposal, value, DispatchOrigin::Root)
+			Self::do_propose(who, track, proposal, value, DispatchOrigin::Root)
 		}
 
 		/// Propose a sensitive action to be taken.
@@ -631,21 +666,23 @@ pub mod pallet {
 		/// The dispatch origin of this call must be _Signed_ and the sender must
 		/// have funds to cover the deposit.
 		///
+		/// - `track`: The track to propose on.
 		/// - `proposal_hash`: The hash of the proposal preimage.
 		/// - `value`: The amount of deposit (must be at least `MinimumDeposit`).
 		///
-		/// Action will be dispatched with pallet_democracy::RawOrigin::Referendum origin.
+		/// Action will be dispatched with pallet_democracy::RawOrigin::Track origin.
 		/// 
 		/// Emits `Proposed`.
 		#[pallet::call_index(100)]
 		#[pallet::weight(T::WeightInfo::propose())]
 		pub fn propose_rich_origin(
 			origin: OriginFor<T>,
+			track: TrackId,
 			proposal: BoundedCallOf<T>,
 			#[pallet::compact] value: BalanceOf<T>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			Self::do_propose(who, proposal, value, DispatchOrigin::Rich)
+			Self::do_propose(who, track, proposal, value, DispatchOrigin::Rich)
 		}
 
 		/// Signals agreement with a particular proposal.
@@ -977,6 +1014,7 @@ pub mod pallet {
 		pub fn clear_public_proposals(origin: OriginFor<T>) -> DispatchResult {
 			ensure_root(origin)?;
 			<PublicProps<T>>::kill();
+			let _ = ProposalTracks::<T>::clear(u32::MAX, None);
 			Ok(())
 		}
 
@@ -1095,6 +1133,7 @@ pub mod pallet {
 				if let Some(index) = props.iter().position(|p| p.1.hash() == proposal_hash) {
 					let (prop_index, ..) = props.remove(index);
 					DepositOf::<T>::take(prop_index);
+					ProposalTracks::<T>::remove(prop_index);
 					Self::clear_metadata(MetadataOwner::Proposal(prop_index));
 				}
 			});
@@ -1135,6 +1174,7 @@ pub mod pallet {
 
 			PublicProps::<T>::mutate(|props| props.retain(|p| p.0 != prop_index));
 			DepositOf::<T>::take(prop_index);
+			ProposalTracks::<T>::remove(prop_index);
 			Self::deposit_event(Event::<T>::ProposalCanceled { prop_index });
 			Self::clear_metadata(MetadataOwner::Proposal(prop_index));
 			Ok(())
@@ -1242,13 +1282,24 @@ impl<T: Config> Pallet<T> {
 		Self::maturing_referenda_at_inner(n, next..last)
 	}
 
-	fn do_propose(who: T::AccountId, proposal: BoundedCallOf<T>, value: BalanceOf<T>, dispatch_origin: DispatchOrigin) -> DispatchResult {
+	fn do_propose(
+		who: T::AccountId,
+		track: TrackId,
+		proposal: BoundedCallOf<T>,
+		value: BalanceOf<T>,
+		dispatch_origin: DispatchOrigin,
+	) -> DispatchResult {
 		T::Citizenship::ensure_politics_allowed(&who)?;
+		let track_info = T::Tracks::info(track).ok_or(Error::<T>::BadTrack)?;
+		ensure!(
+			dispatch_origin != DispatchOrigin::Root || T::RootTracks::contains(&track),
+			Error::<T>::RootNotAllowed
+		);
 
 		T::ProposalFee::on_unbalanced(
 			T::Currency::withdraw(
 				&who,
-				T::ProposalFeeAmount::get(),
+				track_info.proposal_fee,
 				WithdrawReasons::FEE,
 				ExistenceRequirement::KeepAlive
 			)?
@@ -1277,6 +1328,7 @@ impl<T: Config> Pallet<T> {
 
 		PublicProps::<T>::try_append((index, proposal, who, dispatch_origin))
 			.map_err(|_| Error::<T>::TooMany)?;
+		ProposalTracks::<T>::insert(index, track);
 
 		Self::deposit_event(Event::<T>::Proposed { proposal_index: index, deposit: value });
 		Ok(())
@@ -1319,6 +1371,7 @@ impl<T: Config> Pallet<T> {
 	pub fn internal_cancel_referendum(ref_index: ReferendumIndex) {
 		Self::deposit_event(Event::<T>::Cancelled { ref_index });
 		ReferendumInfoOf::<T>::remove(ref_index);
+		Self::release_track(ref_index);
 		Self::clear_metadata(MetadataOwner::Referendum(ref_index));
 	}
 
@@ -1632,22 +1685,45 @@ impl<T: Config> Pallet<T> {
 	/// Table the waiting public proposal with the highest backing for a vote.
 	fn launch_public(now: BlockNumberFor<T>) -> DispatchResult {
 		let mut public_props = Self::public_props();
-		if let Some((winner_index, _)) = public_props.iter().enumerate().max_by_key(
-			// defensive only: All current public proposals have an amount locked
-			|x| Self::backing_for((x.1).0).defensive_unwrap_or_else(Zero::zero),
-		) {
+		if let Some((winner_index, _)) = public_props
+			.iter()
+			.enumerate()
+			// proposals on tracks that are full wait for a later launch
+			.filter(|x| {
+				Self::proposal_track((x.1).0).map_or(true, |(track, info)| {
+					DecidingCount::<T>::get(track) < info.max_deciding
+				})
+			})
+			.max_by_key(
+				// defensive only: All current public proposals have an amount locked
+				|x| Self::backing_for((x.1).0).defensive_unwrap_or_else(Zero::zero),
+			) {
 			let (prop_index, proposal, _, dispatch_origin) = public_props.swap_remove(winner_index);
 			<PublicProps<T>>::put(public_props);
+			let track = Self::proposal_track(prop_index);
+			ProposalTracks::<T>::remove(prop_index);
 
 			if let Some((_depositors, deposit)) = <DepositOf<T>>::take(prop_index) {
 				Self::deposit_event(Event::<T>::Tabled { proposal_index: prop_index, deposit });
+				let (voting_period, threshold, delay) = match track {
+					Some((_, info)) => (info.voting_period, info.threshold, info.enactment_period),
+					None => (
+						T::VotingPeriod::get(),
+						VoteThreshold::SuperMajorityApprove,
+						T::EnactmentPeriod::get(),
+					),
+				};
 				let ref_index = Self::inject_referendum(
-					now.saturating_add(T::VotingPeriod::get()),
+					now.saturating_add(voting_period),
 					proposal,
 					dispatch_origin,
-					VoteThreshold::SuperMajorityApprove,
-					T::EnactmentPeriod::get(),
+					threshold,
+					delay,
 				);
+				if let Some((track, _)) = track {
+					ReferendumTracks::<T>::insert(ref_index, track);
+					DecidingCount::<T>::mutate(track, |count| *count += 1);
+				}
 				Self::transfer_metadata(
 					MetadataOwner::Proposal(prop_index),
 					MetadataOwner::Referendum(ref_index),
@@ -1665,16 +1741,22 @@ impl<T: Config> Pallet<T> {
 		status: ReferendumStatus<BlockNumberFor<T>, BoundedCallOf<T>, BalanceOf<T>>,
 	) -> bool {
 		let politi_pooled = T::LLM::get_politi_pooled_amount();
+		let citizens = T::Citizenship::citizens_count();
+		let track = ReferendumTracks::<T>::get(index);
 		let approved = status
 			.threshold
-			.approved(status.tally.clone(), politi_pooled);
+			.approved(status.tally.clone(), politi_pooled) &&
+			Self::quorum(track, &status.dispatch_origin).approved(&status.tally, citizens);
 
 		if approved {
 			Self::deposit_event(Event::<T>::Passed { ref_index: index });
 
 			let origin = match &status.dispatch_origin {
 				DispatchOrigin::Root => frame_system::RawOrigin::Root.into(),
-				DispatchOrigin::Rich => RawOrigin::Referendum(status.tally, politi_pooled).into(),
+				DispatchOrigin::Rich => match track {
+					Some(track) => RawOrigin::Track(track, status.tally, politi_pooled).into(),
+					None => RawOrigin::Referendum(status.tally, politi_pooled).into(),
+				},
 			};
 
 			// Earliest it can be scheduled for is next block.
@@ -1698,6 +1780,33 @@ impl<T: Config> Pallet<T> {
 		approved
 	}
 
+	/// Track of a public proposal, if it has one that still exists.
+	fn proposal_track(
+		prop_index: PropIndex,
+	) -> Option<(TrackId, &'static TrackInfo<BalanceOf<T>, BlockNumberFor<T>>)> {
+		let track = ProposalTracks::<T>::get(prop_index)?;
+		T::Tracks::info(track).map(|info| (track, info))
+	}
+
+	/// Free the place a finished or cancelled referendum took on its track.
+	fn release_track(ref_index: ReferendumIndex) {
+		if let Some(track) = ReferendumTracks::<T>::take(ref_index) {
+			DecidingCount::<T>::mutate(track, |count| *count = count.saturating_sub(1));
+		}
+	}
+
+	/// Quorum a referendum must meet to pass: the one of its `track`, if it's on one, otherwise
+	/// the one for its `dispatch_origin`.
+	fn quorum(track: Option<TrackId>, dispatch_origin: &DispatchOrigin) -> Quorum {
+		match track.and_then(T::Tracks::info) {
+			Some(info) => info.quorum,
+			None => match dispatch_origin {
+				DispatchOrigin::Root => T::RootQuorum::get(),
+				DispatchOrigin::Rich => T::RichQuorum::get(),
+			},
+		}
+	}
+
 	/// Current era is ending; we should finish up any proposals.
 	///
 	///
@@ -1729,6 +1838,7 @@ impl<T: Config> Pallet<T> {
 		for (index, info) in Self::maturing_referenda_at_inner(now, next..last).into_iter() {
 			let approved = Self::bake_referendum(now, index, info);
 			ReferendumInfoOf::<T>::insert(index, ReferendumInfo::Finished { end: now, approved });
+			Self::release_track(index);
 			weight = max_block_weight;
 		}
 
@@ -1851,16 +1961,77 @@ impl<
 	}
 
 	fn try_origin(o: O) -> Result<Self::Success, O> {
-		let n: BalanceOf<T> = N.into();
-		let d: BalanceOf<T> = D.into();
-		let votes_passing = |t: &Tally<BalanceOf<T>>| {
-			t.ayes * d >= n * (t.ayes + t.nays)
-		};
-		let voters_passing = |t: &Tally<BalanceOf<T>>| {
-			t.aye_voters * D as u64 >= N as u64 * (t.aye_voters + t.nay_voters)
+		o.into().and_then(|o| match o {
+			RawOrigin::Referendum(t, _electorate) if proportion_at_least::<T>(&t, N, D) => Ok(()),
+			r => Err(O::from(r)),
+		})
+	}
+}
+
+/// Whether at least `n/d` of both votes and voters in `t` are in favour.
+fn proportion_at_least<T: Config>(t: &Tally<BalanceOf<T>>, n: u32, d: u32) -> bool {
+	let (n_balance, d_balance): (BalanceOf<T>, BalanceOf<T>) = (n.into(), d.into());
+	let votes_passing = t.ayes * d_balance >= n_balance * (t.ayes + t.nays);
+	let voters_passing = t.aye_voters * d as u64 >= n as u64 * (t.aye_voters + t.nay_voters);
+	votes_passing && voters_passing
+}
+
+/// Ensures the origin is a referendum that passed on the given track.
+pub struct EnsureTrack<T: Config, const TRACK: TrackId> {
+	_phantom: sp_std::marker::PhantomData<T>,
+}
+
+impl<T: Config, O: Into<Result<Origin<T>, O>> + From<Origin<T>>, const TRACK: TrackId>
+	EnsureOrigin<O> for EnsureTrack<T, TRACK>
+{
+	type Success = ();
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		let tally = Tally::<BalanceOf<T>> {
+			ayes: 1u8.into(),
+			nays: 0u8.into(),
+			aye_voters: 1,
+			nay_voters: 0,
+			turnout: 1u8.into(),
 		};
+		Ok(O::from(RawOrigin::Track(TRACK, tally, 1u8.into())))
+	}
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().and_then(|o| match o {
+			RawOrigin::Track(track, ..) if track == TRACK => Ok(()),
+			r => Err(O::from(r)),
+		})
+	}
+}
+
+/// Ensures the origin is a referendum that passed on the given track with at least `N/D` of
+/// both votes and voters in favour.
+pub struct EnsureTrackProportionAtLeast<T: Config, const TRACK: TrackId, const N: u32, const D: u32>
+{
+	_phantom: sp_std::marker::PhantomData<T>,
+}
+
+impl<
+		T: Config,
+		O: Into<Result<Origin<T>, O>> + From<Origin<T>>,
+		const TRACK: TrackId,
+		const N: u32,
+		const D: u32,
+	> EnsureOrigin<O> for EnsureTrackProportionAtLeast<T, TRACK, N, D>
+{
+	type Success = ();
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		<EnsureTrack<T, TRACK> as EnsureOrigin<O>>::try_successful_origin()
+	}
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
 		o.into().and_then(|o| match o {
-			RawOrigin::Referendum(t, _electorate) if votes_passing(&t) && voters_passing(&t) =>
+			RawOrigin::Track(track, t, _electorate)
+				if track == TRACK && proportion_at_least::<T>(&t, N, D) =>
 				Ok(()),
 			r => Err(O::from(r)),
 		})
@@ -132,6 +132,15 @@ impl pallet_assets::Config for Test {
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type Citizenship = ();
+	type Companies = frame_support::traits::Everything;
+	type MaxActiveDistributions = ConstU32<10>;
+	type MaxCheckpoints = ConstU32<100>;
+	type MaxOpenResolutions = ConstU32<10>;
+	type MaxVestingSchedules = ConstU32<3>;
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ConstU32<1>;
+	type MaxFreezes = ConstU32<1>;
 }
 
 // Test that a fitlered call can be dispatched.
@@ -246,6 +255,11 @@ impl pallet_nfts::Config for Test {
 	type Helper = ();
 	type Citizenship = ();
 	type MetadataValidator = ();
+	type TransferApprover = ();
+	type Shares = ();
+	type RentAssets = ();
+	type MaxMetadataIndexKeys = ConstU32<16>;
+	type MaxItemParts = ConstU32<4>;
 	type MaxAttributesPerCall = MaxAttributesPerCall;
 	type OffchainSignature = TestSignature;
 	type OffchainPublic = <TestSignature as sp_runtime::traits::Verify>::Signer;
@@ -308,6 +322,66 @@ parameter_types! {
 	pub const MaxRegistrars: u32 = 20;
 }
 
+parameter_types! {
+	pub const NoQuorum: Quorum = Quorum::none();
+}
+
+pub struct TestTracks;
+impl TracksInfo<u64, u64> for TestTracks {
+	fn tracks() -> &'static [(TrackId, TrackInfo<u64, u64>)] {
+		static TRACKS: [(TrackId, TrackInfo<u64, u64>); 3] = [
+			(
+				0,
+				TrackInfo {
+					name: "root",
+					voting_period: 2,
+					enactment_period: 2,
+					threshold: VoteThreshold::SuperMajorityApprove,
+					quorum: Quorum::none(),
+					max_deciding: 100,
+					proposal_fee: 10,
+				},
+			),
+			(
+				1,
+				TrackInfo {
+					name: "limited",
+					voting_period: 4,
+					enactment_period: 1,
+					threshold: VoteThreshold::SimpleMajority,
+					quorum: Quorum::none(),
+					max_deciding: 1,
+					proposal_fee: 20,
+				},
+			),
+			(
+				10,
+				TrackInfo {
+					name: "constitution",
+					voting_period: 2,
+					enactment_period: 2,
+					threshold: VoteThreshold::SimpleMajority,
+					quorum: Quorum {
+						llm_approval: Perbill::from_parts(666_666_667),
+						voter_approval: Perbill::from_parts(666_666_667),
+						participation: Perbill::from_percent(50),
+					},
+					max_deciding: 100,
+					proposal_fee: 10,
+				},
+			),
+		];
+		&TRACKS
+	}
+}
+
+pub struct TestRootTracks;
+impl Contains<TrackId> for TestRootTracks {
+	fn contains(track: &TrackId) -> bool {
+		*track == 0
+	}
+}
+
 type EnsureOneOrRoot = EitherOfDiverse<EnsureRoot<u64>, EnsureSignedBy<One, u64>>;
 type EnsureTwoOrRoot = EitherOfDiverse<EnsureRoot<u64>, EnsureSignedBy<Two, u64>>;
 impl pallet_identity::Config for Test {
@@ -362,7 +436,10 @@ impl Config for Test {
 	type LLInitializer = LiberlandInitializer;
 	type DelegateeFilter = Everything;
 	type ProposalFee = ();
-	type ProposalFeeAmount = ConstU64<10>;
+	type Tracks = TestTracks;
+	type RootTracks = TestRootTracks;
+	type RootQuorum = NoQuorum;
+	type RichQuorum = NoQuorum;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -414,7 +491,7 @@ fn set_balance_proposal_is_correctly_filtered_out() {
 }
 
 fn propose_set_balance(who: u64, value: u64, delay: u64) -> DispatchResult {
-	Democracy::propose(RuntimeOrigin::signed(who), set_balance_proposal(value), delay)
+	Democracy::propose(RuntimeOrigin::signed(who), 0, set_balance_proposal(value), delay)
 }
 
 fn next_block() {
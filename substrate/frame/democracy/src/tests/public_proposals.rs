@@ -189,3 +189,110 @@ fn all_referenda_should_start_at_the_same_time() {
 		assert_ok!(Democracy::vote(RuntimeOrigin::signed(1), 2, aye(1)));
 	});
 }
+
+#[test]
+fn proposing_on_unknown_track_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Democracy::propose(RuntimeOrigin::signed(1), 2, set_balance_proposal(2), 2),
+			Error::<Test>::BadTrack
+		);
+	});
+}
+
+#[test]
+fn root_proposals_are_limited_to_root_tracks() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Democracy::propose(RuntimeOrigin::signed(1), 1, set_balance_proposal(2), 2),
+			Error::<Test>::RootNotAllowed
+		);
+		assert_ok!(Democracy::propose_rich_origin(
+			RuntimeOrigin::signed(1),
+			1,
+			set_balance_proposal(2),
+			2
+		));
+		assert_ok!(Democracy::propose(RuntimeOrigin::signed(1), 0, set_balance_proposal(3), 2));
+	});
+}
+
+#[test]
+fn tracks_set_fee_and_referendum_parameters() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(0);
+		assert_ok!(Democracy::propose_rich_origin(
+			RuntimeOrigin::signed(1),
+			1,
+			set_balance_proposal(2),
+			2
+		));
+		assert_eq!(Balances::total_balance(&1), 80);
+
+		fast_forward_to(2);
+		let status = Democracy::referendum_status(0).unwrap();
+		assert_eq!(status.end, 6);
+		assert_eq!(status.delay, 1);
+		assert_eq!(status.threshold, VoteThreshold::SimpleMajority);
+		assert_eq!(ReferendumTracks::<Test>::get(0), Some(1));
+		assert_eq!(DecidingCount::<Test>::get(1), 1);
+	});
+}
+
+#[test]
+fn tracks_limit_concurrent_referenda() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(0);
+		assert_ok!(Democracy::propose_rich_origin(
+			RuntimeOrigin::signed(1),
+			1,
+			set_balance_proposal(2),
+			2
+		));
+		assert_ok!(Democracy::propose_rich_origin(
+			RuntimeOrigin::signed(2),
+			1,
+			set_balance_proposal(3),
+			3
+		));
+		assert_ok!(propose_set_balance(3, 4, 1));
+
+		fast_forward_to(2);
+		// the best backed proposal on the full track has to wait
+		assert_eq!(Democracy::referendum_count(), 2);
+		assert_eq!(Democracy::referendum_status(0).unwrap().proposal, set_balance_proposal(3));
+		assert_eq!(Democracy::backing_for(0), Some(2));
+
+		fast_forward_to(4);
+		assert_eq!(Democracy::referendum_count(), 2);
+
+		// the track is freed once the referendum is baked
+		fast_forward_to(6);
+		assert_eq!(DecidingCount::<Test>::get(1), 0);
+		assert_eq!(Democracy::referendum_count(), 2);
+
+		fast_forward_to(8);
+		assert_eq!(DecidingCount::<Test>::get(1), 1);
+		assert_eq!(Democracy::referendum_count(), 3);
+		assert_eq!(Democracy::referendum_status(2).unwrap().proposal, set_balance_proposal(2));
+	});
+}
+
+#[test]
+fn cancelling_referendum_frees_track() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(0);
+		assert_ok!(Democracy::propose_rich_origin(
+			RuntimeOrigin::signed(1),
+			1,
+			set_balance_proposal(2),
+			2
+		));
+		fast_forward_to(2);
+		assert_eq!(DecidingCount::<Test>::get(1), 1);
+
+		assert_ok!(Democracy::cancel_referendum(RuntimeOrigin::root(), 0));
+		assert_eq!(DecidingCount::<Test>::get(1), 0);
+		assert_eq!(ReferendumTracks::<Test>::get(0), None);
+	});
+}
@@ -171,3 +171,40 @@ fn lowest_unbaked_should_be_sensible() {
 		assert_eq!(Balances::free_balance(42), 1);
 	});
 }
+
+#[test]
+fn track_quorum_needs_llm_and_headcount() {
+	new_test_ext().execute_with(|| {
+		let votes = [
+			// too few citizens voted
+			vec![(1, true), (2, true), (3, true)],
+			// less than 2/3 of LLM votes are ayes
+			vec![(1, true), (2, true), (3, true), (4, true), (6, false)],
+			// less than 2/3 of voters vote aye
+			vec![(1, false), (2, false), (5, true), (6, true)],
+			// passes
+			vec![(1, false), (2, true), (3, true), (4, true), (5, true)],
+		];
+		for votes in votes {
+			let r = Democracy::inject_referendum(
+				2,
+				set_balance_proposal(2),
+				DispatchOrigin::Rich,
+				VoteThreshold::SimpleMajority,
+				0,
+			);
+			ReferendumTracks::<Test>::insert(r, 10);
+			for (who, approve) in votes {
+				let vote = if approve { aye(who) } else { nay(who) };
+				assert_ok!(Democracy::vote(RuntimeOrigin::signed(who), r, vote));
+			}
+		}
+
+		next_block();
+
+		for r in 0..3 {
+			System::assert_has_event(crate::Event::NotPassed { ref_index: r }.into());
+		}
+		System::assert_has_event(crate::Event::Passed { ref_index: 3 }.into());
+	});
+}
@@ -21,7 +21,7 @@
 
 //! Miscellaneous additional datatypes.
 
-use crate::{AccountVote, Conviction, Vote, VoteThreshold};
+use crate::{AccountVote, Conviction, Quorum, Vote, VoteThreshold};
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{
@@ -35,10 +35,43 @@ pub type PropIndex = u32;
 /// A referendum index.
 pub type ReferendumIndex = u32;
 
+/// A referendum track identifier.
+pub type TrackId = u16;
+
+/// Voting parameters of a referendum track.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct TrackInfo<Balance, BlockNumber> {
+	/// Name of the track.
+	pub name: &'static str,
+	/// How long referenda on this track are voted on.
+	pub voting_period: BlockNumber,
+	/// The delay (in blocks) to wait after a successful referendum before deploying.
+	pub enactment_period: BlockNumber,
+	/// The thresholding mechanism to determine whether a referendum passed.
+	pub threshold: VoteThreshold,
+	/// Quorum referenda on this track must meet on top of `threshold`.
+	pub quorum: Quorum,
+	/// Maximum number of referenda that can be ongoing on this track at once.
+	pub max_deciding: u32,
+	/// Fee for proposing on this track.
+	pub proposal_fee: Balance,
+}
+
+/// Information on the referendum tracks.
+pub trait TracksInfo<Balance, BlockNumber> {
+	/// All tracks, with their ids.
+	fn tracks() -> &'static [(TrackId, TrackInfo<Balance, BlockNumber>)];
+
+	/// Info of the track `id`, if it exists.
+	fn info(id: TrackId) -> Option<&'static TrackInfo<Balance, BlockNumber>> {
+		Self::tracks().iter().find(|(track, _)| *track == id).map(|(_, info)| info)
+	}
+}
+
 #[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
 pub enum DispatchOrigin {
 	Root, // Dispatches as pallet_system::RawOrigin::Root
-	Rich, // Dispatches as crate::RawOrigin::Referendum(tally, electorate)
+	Rich, // Dispatches as crate::RawOrigin::Track(track, tally, electorate)
 }
 
 /// Info regarding an ongoing referendum.
@@ -26,7 +26,10 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sp_runtime::traits::{IntegerSquareRoot, Zero};
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, IntegerSquareRoot, Saturating, Zero},
+	PerThing, Perbill,
+};
 use sp_std::ops::{Add, Div, Mul, Rem};
 
 /// A means of determining if a vote is past pass threshold.
@@ -43,6 +46,62 @@ pub enum VoteThreshold {
 	SimpleMajority,
 }
 
+/// Requirements a referendum must meet on top of its `VoteThreshold`, counting both LLM and
+/// citizens.
+#[derive(
+	Clone,
+	Copy,
+	Default,
+	PartialEq,
+	Eq,
+	Encode,
+	MaxEncodedLen,
+	Decode,
+	sp_runtime::RuntimeDebug,
+	TypeInfo,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Quorum {
+	/// Minimum share of LLM votes that must be ayes.
+	pub llm_approval: Perbill,
+	/// Minimum share of voters that must vote aye.
+	pub voter_approval: Perbill,
+	/// Minimum share of all citizens that must vote.
+	pub participation: Perbill,
+}
+
+impl Quorum {
+	/// Quorum that adds no requirements.
+	pub const fn none() -> Self {
+		Self {
+			llm_approval: Perbill::from_parts(0),
+			voter_approval: Perbill::from_parts(0),
+			participation: Perbill::from_parts(0),
+		}
+	}
+
+	/// Returns `true` if `tally` meets all the requirements, given `citizens` citizens in total.
+	pub fn approved<Balance: AtLeast32BitUnsigned + Copy>(
+		&self,
+		tally: &Tally<Balance>,
+		citizens: u64,
+	) -> bool {
+		// voters are stored x10000 in the tally
+		let votes = tally.ayes.saturating_add(tally.nays);
+		let voters = tally.aye_voters.saturating_add(tally.nay_voters);
+		at_least(tally.ayes, votes, self.llm_approval) &&
+			at_least(tally.aye_voters, voters, self.voter_approval) &&
+			at_least(voters, citizens.saturating_mul(10000), self.participation)
+	}
+}
+
+/// Return `true` iff `n / d >= share`, without rounding either side. Always `true` if `d` is
+/// zero.
+fn at_least<T: AtLeast32BitUnsigned + Copy>(n: T, d: T, share: Perbill) -> bool {
+	d.is_zero() ||
+		!compare_rationals(n, d, T::from(share.deconstruct()), T::from(Perbill::ACCURACY))
+}
+
 pub trait Approved<Balance> {
 	/// Given a `tally` of votes and a total size of `electorate`, this returns `true` if the
 	/// overall outcome is in favor of approval according to `self`'s threshold method.
@@ -122,4 +181,41 @@ mod tests {
 		assert!(VoteThreshold::SuperMajorityApprove
 			.approved(Tally { ayes: 100, aye_voters: 10000, nay_voters: 10000, nays: 50, turnout: 150 }, 210));
 	}
+
+	#[test]
+	fn quorum_should_work() {
+		let quorum = Quorum {
+			llm_approval: Perbill::from_percent(60),
+			voter_approval: Perbill::from_percent(50),
+			participation: Perbill::from_percent(40),
+		};
+		let tally =
+			Tally { ayes: 180, aye_voters: 10000, nay_voters: 10000, nays: 120, turnout: 300 };
+		assert!(quorum.approved(&tally, 5));
+		assert!(!quorum.approved(&tally, 6));
+		assert!(!quorum.approved(&Tally { ayes: 179, nays: 121, ..tally.clone() }, 5));
+		assert!(
+			!quorum.approved(&Tally { aye_voters: 9999, nay_voters: 10001, ..tally.clone() }, 5)
+		);
+		assert!(Quorum::none().approved(&Tally::<u64>::default(), 5));
+	}
+
+	#[test]
+	fn quorum_compares_exact_shares() {
+		let quorum =
+			Quorum { llm_approval: Perbill::from_parts(666_666_667), ..Quorum::none() };
+		let tally = |ayes: u128, nays: u128| Tally {
+			ayes,
+			nays,
+			aye_voters: 0,
+			nay_voters: 0,
+			turnout: ayes + nays,
+		};
+		assert!(!quorum.approved(&tally(2, 1), 0));
+		assert!(quorum.approved(&tally(666_666_667, 333_333_333), 0));
+		assert!(!quorum.approved(&tally(666_666_666, 333_333_334), 0));
+		// doesn't overflow with large balances
+		assert!(quorum.approved(&tally(u128::MAX / 2, u128::MAX / 5), 0));
+		assert!(!quorum.approved(&tally(u128::MAX / 2, u128::MAX / 4), 0));
+	}
 }
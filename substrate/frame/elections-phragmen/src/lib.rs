@@ -1379,6 +1379,11 @@ mod tests {
 		type Helper = ();
 		type Citizenship = ();
 		type MetadataValidator = ();
+		type TransferApprover = ();
+		type Shares = ();
+		type RentAssets = ();
+		type MaxMetadataIndexKeys = ConstU32<16>;
+		type MaxItemParts = ConstU32<4>;
 		type MaxAttributesPerCall = MaxAttributesPerCall;
 		type OffchainSignature = TestSignature;
 		type OffchainPublic = <TestSignature as sp_runtime::traits::Verify>::Signer;
@@ -1406,6 +1411,15 @@ mod tests {
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper = ();
 		type Citizenship = ();
+		type Companies = frame_support::traits::Everything;
+		type MaxActiveDistributions = ConstU32<10>;
+		type MaxCheckpoints = ConstU32<100>;
+		type MaxOpenResolutions = ConstU32<10>;
+		type MaxVestingSchedules = ConstU32<3>;
+		type RuntimeHoldReason = ();
+		type FreezeIdentifier = ();
+		type MaxHolds = ConstU32<1>;
+		type MaxFreezes = ConstU32<1>;
 	}
 
 	parameter_types! {
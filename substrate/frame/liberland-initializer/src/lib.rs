@@ -137,6 +137,12 @@ pub mod pallet {
 					true,
 				)
 				.unwrap();
+				pallet_nfts::Pallet::<T>::set_transfer_approval_required(
+					frame_system::RawOrigin::Root.into(),
+					idx,
+					true,
+				)
+				.unwrap();
 			}
 
 			if let Some(metaverse_land_registrar) = &self.metaverse_land_registrar {
@@ -78,6 +78,9 @@ pub mod pallet {
 		type ConstitutionOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		type InternationalTreatyOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		type LowTierDeleteOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin allowed to add, amend and repeal legislation of tiers below
+		/// _InternationalTreaty_. Succeeds with the most important tier it may change.
+		type LowTierOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = LegislationTier>;
 		type WeightInfo: WeightInfo;
 	}
 
@@ -206,7 +209,7 @@ pub mod pallet {
 		/// The dispatch origin of this call must be:
 		/// * _ConstitutionOrigin_ if _tier_ is _Constitution_,
 		/// * _InternationalTreatyOrigin_ if _tier_ is _InternationalTreaty_,
-		/// * _Root_ otherwise.
+		/// * _LowTierOrigin_ allowing _tier_ otherwise.
 		///
 		/// - `tier`: Tier of the legislation.
 		/// - `id`: Id of the legislation.
@@ -238,7 +241,7 @@ pub mod pallet {
 					T::InternationalTreatyOrigin::ensure_origin(origin)?;
 				},
 				_ => {
-					ensure_root(origin)?;
+					Self::ensure_low_tier_origin(origin, tier)?;
 				},
 			}
 
@@ -265,8 +268,8 @@ pub mod pallet {
 		/// The dispatch origin of this call must be:
 		/// * _ConstitutionOrigin_ if _tier_ is _Constitution_,
 		/// * _InternationalTreatyOrigin_ if _tier_ is _InternationalTreaty_,
-		/// * _Root_ if _tier_ is _Law_.
-		/// * _LowTierDeleteOrigin_ otherwise.
+		/// * _LowTierOrigin_ allowing _tier_ if _tier_ is _Law_.
+		/// * _LowTierDeleteOrigin_ or _LowTierOrigin_ allowing _tier_ otherwise.
 		///
 		/// - `tier`: Tier of the legislation.
 		/// - `id`: Id of the legislation.
@@ -303,10 +306,12 @@ pub mod pallet {
 					T::InternationalTreatyOrigin::ensure_origin(origin)?;
 				},
 				Law => {
-					ensure_root(origin)?;
+					Self::ensure_low_tier_origin(origin, tier)?;
 				},
 				_ => {
-					T::LowTierDeleteOrigin::ensure_origin(origin)?;
+					T::LowTierDeleteOrigin::try_origin(origin)
+						.map(|_| ())
+						.or_else(|origin| Self::ensure_low_tier_origin(origin, tier))?;
 				},
 			}
 
@@ -328,8 +333,8 @@ pub mod pallet {
 		/// The dispatch origin of this call must be:
 		/// * _ConstitutionOrigin_ if _tier_ is _Constitution_,
 		/// * _InternationalTreatyOrigin_ if _tier_ is _InternationalTreaty_,
-		/// * _Root_ if _tier_ is _Law_.
-		/// * _LowTierDeleteOrigin_ otherwise.
+		/// * _LowTierOrigin_ allowing _tier_ if _tier_ is _Law_.
+		/// * _LowTierDeleteOrigin_ or _LowTierOrigin_ allowing _tier_ otherwise.
 		///
 		/// - `tier`: Tier of the legislation.
 		/// - `id`: Id of the legislation.
@@ -366,10 +371,12 @@ pub mod pallet {
 					T::InternationalTreatyOrigin::ensure_origin(origin)?;
 				},
 				Law => {
-					ensure_root(origin)?;
+					Self::ensure_low_tier_origin(origin, tier)?;
 				},
 				_ => {
-					T::LowTierDeleteOrigin::ensure_origin(origin)?;
+					T::LowTierDeleteOrigin::try_origin(origin)
+						.map(|_| ())
+						.or_else(|origin| Self::ensure_low_tier_origin(origin, tier))?;
 				},
 			}
 
@@ -508,7 +515,7 @@ pub mod pallet {
 		/// The dispatch origin of this call must be:
 		/// * _ConstitutionOrigin_ if _tier_ is _Constitution_,
 		/// * _InternationalTreatyOrigin_ if _tier_ is _InternationalTreaty_,
-		/// * _Root_ otherwise.
+		/// * _LowTierOrigin_ allowing _tier_ otherwise.
 		///
 		/// - `tier`: Tier of the legislation.
 		/// - `id`: Id of the legislation.
@@ -549,7 +556,7 @@ pub mod pallet {
 					T::InternationalTreatyOrigin::ensure_origin(origin)?;
 				},
 				_ => {
-					ensure_root(origin)?;
+					Self::ensure_low_tier_origin(origin, tier)?;
 				},
 			}
 
@@ -566,6 +573,12 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		fn ensure_low_tier_origin(origin: OriginFor<T>, tier: LegislationTier) -> DispatchResult {
+			let allowed = T::LowTierOrigin::ensure_origin(origin)?;
+			ensure!(tier >= allowed, sp_runtime::traits::BadOrigin);
+			Ok(())
+		}
+
 		fn do_headcount_veto(
 			tier: LegislationTier,
 			id: LegislationId,
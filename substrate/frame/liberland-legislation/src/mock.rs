@@ -1,12 +1,13 @@
 #![cfg(test)]
 use crate as pallet_liberland_legislation;
+use crate::types::LegislationTier;
 use frame_support::{
 	ord_parameter_types,
 	pallet_prelude::Weight,
 	parameter_types,
 	traits::{
-		AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, EitherOfDiverse, EqualPrivilegeOnly,
-		Everything,
+		AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, EitherOf, EitherOfDiverse,
+		EqualPrivilegeOnly, Everything, MapSuccess,
 	},
 	PalletId,
 };
@@ -17,7 +18,7 @@ use pallet_balances::AccountData;
 use sp_core::H256;
 use sp_runtime::{
 	testing::TestSignature,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, IdentityLookup, Replace},
 	BuildStorage, Perbill, Permill,
 };
 
@@ -89,6 +90,30 @@ impl pallet_scheduler::Config for Test {
 	type Preimages = ();
 }
 
+parameter_types! {
+	pub const NoQuorum: pallet_democracy::Quorum = pallet_democracy::Quorum::none();
+}
+
+pub struct TestTracks;
+impl pallet_democracy::TracksInfo<u64, u64> for TestTracks {
+	fn tracks() -> &'static [(pallet_democracy::TrackId, pallet_democracy::TrackInfo<u64, u64>)] {
+		static TRACKS: [(pallet_democracy::TrackId, pallet_democracy::TrackInfo<u64, u64>); 1] =
+			[(
+				5,
+				pallet_democracy::TrackInfo {
+					name: "tier5",
+					voting_period: 2,
+					enactment_period: 2,
+					threshold: pallet_democracy::VoteThreshold::SuperMajorityApprove,
+					quorum: pallet_democracy::Quorum::none(),
+					max_deciding: 100,
+					proposal_fee: 0,
+				},
+			)];
+		&TRACKS
+	}
+}
+
 impl pallet_democracy::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = pallet_balances::Pallet<Self>;
@@ -124,8 +149,11 @@ impl pallet_democracy::Config for Test {
 	type LLInitializer = LiberlandInitializer;
 	type DelegateeFilter = Everything;
 	type SubmitOrigin = EnsureSigned<Self::AccountId>;
-	type ProposalFeeAmount = ConstU64<0>;
+	type Tracks = TestTracks;
+	type RootTracks = Everything;
 	type ProposalFee = ();
+	type RootQuorum = NoQuorum;
+	type RichQuorum = NoQuorum;
 }
 
 impl pallet_balances::Config for Test {
@@ -175,6 +203,11 @@ impl pallet_nfts::Config for Test {
 	type Helper = ();
 	type Citizenship = ();
 	type MetadataValidator = ();
+	type TransferApprover = ();
+	type Shares = ();
+	type RentAssets = ();
+	type MaxMetadataIndexKeys = ConstU32<16>;
+	type MaxItemParts = ConstU32<4>;
 
 	type MaxAttributesPerCall = MaxAttributesPerCall;
 	type OffchainSignature = TestSignature;
@@ -203,6 +236,15 @@ impl pallet_assets::Config for Test {
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type Citizenship = ();
+	type Companies = frame_support::traits::Everything;
+	type MaxActiveDistributions = ConstU32<10>;
+	type MaxCheckpoints = ConstU32<100>;
+	type MaxOpenResolutions = ConstU32<10>;
+	type MaxVestingSchedules = ConstU32<3>;
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ConstU32<1>;
+	type MaxFreezes = ConstU32<1>;
 }
 
 parameter_types! {
@@ -264,6 +306,11 @@ impl pallet_llm::Config for Test {
 	type MaxCourts = ConstU32<1>;
 }
 
+parameter_types! {
+	pub const LawTier: LegislationTier = LegislationTier::Law;
+	pub const Tier5Tier: LegislationTier = LegislationTier::Tier5;
+}
+
 impl pallet_liberland_legislation::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Citizenship = LLM;
@@ -271,6 +318,10 @@ impl pallet_liberland_legislation::Config for Test {
 	type ConstitutionOrigin = pallet_democracy::EnsureReferendumProportionAtLeast<Self, 3, 4>;
 	type InternationalTreatyOrigin = EnsureSignedBy<One, u64>;
 	type LowTierDeleteOrigin = EnsureRoot<u64>;
+	type LowTierOrigin = EitherOf<
+		MapSuccess<EnsureRoot<u64>, Replace<LawTier>>,
+		MapSuccess<pallet_democracy::EnsureTrack<Self, 5>, Replace<Tier5Tier>>,
+	>;
 	type WeightInfo = ();
 }
 
@@ -147,6 +147,53 @@ fn add_legislation_requires_root() {
 	});
 }
 
+#[test]
+fn track_origin_can_only_change_its_tiers() {
+	new_test_ext().execute_with(|| {
+		let track_origin = |track| -> RuntimeOrigin {
+			pallet_democracy::Origin::<Test>::Track(
+				track,
+				Tally { ayes: 1, nays: 0, aye_voters: 1, nay_voters: 0, turnout: 1 },
+				1000,
+			)
+			.try_into()
+			.unwrap()
+		};
+
+		assert_noop!(
+			LiberlandLegislation::add_legislation(
+				track_origin(4),
+				Tier5,
+				ZERO_ID,
+				sample_legislation(),
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			LiberlandLegislation::add_legislation(
+				track_origin(5),
+				Law,
+				ZERO_ID,
+				sample_legislation()
+			),
+			BadOrigin
+		);
+		assert_ok!(LiberlandLegislation::add_legislation(
+			track_origin(5),
+			Tier5,
+			ZERO_ID,
+			sample_legislation(),
+		));
+		assert_ok!(LiberlandLegislation::add_legislation(
+			track_origin(5),
+			Decision,
+			ZERO_ID,
+			sample_legislation(),
+		));
+		assert_ok!(LiberlandLegislation::repeal_legislation(track_origin(5), Tier5, ZERO_ID, 0));
+	});
+}
+
 #[test]
 fn add_legislation_tier_must_be_valid() {
 	new_test_ext().execute_with(|| {
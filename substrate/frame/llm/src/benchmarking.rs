@@ -14,7 +14,7 @@ use crate::{LLMPolitics, Pallet as LLM};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_system::RawOrigin;
 use sp_core::Get;
-use sp_runtime::{BoundedVec, Saturating};
+use sp_runtime::{BoundedVec, FixedPointNumber, FixedU128, Saturating};
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
@@ -136,6 +136,13 @@ benchmarks! {
 	verify {
 		assert_eq!(Courts::<T>::get(), courts);
 	}
+
+	set_fee_rate {
+		let rate = Some(FixedU128::from_rational(1, 10));
+	}: _(RawOrigin::Root, rate)
+	verify {
+		assert_eq!(FeeRate::<T>::get(), rate);
+	}
 }
 
 impl_benchmark_test_suite!(LLM, crate::mock::new_test_ext(), crate::mock::Test,);
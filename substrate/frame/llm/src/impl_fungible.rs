@@ -2,8 +2,10 @@ use super::*;
 use frame_support::{
 	pallet_prelude::*,
 	traits::tokens::{
-		fungible::{Dust, Inspect, Mutate, Unbalanced},
+		fungible::{freeze, hold, Dust, Inspect, Mutate, Unbalanced},
 		fungibles::{
+			freeze::{Inspect as FungiblesInspectFreeze, Mutate as FungiblesMutateFreeze},
+			hold::{Inspect as FungiblesInspectHold, Unbalanced as FungiblesUnbalancedHold},
 			Dust as FungiblesDust, Inspect as FungiblesInspect, Unbalanced as FungiblesUnbalanced,
 		},
 		DepositConsequence, Fortitude, Precision, Preservation, Provenance, WithdrawConsequence,
@@ -102,3 +104,66 @@ impl<T: Config> Unbalanced<T::AccountId> for Pallet<T> {
 }
 
 impl<T: Config> Mutate<T::AccountId> for Pallet<T> {}
+
+impl<T: Config> hold::Inspect<T::AccountId> for Pallet<T> {
+	type Reason = <T as pallet_assets::Config>::RuntimeHoldReason;
+
+	fn total_balance_on_hold(who: &T::AccountId) -> Self::Balance {
+		let id = Self::llm_id();
+		Assets::<T>::total_balance_on_hold(id, who)
+	}
+
+	fn balance_on_hold(reason: &Self::Reason, who: &T::AccountId) -> Self::Balance {
+		let id = Self::llm_id();
+		Assets::<T>::balance_on_hold(id, reason, who)
+	}
+
+	fn hold_available(reason: &Self::Reason, who: &T::AccountId) -> bool {
+		let id = Self::llm_id();
+		Assets::<T>::hold_available(id, reason, who)
+	}
+}
+
+impl<T: Config> hold::Unbalanced<T::AccountId> for Pallet<T> {
+	fn set_balance_on_hold(
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		let id = Self::llm_id();
+		Assets::<T>::set_balance_on_hold(id, reason, who, amount)
+	}
+}
+
+impl<T: Config> hold::Mutate<T::AccountId> for Pallet<T> {}
+
+impl<T: Config> freeze::Inspect<T::AccountId> for Pallet<T> {
+	type Id = <T as pallet_assets::Config>::FreezeIdentifier;
+
+	fn balance_frozen(id: &Self::Id, who: &T::AccountId) -> Self::Balance {
+		let asset_id = Self::llm_id();
+		Assets::<T>::balance_frozen(asset_id, id, who)
+	}
+
+	fn can_freeze(id: &Self::Id, who: &T::AccountId) -> bool {
+		let asset_id = Self::llm_id();
+		Assets::<T>::can_freeze(asset_id, id, who)
+	}
+}
+
+impl<T: Config> freeze::Mutate<T::AccountId> for Pallet<T> {
+	fn set_freeze(id: &Self::Id, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		let asset_id = Self::llm_id();
+		Assets::<T>::set_freeze(asset_id, id, who, amount)
+	}
+
+	fn extend_freeze(id: &Self::Id, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		let asset_id = Self::llm_id();
+		Assets::<T>::extend_freeze(asset_id, id, who, amount)
+	}
+
+	fn thaw(id: &Self::Id, who: &T::AccountId) -> DispatchResult {
+		let asset_id = Self::llm_id();
+		<Assets<T> as FungiblesMutateFreeze<T::AccountId>>::thaw(asset_id, id, who)
+	}
+}
@@ -55,7 +55,7 @@ pub mod pallet {
 	use scale_info::prelude::vec;
 	use sp_runtime::{
 		traits::{AccountIdConversion, StaticLookup},
-		AccountId32, Perbill, Permill,
+		AccountId32, FixedPointNumber, FixedU128, Perbill, Permill,
 	};
 	use sp_std::vec::Vec;
 
@@ -112,6 +112,12 @@ pub mod pallet {
 	pub(super) type Courts<T: Config> =
 		StorageValue<_, BoundedVec<T::AccountId, T::MaxCourts>, ValueQuery>;
 
+	/// Amount of LLM charged per unit of LLD when transaction fees are paid in LLM. If not set,
+	/// the price is quoted from the LLM/LLD pool.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_rate)]
+	pub(super) type FeeRate<T: Config> = StorageValue<_, FixedU128, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		/// duration, in blocks, for which additional unlocks should be locked
@@ -146,10 +152,7 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config:
-		pallet_assets::Config
-		+ frame_system::Config
-		+ pallet_identity::Config
-		+ pallet_asset_conversion::Config
+		pallet_assets::Config + frame_system::Config + pallet_identity::Config
 	{
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -453,6 +456,76 @@ pub mod pallet {
 			Courts::<T>::set(courts);
 			Ok(())
 		}
+
+		/// Set the rate at which transaction fees are charged when paid in LLM. Can only be
+		/// called by Root.
+		///
+		/// - `rate`: Amount of LLM per unit of LLD. `None` prices fees through the LLM/LLD pool.
+		///
+		/// Emits: `FeeRateSet`
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_fee_rate())]
+		pub fn set_fee_rate(origin: OriginFor<T>, rate: Option<FixedU128>) -> DispatchResult {
+			ensure_root(origin)?;
+			FeeRate::<T>::set(rate);
+			Self::deposit_event(Event::<T>::FeeRateSet(rate));
+			Ok(())
+		}
+
+		/// Transfer LLM from treasury to specified account, locked with a vesting schedule. Can
+		/// only be called by Senate.
+		///
+		/// - `to_account`: Account to transfer to.
+		/// - `amount`: Amount to transfer.
+		/// - `start`: Block at which the vesting starts.
+		/// - `curve`: How the funds unlock after the start.
+		/// - `revocable`: Whether Senate can take back funds that didn't vest yet.
+		///
+		/// Emits: `Transferred` and `VestingScheduleAdded` from `pallet-assets`
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::treasury_llm_transfer())]
+		pub fn treasury_llm_vested_transfer(
+			origin: OriginFor<T>,
+			to_account: T::AccountId,
+			amount: BalanceOfAssets<T>,
+			start: BlockNumberFor<T>,
+			curve: pallet_assets::VestingCurve<BlockNumberFor<T>>,
+			revocable: bool,
+		) -> DispatchResult {
+			T::SenateOrigin::ensure_origin(origin)?;
+			Assets::<T>::do_vested_transfer(
+				Self::llm_id(),
+				Self::get_llm_treasury_account(),
+				to_account,
+				amount,
+				start,
+				curve,
+				revocable,
+			)
+		}
+
+		/// Return LLM of a revocable vesting schedule that didn't vest yet to treasury. Can only
+		/// be called by Senate.
+		///
+		/// - `account`: Account with the vesting schedule.
+		/// - `schedule_index`: Index of the schedule to revoke.
+		///
+		/// Emits: `VestingRevoked` from `pallet-assets`
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::treasury_llm_transfer())]
+		pub fn treasury_llm_revoke_vesting(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			schedule_index: u32,
+		) -> DispatchResult {
+			T::SenateOrigin::ensure_origin(origin)?;
+			Assets::<T>::do_revoke_vesting(
+				Self::llm_id(),
+				Self::get_llm_treasury_account(),
+				account,
+				schedule_index,
+			)
+		}
 	}
 
 	#[pallet::event]
@@ -468,6 +541,8 @@ pub mod pallet {
 		LLMPoliticsUnlocked(T::AccountId, BalanceOfAssets<T>),
 		/// Remark
 		Remarked(RemarkData),
+		/// Rate for transaction fees paid in LLM was changed
+		FeeRateSet(Option<FixedU128>),
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -488,6 +563,11 @@ pub mod pallet {
 			Assets::<T>::balance(Self::llm_id().into(), account)
 		}
 
+		/// Check if given account is one of the Courts allowed to `force_transfer`.
+		pub fn is_court(account: &T::AccountId) -> bool {
+			Courts::<T>::get().contains(account)
+		}
+
 		fn get_unlock_amount(balance: BalanceOfAssets<T>) -> Result<BalanceOfAssets<T>, Error<T>> {
 			let factor = T::UnlockFactor::get();
 			let amount = factor.mul_floor(balance);
@@ -583,6 +663,22 @@ pub mod pallet {
 			Self::release_tokens_from_vault(prereleased)
 		}
 
+		/// Amount of LLM corresponding to `fee` LLD at the rate set by `set_fee_rate`, if any.
+		pub fn fee_in_llm(fee: u128) -> Option<BalanceOfAssets<T>> {
+			let rate = FeeRate::<T>::get()?;
+			rate.checked_mul_int(fee)?.try_into().ok()
+		}
+
+		/// Charge a transaction fee of `amount` liquid LLM from `who` to **Treasury**.
+		pub fn charge_fee(who: &T::AccountId, amount: BalanceOfAssets<T>) -> DispatchResult {
+			Self::transfer(who.clone(), Self::get_llm_treasury_account(), amount)
+		}
+
+		/// Return `amount` LLM of an overestimated transaction fee from **Treasury** to `who`.
+		pub fn refund_fee(who: &T::AccountId, amount: BalanceOfAssets<T>) -> DispatchResult {
+			Self::transfer_from_treasury(who.clone(), amount)
+		}
+
 		/// Asset ID of the LLM asset for `pallet-assets`
 		pub fn llm_id() -> <T as pallet_assets::Config>::AssetId {
 			<T as Config>::AssetId::get()
@@ -733,21 +829,10 @@ pub mod pallet {
 		) -> bool {
 			reg.judgements.contains(&(0u32, KnownGood))
 		}
-
-		fn is_dex_pool(account: &T::AccountId) -> bool {
-			pallet_asset_conversion::Pools::<T>::iter_keys()
-				.map(|id| pallet_asset_conversion::Pallet::<T>::get_pool_account(&id))
-				.find(|addr| addr == account)
-				.is_some()
-		}
 	}
 
 	impl<T: Config> CitizenshipChecker<T::AccountId> for Pallet<T> {
 		fn ensure_stocks_allowed(account: &T::AccountId) -> Result<(), DispatchError> {
-			if Self::is_dex_pool(account) {
-				return Ok(());
-			}
-
 			let identity =
 				&pallet_identity::Pallet::<T>::identity(account).ok_or(Error::<T>::NonEResident)?;
 			let is_citizen_or_eresident =
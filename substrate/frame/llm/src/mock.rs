@@ -52,6 +52,15 @@ impl pallet_assets::Config for Test {
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type Citizenship = ();
+	type Companies = frame_support::traits::Everything;
+	type MaxActiveDistributions = ConstU32<10>;
+	type MaxCheckpoints = ConstU32<100>;
+	type MaxOpenResolutions = ConstU32<10>;
+	type MaxVestingSchedules = ConstU32<3>;
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ConstU32<1>;
+	type MaxFreezes = ConstU32<1>;
 }
 
 parameter_types! {
@@ -10,15 +10,22 @@ use frame_support::{
 	error::BadOrigin,
 	traits::{
 		tokens::{
-			fungible::{Inspect, Mutate},
-			Preservation,
+			fungible::{
+				freeze::{Inspect as FreezeInspect, Mutate as FreezeMutate},
+				hold::{Inspect as HoldInspect, Mutate as HoldMutate},
+				Inspect, Mutate,
+			},
+			Fortitude, Precision, Preservation,
 		},
 		OnInitialize,
 	},
 };
 use liberland_traits::{CitizenshipChecker, LLM as LLMTrait};
 use pallet_identity::{Data, IdentityInfo};
-use sp_runtime::traits::{BlakeTwo256, Hash};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	FixedPointNumber, FixedU128,
+};
 
 type AssetsError<T> = pallet_assets::Error<T>;
 
@@ -296,6 +303,60 @@ fn treasury_llm_transfer_to_politipool_locks_funds() {
 	});
 }
 
+#[test]
+fn treasury_llm_vested_transfer_locks_funds() {
+	new_test_ext().execute_with(|| {
+		let id = LLM::llm_id();
+		let curve = pallet_assets::VestingCurve::Linear { duration: 10, cliff: 0 };
+		assert_noop!(
+			LLM::treasury_llm_vested_transfer(RuntimeOrigin::signed(1), 3, 100, 1, curve, true),
+			BadOrigin
+		);
+		assert_ok!(LLM::treasury_llm_vested_transfer(
+			RuntimeOrigin::root(),
+			3,
+			100,
+			1,
+			curve,
+			true
+		));
+		assert_eq!(LLM::balance(3), 100);
+		assert_eq!(LLM::reducible_balance(&3, Preservation::Expendable, Fortitude::Polite), 0);
+
+		System::set_block_number(6);
+		assert_eq!(LLM::reducible_balance(&3, Preservation::Expendable, Fortitude::Polite), 50);
+
+		assert_noop!(LLM::treasury_llm_revoke_vesting(RuntimeOrigin::signed(1), 3, 0), BadOrigin);
+		assert_ok!(LLM::treasury_llm_revoke_vesting(RuntimeOrigin::root(), 3, 0));
+		System::assert_last_event(
+			pallet_assets::Event::VestingRevoked { asset_id: id, who: 3, returned: 50 }.into(),
+		);
+		assert_eq!(LLM::balance(3), 50);
+		assert_eq!(LLM::reducible_balance(&3, Preservation::Expendable, Fortitude::Polite), 50);
+	});
+}
+
+#[test]
+fn llm_holds_and_freezes_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LLM::treasury_llm_transfer(RuntimeOrigin::root(), 3, 100));
+
+		assert_ok!(LLM::hold(&(), &3, 30));
+		assert_eq!(LLM::balance(3), 70);
+		assert_eq!(LLM::total_balance(&3), 100);
+		assert_eq!(LLM::balance_on_hold(&(), &3), 30);
+
+		assert_ok!(LLM::set_freeze(&(), &3, 50));
+		assert_eq!(LLM::balance_frozen(&(), &3), 50);
+		assert_eq!(LLM::reducible_balance(&3, Preservation::Expendable, Fortitude::Polite), 50);
+
+		assert_ok!(LLM::thaw(&(), &3));
+		assert_ok!(LLM::release(&(), &3, 30, Precision::Exact));
+		assert_eq!(LLM::balance(3), 100);
+		assert_eq!(LLM::reducible_balance(&3, Preservation::Expendable, Fortitude::Polite), 100);
+	});
+}
+
 #[test]
 fn sets_locks_durations_on_genesis() {
 	new_test_ext().execute_with(|| {
@@ -659,6 +720,29 @@ fn set_courts_works() {
 	});
 }
 
+#[test]
+fn llm_fees_go_to_treasury() {
+	new_test_ext().execute_with(|| {
+		let id = LLM::llm_id();
+		let treasury = LLM::get_llm_treasury_account();
+		assert_ok!(LLM::treasury_llm_transfer(RuntimeOrigin::root(), 3, 100));
+		assert_eq!(LLM::fee_in_llm(100), None);
+
+		let rate = Some(FixedU128::from_rational(1, 2));
+		assert_noop!(LLM::set_fee_rate(RuntimeOrigin::signed(1), rate), BadOrigin);
+		assert_ok!(LLM::set_fee_rate(RuntimeOrigin::root(), rate));
+		System::assert_last_event(Event::FeeRateSet(rate).into());
+		assert_eq!(LLM::fee_in_llm(100), Some(50));
+
+		let treasury_balance = Assets::balance(id, treasury);
+		assert_ok!(LLM::charge_fee(&3, 50));
+		assert_ok!(LLM::refund_fee(&3, 20));
+		assert_eq!(Assets::balance(id, 3), 70);
+		assert_eq!(Assets::balance(id, treasury), treasury_balance + 30);
+		assert!(LLM::charge_fee(&3, 71).is_err());
+	});
+}
+
 #[test]
 fn only_approved_accounts_can_call_force_transfer() {
 	new_test_ext().execute_with(|| {
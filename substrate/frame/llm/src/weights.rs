@@ -39,6 +39,7 @@ pub trait WeightInfo {
 	fn remark(l: u32, ) -> Weight;
 	fn force_transfer() -> Weight;
 	fn set_courts(l: u32, ) -> Weight;
+	fn set_fee_rate() -> Weight;
 }
 
 /// Weights for pallet_llm using the Substrate node and recommended hardware.
@@ -205,6 +206,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(241_677, 0).saturating_mul(l.into()))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `LLM::FeeRate` (r:0 w:1)
+	/// Proof: `LLM::FeeRate` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
+	fn set_fee_rate() -> Weight {
+		// Estimated from `set_courts`, not benchmarked yet.
+		Weight::from_parts(7_011_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -370,4 +378,11 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(241_677, 0).saturating_mul(l.into()))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `LLM::FeeRate` (r:0 w:1)
+	/// Proof: `LLM::FeeRate` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
+	fn set_fee_rate() -> Weight {
+		// Estimated from `set_courts`, not benchmarked yet.
+		Weight::from_parts(7_011_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -194,6 +194,24 @@ fn add_collection_attribute<T: Config<I>, I: 'static>(
 	(key, caller, caller_lookup)
 }
 
+fn offer_lease<T: Config<I>, I: 'static>(
+) -> (T::CollectionId, T::ItemId, T::AccountId, T::AccountId) {
+	let (collection, owner, _) = create_collection::<T, I>();
+	let (item, ..) = mint_item::<T, I>(0);
+	let lessee: T::AccountId = account("lessee", 0, SEED);
+	T::Currency::make_free_balance_be(&lessee, DepositBalanceOf::<T, I>::max_value());
+	assert_ok!(Nfts::<T, I>::offer_lease(
+		SystemOrigin::Signed(owner.clone()).into(),
+		collection,
+		item,
+		T::Lookup::unlookup(lessee.clone()),
+		Rent::Native(BalanceOf::<T, I>::from(100u32)),
+		BlockNumberFor::<T>::from(10u32),
+		3,
+	));
+	(collection, item, owner, lessee)
+}
+
 fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::RuntimeEvent) {
 	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
@@ -891,5 +909,113 @@ benchmarks_instance_pallet! {
 		);
 	}
 
+	set_transfer_approval_required {
+		let (collection, caller, _) = create_collection::<T, I>();
+	}: _(SystemOrigin::Signed(caller), collection, true)
+	verify {
+		assert_last_event::<T, I>(
+			Event::TransferApprovalRequiredSet { collection, required: true }.into(),
+		);
+	}
+
+	approve_pending_transfer {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		assert_ok!(Nfts::<T, I>::set_transfer_approval_required(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			true,
+		));
+		assert_ok!(Nfts::<T, I>::transfer(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			item,
+			target_lookup,
+		));
+	}: _(SystemOrigin::Signed(caller), collection, item)
+	verify {
+		assert!(PendingTransfers::<T, I>::get(collection, item).is_none());
+		assert_eq!(Item::<T, I>::get(collection, item).unwrap().owner, target);
+		assert_eq!(TitleHistoryLength::<T, I>::get(collection, item), 1);
+	}
+
+	cancel_pending_transfer {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		assert_ok!(Nfts::<T, I>::set_transfer_approval_required(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			true,
+		));
+		assert_ok!(Nfts::<T, I>::transfer(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			item,
+			target_lookup,
+		));
+	}: _(SystemOrigin::Signed(caller), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::TransferRequestCancelled { collection, item }.into());
+	}
+
+	offer_lease {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let lessee: T::AccountId = account("lessee", 0, SEED);
+		let lessee_lookup = T::Lookup::unlookup(lessee.clone());
+		let rent = Rent::Native(BalanceOf::<T, I>::from(100u32));
+		let period = BlockNumberFor::<T>::from(10u32);
+	}: _(SystemOrigin::Signed(caller), collection, item, lessee_lookup, rent.clone(), period, 3)
+	verify {
+		assert_last_event::<T, I>(
+			Event::LeaseOffered { collection, item, lessee, rent, period, periods: 3 }.into(),
+		);
+	}
+
+	cancel_lease_offer {
+		let (collection, item, owner, _) = offer_lease::<T, I>();
+	}: _(SystemOrigin::Signed(owner), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::LeaseOfferCancelled { collection, item }.into());
+	}
+
+	accept_lease {
+		let (collection, item, _, lessee) = offer_lease::<T, I>();
+	}: _(SystemOrigin::Signed(lessee.clone()), collection, item)
+	verify {
+		assert_eq!(Nfts::<T, I>::item_user(collection, item), Some(lessee));
+	}
+
+	pay_rent {
+		let (collection, item, _, lessee) = offer_lease::<T, I>();
+		assert_ok!(Nfts::<T, I>::accept_lease(
+			SystemOrigin::Signed(lessee.clone()).into(),
+			collection,
+			item,
+		));
+	}: _(SystemOrigin::Signed(lessee.clone()), collection, item, 2)
+	verify {
+		let lease = Nfts::<T, I>::lease(collection, item).unwrap();
+		assert_eq!(lease.paid_until, lease.expires_at);
+	}
+
+	end_lease {
+		let (collection, item, _, lessee) = offer_lease::<T, I>();
+		assert_ok!(Nfts::<T, I>::accept_lease(
+			SystemOrigin::Signed(lessee.clone()).into(),
+			collection,
+			item,
+		));
+	}: _(SystemOrigin::Signed(lessee.clone()), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::LeaseEnded { collection, item, lessee }.into());
+	}
+
 	impl_benchmark_test_suite!(Nfts, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -210,6 +210,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// This function returns a dispatch error in the following cases:
 	/// - If the collection ID is invalid ([`UnknownCollection`](crate::Error::UnknownCollection)).
 	/// - If the item is locked ([`ItemLocked`](crate::Error::ItemLocked)).
+	/// - If the item is leased ([`ItemLeased`](crate::Error::ItemLeased)).
 	pub fn do_burn(
 		collection: T::CollectionId,
 		item: T::ItemId,
@@ -220,6 +221,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			!Self::has_system_attribute(&collection, &item, PalletAttributes::TransferDisabled)?,
 			Error::<T, I>::ItemLocked
 		);
+		ensure!(Self::item_user(collection, item).is_none(), Error::<T, I>::ItemLeased);
 		let item_config = Self::get_item_config(&collection, &item)?;
 		// NOTE: if item's settings are not empty (e.g. item's metadata is locked)
 		// then we keep the config record and don't remove it
@@ -266,6 +268,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Account::<T, I>::remove((&owner, &collection, &item));
 		ItemPriceOf::<T, I>::remove(&collection, &item);
 		PendingSwapOf::<T, I>::remove(&collection, &item);
+		PendingTransfers::<T, I>::remove(&collection, &item);
+		LeaseOffers::<T, I>::remove(&collection, &item);
+		Leases::<T, I>::remove(&collection, &item);
+		Self::remove_metadata_index(collection, item);
 		ItemAttributesApprovalsOf::<T, I>::remove(&collection, &item);
 
 		if remove_config {
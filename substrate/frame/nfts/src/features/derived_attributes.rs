@@ -0,0 +1,86 @@
+/*
+
+Copyright © 2023 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+//! This module contains helper methods to maintain system attributes derived from the metadata of
+//! items (e.g. area of land plots).
+//!
+//! Added by Liberland
+
+use crate::{traits::MetadataValidator, *};
+use frame_support::pallet_prelude::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// System attributes derived from `metadata` of the item.
+	pub(crate) fn derived_attributes(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		metadata: Option<&BoundedVec<u8, T::StringLimit>>,
+	) -> Vec<(Vec<u8>, Vec<u8>)> {
+		metadata.map_or_else(Vec::new, |metadata| {
+			T::MetadataValidator::derived_attributes(collection, item, metadata)
+		})
+	}
+
+	/// Replace derived attributes `old` of the item with `new`. There can be at most
+	/// `MaxAttributesPerCall` of them.
+	pub(crate) fn set_derived_attributes(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		old: Vec<(Vec<u8>, Vec<u8>)>,
+		new: Vec<(Vec<u8>, Vec<u8>)>,
+	) -> DispatchResult {
+		ensure!(
+			new.len() <= T::MaxAttributesPerCall::get() as usize,
+			Error::<T, I>::MaxAttributesLimitReached
+		);
+		for (key, _) in old {
+			if new.iter().any(|(new_key, _)| new_key == &key) {
+				continue
+			}
+			let key = Self::construct_attribute_key(key)?;
+			if Attribute::<T, I>::contains_key((
+				&collection,
+				Some(item),
+				&AttributeNamespace::Pallet,
+				&key,
+			)) {
+				Self::do_clear_attribute(
+					None,
+					collection,
+					Some(item),
+					AttributeNamespace::Pallet,
+					key,
+				)?;
+			}
+		}
+		for (key, value) in new {
+			Self::do_force_set_attribute(
+				None,
+				collection,
+				Some(item),
+				AttributeNamespace::Pallet,
+				Self::construct_attribute_key(key)?,
+				Self::construct_attribute_value(value)?,
+			)?;
+		}
+		Ok(())
+	}
+
+	/// Get the system attribute of the item derived from its metadata.
+	pub fn derived_attribute(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		key: Vec<u8>,
+	) -> Option<Vec<u8>> {
+		let key = BoundedVec::<u8, T::KeyLimit>::try_from(key).ok()?;
+		Attribute::<T, I>::get((collection, Some(item), AttributeNamespace::Pallet, key))
+			.map(|(value, _)| value.into())
+	}
+}
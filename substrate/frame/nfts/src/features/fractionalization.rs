@@ -0,0 +1,116 @@
+/*
+
+Copyright © 2023 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+//! This module contains helper methods to fractionalize items into fungible shares and to unify
+//! them back.
+//!
+//! Added by Liberland
+
+use crate::{traits::Shares, *};
+use frame_support::pallet_prelude::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub fn do_fractionalize(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		asset: ShareAssetIdOf<T, I>,
+		beneficiary: T::AccountId,
+		shares: ShareBalanceOf<T, I>,
+	) -> DispatchResult {
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(details.owner == origin, Error::<T, I>::NoPermission);
+		ensure!(!T::Locker::is_locked(collection, item), Error::<T, I>::ItemLocked);
+		ensure!(
+			!Self::has_system_attribute(&collection, &item, PalletAttributes::TransferDisabled)?,
+			Error::<T, I>::ItemLocked
+		);
+		ensure!(
+			!PendingTransfers::<T, I>::contains_key(&collection, &item),
+			Error::<T, I>::TransferAlreadyPending
+		);
+		// unifying moves the item without the collection owner's approval
+		ensure!(
+			!TransferApprovalRequired::<T, I>::get(&collection),
+			Error::<T, I>::TransferApprovalRequired
+		);
+		ensure!(Self::item_user(collection, item).is_none(), Error::<T, I>::ItemLeased);
+		ensure!(!shares.is_zero(), Error::<T, I>::ZeroShares);
+		ensure!(!SharesItem::<T, I>::contains_key(&asset), Error::<T, I>::SharesAssetInUse);
+
+		Self::do_force_set_attribute(
+			None,
+			collection,
+			Some(item),
+			AttributeNamespace::Pallet,
+			Self::construct_attribute_key(PalletAttributes::TransferDisabled.encode())?,
+			BoundedVec::new(),
+		)?;
+		ItemShares::<T, I>::insert(&collection, &item, Fractionalization { asset, shares });
+		SharesItem::<T, I>::insert(&asset, (collection, item));
+		T::Shares::issue(
+			asset,
+			&beneficiary,
+			shares,
+			CitizenshipRequired::<T, I>::get(&collection),
+		)?;
+
+		Self::deposit_event(Event::ItemFractionalized {
+			collection,
+			item,
+			asset,
+			shares,
+			beneficiary,
+		});
+		Ok(())
+	}
+
+	pub fn do_unify(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		beneficiary: T::AccountId,
+	) -> DispatchResult {
+		let Fractionalization { asset, .. } =
+			ItemShares::<T, I>::take(&collection, &item).ok_or(Error::<T, I>::NotFractionalized)?;
+		ensure!(T::Shares::holds_all(asset, &origin), Error::<T, I>::NotAllShares);
+		T::Shares::redeem(asset, &origin)?;
+		SharesItem::<T, I>::remove(&asset);
+
+		Self::do_clear_attribute(
+			None,
+			collection,
+			Some(item),
+			AttributeNamespace::Pallet,
+			Self::construct_attribute_key(PalletAttributes::TransferDisabled.encode())?,
+		)?;
+
+		let owner = Self::owner(collection, item).ok_or(Error::<T, I>::UnknownItem)?;
+		if owner != beneficiary {
+			Self::do_transfer(collection, item, beneficiary.clone(), |_, _| Ok(()))?;
+		}
+
+		Self::deposit_event(Event::ItemUnified { collection, item, asset, beneficiary });
+		Ok(())
+	}
+
+	/// Get the asset representing shares of the item, if it's fractionalized.
+	pub fn item_shares(
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> Option<ShareAssetIdOf<T, I>> {
+		ItemShares::<T, I>::get(collection, item).map(|f| f.asset)
+	}
+
+	/// Get the item represented by shares `asset`, if any.
+	pub fn shares_item(asset: ShareAssetIdOf<T, I>) -> Option<(T::CollectionId, T::ItemId)> {
+		SharesItem::<T, I>::get(asset)
+	}
+}
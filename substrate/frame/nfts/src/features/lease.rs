@@ -0,0 +1,186 @@
+/*
+
+Copyright © 2023 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+//! This module contains helper methods to lease items, giving the lessee a user role distinct
+//! from the owner for a limited time, in exchange for rent.
+//!
+//! The user role lapses on its own once the paid period or the whole lease ends, so the item
+//! reverts to its owner without any further action. Leased items can't be transferred, and
+//! transfers drop lease offers and lapsed leases of the previous owner.
+//!
+//! Added by Liberland
+
+use crate::{traits::RentAssets, *};
+use frame_support::{pallet_prelude::*, traits::ExistenceRequirement::KeepAlive};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub fn do_offer_lease(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		lessee: T::AccountId,
+		rent: RentOf<T, I>,
+		period: BlockNumberFor<T>,
+		periods: u32,
+	) -> DispatchResult {
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(details.owner == origin, Error::<T, I>::NoPermission);
+		ensure!(!period.is_zero() && periods > 0, Error::<T, I>::InvalidLeaseTerms);
+		ensure!(Self::item_user(collection, item).is_none(), Error::<T, I>::ItemLeased);
+
+		LeaseOffers::<T, I>::insert(
+			&collection,
+			&item,
+			LeaseOffer { lessee: lessee.clone(), rent: rent.clone(), period, periods },
+		);
+
+		Self::deposit_event(Event::LeaseOffered {
+			collection,
+			item,
+			lessee,
+			rent,
+			period,
+			periods,
+		});
+		Ok(())
+	}
+
+	pub fn do_cancel_lease_offer(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> DispatchResult {
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(details.owner == origin, Error::<T, I>::NoPermission);
+		ensure!(
+			LeaseOffers::<T, I>::take(&collection, &item).is_some(),
+			Error::<T, I>::NoLeaseOffer
+		);
+
+		Self::deposit_event(Event::LeaseOfferCancelled { collection, item });
+		Ok(())
+	}
+
+	pub fn do_accept_lease(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> DispatchResult {
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		let offer =
+			LeaseOffers::<T, I>::take(&collection, &item).ok_or(Error::<T, I>::NoLeaseOffer)?;
+		ensure!(offer.lessee == origin, Error::<T, I>::NoPermission);
+		ensure!(Self::item_user(collection, item).is_none(), Error::<T, I>::ItemLeased);
+		Self::maybe_ensure_citizenship(collection, &origin)?;
+
+		Self::transfer_rent(&offer.rent, 1, &origin, &details.owner)?;
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let paid_until = now.saturating_add(offer.period);
+		let expires_at = now.saturating_add(offer.period.saturating_mul(offer.periods.into()));
+		Leases::<T, I>::insert(
+			&collection,
+			&item,
+			Lease {
+				lessee: origin.clone(),
+				rent: offer.rent,
+				period: offer.period,
+				paid_until,
+				expires_at,
+			},
+		);
+
+		Self::deposit_event(Event::LeaseStarted {
+			collection,
+			item,
+			lessee: origin,
+			paid_until,
+			expires_at,
+		});
+		Ok(())
+	}
+
+	pub fn do_pay_rent(
+		payer: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		periods: u32,
+	) -> DispatchResult {
+		let owner = Self::owner(collection, item).ok_or(Error::<T, I>::UnknownItem)?;
+		let mut lease = Self::active_lease(collection, item).ok_or(Error::<T, I>::NotLeased)?;
+		ensure!(periods > 0, Error::<T, I>::InvalidLeaseTerms);
+		let paid_until =
+			lease.paid_until.saturating_add(lease.period.saturating_mul(periods.into()));
+		ensure!(paid_until <= lease.expires_at, Error::<T, I>::InvalidLeaseTerms);
+		Self::maybe_ensure_citizenship(collection, &lease.lessee)?;
+
+		Self::transfer_rent(&lease.rent, periods, &payer, &owner)?;
+		lease.paid_until = paid_until;
+		Leases::<T, I>::insert(&collection, &item, lease);
+
+		Self::deposit_event(Event::RentPaid { collection, item, payer, periods, paid_until });
+		Ok(())
+	}
+
+	pub fn do_end_lease(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> DispatchResult {
+		let lease = Leases::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::NotLeased)?;
+		// active leases can only be surrendered by the lessee, lapsed ones can be cleaned up by
+		// anyone
+		if Self::is_lease_active(&lease) {
+			ensure!(lease.lessee == origin, Error::<T, I>::NoPermission);
+		}
+		Leases::<T, I>::remove(&collection, &item);
+
+		Self::deposit_event(Event::LeaseEnded { collection, item, lessee: lease.lessee });
+		Ok(())
+	}
+
+	fn transfer_rent(
+		rent: &RentOf<T, I>,
+		periods: u32,
+		from: &T::AccountId,
+		to: &T::AccountId,
+	) -> DispatchResult {
+		match rent {
+			Rent::Native(amount) =>
+				T::Currency::transfer(from, to, amount.saturating_mul(periods.into()), KeepAlive),
+			Rent::Asset(asset, amount) =>
+				T::RentAssets::transfer(*asset, from, to, amount.saturating_mul(periods.into())),
+		}
+	}
+
+	fn is_lease_active(lease: &LeaseOf<T, I>) -> bool {
+		let now = frame_system::Pallet::<T>::block_number();
+		now < lease.paid_until && now < lease.expires_at
+	}
+
+	fn active_lease(collection: T::CollectionId, item: T::ItemId) -> Option<LeaseOf<T, I>> {
+		Leases::<T, I>::get(collection, item).filter(Self::is_lease_active)
+	}
+
+	/// Get the current user of the item - the lessee, if it's leased and the rent is paid.
+	pub fn item_user(collection: T::CollectionId, item: T::ItemId) -> Option<T::AccountId> {
+		Self::active_lease(collection, item).map(|lease| lease.lessee)
+	}
+
+	/// Get the lease of the item, including lapsed ones that weren't ended yet.
+	pub fn lease(collection: T::CollectionId, item: T::ItemId) -> Option<LeaseOf<T, I>> {
+		Leases::<T, I>::get(collection, item)
+	}
+
+	/// Get the lease offered for the item, if any.
+	pub fn lease_offer(collection: T::CollectionId, item: T::ItemId) -> Option<LeaseOfferOf<T, I>> {
+		LeaseOffers::<T, I>::get(collection, item)
+	}
+}
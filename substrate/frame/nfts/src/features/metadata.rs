@@ -26,6 +26,12 @@ use crate::{traits::MetadataValidator, *};
 use frame_support::pallet_prelude::*;
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Weight of validating item metadata with `MetadataValidator`, on top of the benchmarked
+	/// `set_metadata` weight.
+	pub(crate) fn metadata_validation_weight() -> Weight {
+		T::DbWeight::get().reads(T::MetadataValidator::max_validation_reads() as u64)
+	}
+
 	/// Sets the metadata for a specific item within a collection.
 	///
 	/// - `maybe_check_origin`: An optional account ID that is allowed to set the metadata. If
@@ -43,6 +49,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// - `LockedItemMetadata`: The metadata for the item is locked and cannot be modified.
 	/// - `NoPermission`: The caller does not have the required permission to set the metadata.
 	/// - `DepositExceeded`: The deposit amount exceeds the maximum allowed value.
+	/// - `TooManyPlotsInCell`: The metadata can't be validated within the validator's limits.
+	/// - `IncorrectData`: The metadata was rejected by the validator.
 	pub(crate) fn do_set_item_metadata(
 		maybe_check_origin: Option<T::AccountId>,
 		collection: T::CollectionId,
@@ -69,9 +77,24 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		let collection_config = Self::get_collection_config(&collection)?;
 
+		ensure!(
+			T::MetadataValidator::within_validation_limits(collection, item, &data),
+			Error::<T, I>::TooManyPlotsInCell
+		);
 		if !T::MetadataValidator::validate_metadata(collection, item, &data) {
 			return Err(Error::<T, I>::IncorrectData.into())
 		}
+		let index_keys: BoundedVec<u64, T::MaxMetadataIndexKeys> =
+			T::MetadataValidator::index_keys(collection, item, &data)
+				.try_into()
+				.map_err(|_| Error::<T, I>::TooManyMetadataIndexKeys)?;
+		let index_keys_len = index_keys.len() * sp_std::mem::size_of::<u64>();
+		let old_derived_attributes = Self::derived_attributes(
+			collection,
+			item,
+			Self::item_metadata(collection, item).as_ref(),
+		);
+		let derived_attributes = Self::derived_attributes(collection, item, Some(&data));
 
 		ItemMetadataOf::<T, I>::try_mutate_exists(collection, item, |metadata| {
 			if metadata.is_none() {
@@ -86,7 +109,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			if collection_config.is_setting_enabled(CollectionSetting::DepositRequired) && !is_root
 			{
 				deposit = T::DepositPerByte::get()
-					.saturating_mul(((data.len()) as u32).into())
+					.saturating_mul(((data.len() + index_keys_len) as u32).into())
 					.saturating_add(T::MetadataDepositBase::get());
 			}
 
@@ -114,8 +137,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 			Collection::<T, I>::insert(&collection, &collection_details);
 			Self::deposit_event(Event::ItemMetadataSet { collection, item, data });
-			Ok(())
-		})
+			Ok::<(), DispatchError>(())
+		})?;
+
+		Self::set_metadata_index(collection, item, index_keys);
+		Self::set_derived_attributes(collection, item, old_derived_attributes, derived_attributes)
 	}
 
 	/// Clears the metadata for a specific item within a collection.
@@ -166,6 +192,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 
 		Collection::<T, I>::insert(&collection, &collection_details);
+		Self::remove_metadata_index(collection, item);
+		let derived_attributes = Self::derived_attributes(collection, item, Some(&metadata.data));
+		Self::set_derived_attributes(collection, item, derived_attributes, Vec::new())?;
 		Self::deposit_event(Event::ItemMetadataCleared { collection, item });
 
 		Ok(())
@@ -0,0 +1,82 @@
+/*
+
+Copyright © 2023 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+//! This module contains helper methods to maintain `MetadataIndex`, which allows looking up items
+//! by keys derived from their metadata (e.g. spatial grid cells of land plots).
+//!
+//! Added by Liberland
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Replace keys under which the item is indexed.
+	pub(crate) fn set_metadata_index(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		keys: BoundedVec<u64, T::MaxMetadataIndexKeys>,
+	) {
+		Self::remove_metadata_index(collection, item);
+		if keys.is_empty() {
+			return
+		}
+		for key in keys.iter() {
+			MetadataIndex::<T, I>::insert((&collection, key, &item), ());
+		}
+		ItemMetadataIndexKeys::<T, I>::insert(&collection, &item, keys);
+	}
+
+	/// Remove the item from the index.
+	pub(crate) fn remove_metadata_index(collection: T::CollectionId, item: T::ItemId) {
+		for key in ItemMetadataIndexKeys::<T, I>::take(&collection, &item) {
+			MetadataIndex::<T, I>::remove((&collection, key, &item));
+		}
+	}
+
+	/// Get the metadata of the item, if set.
+	pub fn item_metadata(
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> Option<BoundedVec<u8, T::StringLimit>> {
+		ItemMetadataOf::<T, I>::get(collection, item).map(|m| m.data)
+	}
+
+	/// Items of the collection indexed under `key`.
+	pub fn indexed_items(collection: T::CollectionId, key: u64) -> Vec<T::ItemId> {
+		MetadataIndex::<T, I>::iter_key_prefix((collection, key)).collect()
+	}
+
+	/// Recalculate index keys and derived attributes of an item from its current metadata. Used
+	/// for items that had metadata set before these were introduced. Doesn't touch deposits.
+	pub fn reindex_item_metadata(collection: T::CollectionId, item: T::ItemId) -> DispatchResult {
+		let metadata = ItemMetadataOf::<T, I>::get(&collection, &item)
+			.ok_or(Error::<T, I>::MetadataNotFound)?;
+		let keys = T::MetadataValidator::index_keys(collection, item, &metadata.data)
+			.try_into()
+			.map_err(|_| Error::<T, I>::TooManyMetadataIndexKeys)?;
+		Self::set_metadata_index(collection, item, keys);
+		let derived_attributes = Self::derived_attributes(collection, item, Some(&metadata.data));
+		Self::set_derived_attributes(collection, item, Vec::new(), derived_attributes)
+	}
+
+	/// Replace metadata of an item without validating it. Used by runtime migrations changing
+	/// the format of metadata. Doesn't touch deposits, index keys or derived attributes.
+	pub fn translate_item_metadata(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		data: BoundedVec<u8, T::StringLimit>,
+	) -> DispatchResult {
+		ItemMetadataOf::<T, I>::try_mutate(&collection, &item, |metadata| {
+			let metadata = metadata.as_mut().ok_or(Error::<T, I>::MetadataNotFound)?;
+			metadata.data = data;
+			Ok(())
+		})
+	}
+}
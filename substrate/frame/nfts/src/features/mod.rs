@@ -26,8 +26,14 @@ pub mod buy_sell;
 pub mod citizenship;
 pub mod create_delete_collection;
 pub mod create_delete_item;
+pub mod derived_attributes;
+pub mod fractionalization;
+pub mod lease;
 pub mod lock;
 pub mod metadata;
+pub mod metadata_index;
 pub mod roles;
 pub mod settings;
-pub mod transfer;
\ No newline at end of file
+pub mod subdivision;
+pub mod transfer;
+pub mod transfer_approval;
\ No newline at end of file
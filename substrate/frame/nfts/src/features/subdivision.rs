@@ -0,0 +1,191 @@
+/*
+
+Copyright © 2023 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+//! This module contains helper methods to subdivide items and merge them, keeping links between
+//! the original and new items.
+//!
+//! Added by Liberland
+
+use crate::{traits::MetadataValidator, *};
+use frame_support::pallet_prelude::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Weight of subdividing an item into `parts` items or merging `parts` items.
+	pub(crate) fn restructure_weight(parts: u32) -> Weight {
+		let parts = parts.saturating_add(1) as u64;
+		T::WeightInfo::mint()
+			.saturating_add(T::WeightInfo::set_metadata())
+			.saturating_add(T::WeightInfo::lock_item_properties())
+			.saturating_add(Self::metadata_validation_weight())
+			.saturating_add(T::WeightInfo::force_set_attribute())
+			.saturating_add(
+				T::WeightInfo::force_set_attribute()
+					.saturating_mul(T::MaxAttributesPerCall::get() as u64),
+			)
+			// index entries, plus the item's lease
+			.saturating_add(T::DbWeight::get().reads_writes(
+				3 * T::MaxMetadataIndexKeys::get() as u64 + 1,
+				2 * T::MaxMetadataIndexKeys::get() as u64,
+			))
+			.saturating_mul(parts)
+	}
+
+	fn ensure_can_restructure(
+		maybe_check_origin: &Option<T::AccountId>,
+		collection: &T::CollectionId,
+	) -> DispatchResult {
+		if let Some(check_origin) = maybe_check_origin {
+			ensure!(
+				Self::has_role(collection, check_origin, CollectionRole::Admin),
+				Error::<T, I>::NoPermission
+			);
+		}
+		Ok(())
+	}
+
+	/// Checks that the item can be subdivided or merged and returns its owner and metadata.
+	fn restructured_item_details(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> Result<(T::AccountId, BoundedVec<u8, T::StringLimit>), DispatchError> {
+		let details = Item::<T, I>::get(collection, item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(!T::Locker::is_locked(*collection, *item), Error::<T, I>::ItemLocked);
+		ensure!(
+			!Self::has_system_attribute(collection, item, PalletAttributes::TransferDisabled)?,
+			Error::<T, I>::ItemLocked
+		);
+		ensure!(
+			!PendingTransfers::<T, I>::contains_key(collection, item),
+			Error::<T, I>::TransferAlreadyPending
+		);
+		ensure!(Self::item_user(*collection, *item).is_none(), Error::<T, I>::ItemLeased);
+		let metadata =
+			Self::item_metadata(*collection, *item).ok_or(Error::<T, I>::MetadataNotFound)?;
+		Ok((details.owner, metadata))
+	}
+
+	fn set_links(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		attribute: PalletAttributes<T::CollectionId>,
+		items: &Vec<T::ItemId>,
+	) -> DispatchResult {
+		Self::do_force_set_attribute(
+			None,
+			collection,
+			Some(item),
+			AttributeNamespace::Pallet,
+			Self::construct_attribute_key(attribute.encode())?,
+			Self::construct_attribute_value(items.encode())?,
+		)
+	}
+
+	/// Keep the item for provenance, but take it out of circulation.
+	fn retire_item(collection: T::CollectionId, item: T::ItemId) -> DispatchResult {
+		Self::remove_metadata_index(collection, item);
+		Self::do_lock_item_properties(None, collection, item, true, false)?;
+		Self::do_force_set_attribute(
+			None,
+			collection,
+			Some(item),
+			AttributeNamespace::Pallet,
+			Self::construct_attribute_key(PalletAttributes::TransferDisabled.encode())?,
+			BoundedVec::new(),
+		)
+	}
+
+	/// Mint a new item with metadata, as a result of subdivision or merge.
+	fn mint_part(
+		maybe_check_origin: &Option<T::AccountId>,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		owner: T::AccountId,
+		metadata: BoundedVec<u8, T::StringLimit>,
+	) -> DispatchResult {
+		let item_config = ItemConfig { settings: Self::get_default_item_settings(&collection)? };
+		Self::do_mint(collection, item, maybe_check_origin.clone(), owner, item_config, |_, _| {
+			Ok(())
+		})?;
+		Self::do_set_item_metadata(maybe_check_origin.clone(), collection, item, metadata, None)
+	}
+
+	pub fn do_subdivide(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		parts: Vec<(T::ItemId, BoundedVec<u8, T::StringLimit>)>,
+	) -> DispatchResult {
+		Self::ensure_can_restructure(&maybe_check_origin, &collection)?;
+		ensure!(parts.len() >= 2, Error::<T, I>::NotEnoughParts);
+		let (owner, whole) = Self::restructured_item_details(&collection, &item)?;
+
+		let part_metadata: Vec<_> = parts.iter().map(|(_, m)| m.clone()).collect();
+		ensure!(
+			T::MetadataValidator::validate_tiling(collection, &whole, &part_metadata),
+			Error::<T, I>::InvalidTiling
+		);
+
+		Self::retire_item(collection, item)?;
+
+		let part_ids: Vec<T::ItemId> = parts.iter().map(|(id, _)| *id).collect();
+		let parents = vec![item];
+		for (part, metadata) in parts {
+			Self::mint_part(&maybe_check_origin, collection, part, owner.clone(), metadata)?;
+			Self::set_links(collection, part, PalletAttributes::Parents, &parents)?;
+		}
+		Self::set_links(collection, item, PalletAttributes::Children, &part_ids)?;
+
+		Self::deposit_event(Event::ItemSubdivided { collection, item, parts: part_ids });
+		Ok(())
+	}
+
+	pub fn do_merge(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		items: Vec<T::ItemId>,
+		new_item: T::ItemId,
+		metadata: BoundedVec<u8, T::StringLimit>,
+	) -> DispatchResult {
+		Self::ensure_can_restructure(&maybe_check_origin, &collection)?;
+		ensure!(items.len() >= 2, Error::<T, I>::NotEnoughParts);
+
+		let mut owner = None;
+		let mut part_metadata = Vec::with_capacity(items.len());
+		for (i, item) in items.iter().enumerate() {
+			ensure!(!items[..i].contains(item), Error::<T, I>::NotEnoughParts);
+			let (item_owner, item_metadata) = Self::restructured_item_details(&collection, item)?;
+			ensure!(
+				owner.get_or_insert_with(|| item_owner.clone()) == &item_owner,
+				Error::<T, I>::WrongOwner
+			);
+			part_metadata.push(item_metadata);
+		}
+		let owner = owner.ok_or(Error::<T, I>::NotEnoughParts)?;
+
+		ensure!(
+			T::MetadataValidator::validate_tiling(collection, &metadata, &part_metadata),
+			Error::<T, I>::InvalidTiling
+		);
+
+		for item in items.iter() {
+			Self::retire_item(collection, *item)?;
+		}
+
+		Self::mint_part(&maybe_check_origin, collection, new_item, owner, metadata)?;
+		let children = vec![new_item];
+		for item in items.iter() {
+			Self::set_links(collection, *item, PalletAttributes::Children, &children)?;
+		}
+		Self::set_links(collection, new_item, PalletAttributes::Parents, &items)?;
+
+		Self::deposit_event(Event::ItemsMerged { collection, items, new_item });
+		Ok(())
+	}
+}
@@ -47,6 +47,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	///   ([`ItemLocked`](crate::Error::ItemLocked)).
 	/// - If the collection or item is non-transferable
 	///   ([`ItemsNonTransferable`](crate::Error::ItemsNonTransferable)).
+	/// - If transfers in the collection require approval
+	///   ([`TransferApprovalRequired`](crate::Error::TransferApprovalRequired)).
 	pub fn do_transfer(
 		collection: T::CollectionId,
 		item: T::ItemId,
@@ -55,6 +57,25 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			&CollectionDetailsFor<T, I>,
 			&mut ItemDetailsFor<T, I>,
 		) -> DispatchResult,
+	) -> DispatchResult {
+		// Approval-gated transfers go through `do_approve_pending_transfer`.
+		ensure!(
+			!TransferApprovalRequired::<T, I>::get(&collection),
+			Error::<T, I>::TransferApprovalRequired
+		);
+		Self::do_transfer_unchecked_approval(collection, item, dest, with_details)
+	}
+
+	/// Same as [`do_transfer`](Self::do_transfer), but doesn't check if transfers in the
+	/// collection require approval.
+	pub(crate) fn do_transfer_unchecked_approval(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		dest: T::AccountId,
+		with_details: impl FnOnce(
+			&CollectionDetailsFor<T, I>,
+			&mut ItemDetailsFor<T, I>,
+		) -> DispatchResult,
 	) -> DispatchResult {
 		// Retrieve collection details.
 		let collection_details =
@@ -85,6 +106,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Self::maybe_ensure_citizenship(collection, &dest)?;
 
+		// The lessee keeps the item until the lease lapses or is ended.
+		ensure!(Self::item_user(collection, item).is_none(), Error::<T, I>::ItemLeased);
+
 		// Retrieve the item details.
 		let mut details =
 			Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
@@ -107,6 +131,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Item::<T, I>::insert(&collection, &item, &details);
 		ItemPriceOf::<T, I>::remove(&collection, &item);
 		PendingSwapOf::<T, I>::remove(&collection, &item);
+		PendingTransfers::<T, I>::remove(&collection, &item);
+		// Lease offers and lapsed leases were agreed to by the previous owner.
+		LeaseOffers::<T, I>::remove(&collection, &item);
+		Leases::<T, I>::remove(&collection, &item);
 
 		// Emit `Transferred` event.
 		Self::deposit_event(Event::Transferred {
@@ -0,0 +1,142 @@
+/*
+
+Copyright © 2023 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+//! This module contains helper methods for collections where item transfers need to be approved
+//! by the collection owner, along with the title history of such items.
+//!
+//! Added by Liberland
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub fn do_set_transfer_approval_required(
+		collection: T::CollectionId,
+		required: bool,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		let collection_details =
+			Collection::<T, I>::get(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = maybe_check_owner {
+			ensure!(collection_details.owner == check_owner, Error::<T, I>::NoPermission);
+		}
+
+		TransferApprovalRequired::<T, I>::insert(collection, required);
+		Self::deposit_event(Event::TransferApprovalRequiredSet { collection, required });
+		Ok(())
+	}
+
+	/// Create a pending transfer of an item. `origin` must be the owner of the item or an
+	/// approved delegate.
+	pub(crate) fn do_request_transfer(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		dest: T::AccountId,
+	) -> DispatchResult {
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		if details.owner != origin {
+			let deadline = details.approvals.get(&origin).ok_or(Error::<T, I>::NoPermission)?;
+			if let Some(d) = deadline {
+				let block_number = frame_system::Pallet::<T>::block_number();
+				ensure!(block_number <= *d, Error::<T, I>::ApprovalExpired);
+			}
+		}
+		ensure!(
+			!PendingTransfers::<T, I>::contains_key(&collection, &item),
+			Error::<T, I>::TransferAlreadyPending
+		);
+		Self::maybe_ensure_citizenship(collection, &dest)?;
+
+		let pending = PendingTransfer {
+			from: details.owner,
+			to: dest,
+			requested_at: frame_system::Pallet::<T>::block_number(),
+		};
+		PendingTransfers::<T, I>::insert(&collection, &item, &pending);
+		Self::deposit_event(Event::TransferRequested {
+			collection,
+			item,
+			from: pending.from,
+			to: pending.to,
+		});
+		Ok(())
+	}
+
+	/// Execute a pending transfer and record it in item's title history.
+	pub fn do_approve_pending_transfer(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		let collection_details =
+			Collection::<T, I>::get(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(&collection_details.owner == check_owner, Error::<T, I>::NoPermission);
+		}
+		let pending = PendingTransfers::<T, I>::get(&collection, &item)
+			.ok_or(Error::<T, I>::NoPendingTransfer)?;
+
+		Self::do_transfer_unchecked_approval(
+			collection,
+			item,
+			pending.to.clone(),
+			|_, details| {
+				ensure!(details.owner == pending.from, Error::<T, I>::WrongOwner);
+				Ok(())
+			},
+		)?;
+
+		let approved_by = maybe_check_owner.map(|who| T::TransferApprover::approver(&who));
+		let record = TitleRecord {
+			from: pending.from,
+			to: pending.to,
+			requested_at: pending.requested_at,
+			approved_at: frame_system::Pallet::<T>::block_number(),
+			approved_by: approved_by.clone(),
+		};
+		let index = TitleHistoryLength::<T, I>::get(&collection, &item);
+		TitleHistory::<T, I>::insert((&collection, &item, index), record);
+		TitleHistoryLength::<T, I>::insert(&collection, &item, index.saturating_add(1));
+
+		Self::deposit_event(Event::TransferRequestApproved { collection, item, approved_by });
+		Ok(())
+	}
+
+	/// Remove a pending transfer. `maybe_check_origin` must be the owner of the collection, the
+	/// owner of the item or the requested recipient.
+	pub fn do_cancel_pending_transfer(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		maybe_check_origin: Option<T::AccountId>,
+	) -> DispatchResult {
+		let pending = PendingTransfers::<T, I>::get(&collection, &item)
+			.ok_or(Error::<T, I>::NoPendingTransfer)?;
+		if let Some(who) = maybe_check_origin {
+			let is_collection_owner = Self::collection_owner(collection).as_ref() == Some(&who);
+			ensure!(
+				is_collection_owner || pending.from == who || pending.to == who,
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		PendingTransfers::<T, I>::remove(&collection, &item);
+		Self::deposit_event(Event::TransferRequestCancelled { collection, item });
+		Ok(())
+	}
+
+	/// All approved transfers of the item, oldest first.
+	pub fn title_history(collection: T::CollectionId, item: T::ItemId) -> Vec<TitleRecordOf<T>> {
+		let len = TitleHistoryLength::<T, I>::get(&collection, &item);
+		(0..len)
+			.filter_map(|index| TitleHistory::<T, I>::get((&collection, &item, index)))
+			.collect()
+	}
+}
@@ -83,7 +83,7 @@ pub mod pallet {
 	use liberland_traits::CitizenshipChecker;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -227,6 +227,27 @@ pub mod pallet {
 			Self::CollectionId,
 			Self::ItemId,
 			Self::StringLimit>;
+
+		/// The max number of `MetadataIndex` keys a single item can be indexed under.
+		/// Added by Liberland
+		#[pallet::constant]
+		type MaxMetadataIndexKeys: Get<u32>;
+
+		/// The max number of items an item can be subdivided into or merged from.
+		/// Added by Liberland
+		#[pallet::constant]
+		type MaxItemParts: Get<u32>;
+
+		/// Liberland Transfer Approver - resolves who approved a transfer in collections with
+		/// `TransferApprovalRequired` set, for the purpose of title history
+		type TransferApprover: traits::TransferApprover<Self::AccountId>;
+
+		/// Liberland Shares - fungible assets issued when fractionalizing items
+		type Shares: traits::Shares<Self::AccountId>;
+
+		/// Liberland Rent Assets - fungible assets, other than `Currency`, in which rent for
+		/// leased items can be paid
+		type RentAssets: traits::RentAssets<Self::AccountId>;
 	}
 
 	/// Details of a collection.
@@ -269,6 +290,58 @@ pub mod pallet {
 		ValueQuery, // default false
 	>;
 
+	/// Collection configuration - do item transfers need to be approved by the collection owner?
+	/// Added by Liberland
+	#[pallet::storage]
+	pub(super) type TransferApprovalRequired<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		bool,
+		ValueQuery, // default false
+	>;
+
+	/// Transfers awaiting approval of the collection owner.
+	/// Added by Liberland
+	#[pallet::storage]
+	pub type PendingTransfers<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		PendingTransferOf<T>,
+		OptionQuery,
+	>;
+
+	/// Number of entries in title history of given item.
+	/// Added by Liberland
+	#[pallet::storage]
+	pub type TitleHistoryLength<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Approved transfers of items, indexed by order of execution. Never removed, not even when
+	/// the item is burned.
+	/// Added by Liberland
+	#[pallet::storage]
+	pub type TitleHistory<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, T::ItemId>,
+			NMapKey<Twox64Concat, u32>,
+		),
+		TitleRecordOf<T>,
+		OptionQuery,
+	>;
+
 	/// The collections owned by any given account; set out this way so that collections owned by
 	/// a single account can be enumerated.
 	#[pallet::storage]
@@ -329,6 +402,83 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Items indexed by keys derived from their metadata by `MetadataValidator::index_keys`.
+	/// Added by Liberland
+	#[pallet::storage]
+	pub type MetadataIndex<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Twox64Concat, u64>,
+			NMapKey<Blake2_128Concat, T::ItemId>,
+		),
+		(),
+		OptionQuery,
+	>;
+
+	/// Keys under which an item is currently indexed in `MetadataIndex`.
+	/// Added by Liberland
+	#[pallet::storage]
+	pub type ItemMetadataIndexKeys<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		BoundedVec<u64, T::MaxMetadataIndexKeys>,
+		ValueQuery,
+	>;
+
+	/// Fractionalized items and assets representing their shares.
+	/// Added by Liberland
+	#[pallet::storage]
+	pub type ItemShares<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		FractionalizationOf<T, I>,
+		OptionQuery,
+	>;
+
+	/// Items represented by share assets, reverse of `ItemShares`.
+	/// Added by Liberland
+	#[pallet::storage]
+	pub type SharesItem<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ShareAssetIdOf<T, I>,
+		(T::CollectionId, T::ItemId),
+		OptionQuery,
+	>;
+
+	/// Leases offered by owners of items, awaiting acceptance by the lessee.
+	/// Added by Liberland
+	#[pallet::storage]
+	pub type LeaseOffers<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		LeaseOfferOf<T, I>,
+		OptionQuery,
+	>;
+
+	/// Accepted leases of items. Lapsed leases stay here until ended with `end_lease`.
+	/// Added by Liberland
+	#[pallet::storage]
+	pub type Leases<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		LeaseOf<T, I>,
+		OptionQuery,
+	>;
+
 	/// Attributes of a collection.
 	#[pallet::storage]
 	pub type Attribute<T: Config<I>, I: 'static = ()> = StorageNMap<
@@ -587,6 +737,71 @@ pub mod pallet {
 			attribute: PalletAttributes<T::CollectionId>,
 			value: BoundedVec<u8, T::ValueLimit>,
 		},
+		/// Transfer approval requirement was changed for the `collection`.
+		TransferApprovalRequiredSet { collection: T::CollectionId, required: bool },
+		/// Transfer of an `item` was requested and awaits approval.
+		TransferRequested {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			from: T::AccountId,
+			to: T::AccountId,
+		},
+		/// Pending transfer of an `item` was approved and executed.
+		TransferRequestApproved {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			approved_by: Option<T::AccountId>,
+		},
+		/// Pending transfer of an `item` was rejected or cancelled.
+		TransferRequestCancelled { collection: T::CollectionId, item: T::ItemId },
+		/// An `item` was subdivided into `parts`.
+		ItemSubdivided { collection: T::CollectionId, item: T::ItemId, parts: Vec<T::ItemId> },
+		/// `items` were merged into a `new_item`.
+		ItemsMerged { collection: T::CollectionId, items: Vec<T::ItemId>, new_item: T::ItemId },
+		/// An `item` was locked and `shares` of `asset` were minted to `beneficiary`.
+		ItemFractionalized {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			asset: ShareAssetIdOf<T, I>,
+			shares: ShareBalanceOf<T, I>,
+			beneficiary: T::AccountId,
+		},
+		/// All shares of an `item` were burned and the item was transferred to `beneficiary`.
+		ItemUnified {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			asset: ShareAssetIdOf<T, I>,
+			beneficiary: T::AccountId,
+		},
+		/// An item's owner offered a lease.
+		LeaseOffered {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			lessee: T::AccountId,
+			rent: RentOf<T, I>,
+			period: BlockNumberFor<T>,
+			periods: u32,
+		},
+		/// A lease offer was cancelled.
+		LeaseOfferCancelled { collection: T::CollectionId, item: T::ItemId },
+		/// A lease was accepted and the lessee became the user of the item.
+		LeaseStarted {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			lessee: T::AccountId,
+			paid_until: BlockNumberFor<T>,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// Rent for a leased item was paid.
+		RentPaid {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			payer: T::AccountId,
+			periods: u32,
+			paid_until: BlockNumberFor<T>,
+		},
+		/// A lease was ended.
+		LeaseEnded { collection: T::CollectionId, item: T::ItemId, lessee: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -681,6 +896,37 @@ pub mod pallet {
 		CollectionNotEmpty,
 		/// The witness data should be provided.
 		WitnessRequired,
+		/// Transfers in this collection require approval, use `transfer`.
+		TransferApprovalRequired,
+		/// There's already a pending transfer for this item.
+		TransferAlreadyPending,
+		/// There's no pending transfer for this item.
+		NoPendingTransfer,
+		/// Metadata would be indexed under more than `MaxMetadataIndexKeys` keys.
+		TooManyMetadataIndexKeys,
+		/// Too many items are indexed around the item to validate its metadata.
+		TooManyPlotsInCell,
+		/// Subdivision or merge needs at least 2 distinct parts.
+		NotEnoughParts,
+		/// Parts don't exactly cover the whole item.
+		InvalidTiling,
+		/// The item isn't fractionalized.
+		NotFractionalized,
+		/// The asset already represents shares of an item.
+		SharesAssetInUse,
+		/// Only the holder of all shares can redeem the item.
+		NotAllShares,
+		/// Can't fractionalize an item into zero shares.
+		ZeroShares,
+		/// Lease period and number of periods must be non-zero and paid rent can't extend past
+		/// the end of the lease.
+		InvalidLeaseTerms,
+		/// The item is currently leased.
+		ItemLeased,
+		/// The item isn't leased.
+		NotLeased,
+		/// There's no lease offered for the item.
+		NoLeaseOffer,
 	}
 
 	#[pallet::call]
@@ -978,7 +1224,9 @@ pub mod pallet {
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(5)]
-		#[pallet::weight(T::WeightInfo::burn())]
+		#[pallet::weight(T::WeightInfo::burn().saturating_add(
+			T::DbWeight::get().writes(T::MaxMetadataIndexKeys::get() as u64)
+		))]
 		pub fn burn(
 			origin: OriginFor<T>,
 			collection: T::CollectionId,
@@ -1021,6 +1269,10 @@ pub mod pallet {
 			let origin = ensure_signed(origin)?;
 			let dest = T::Lookup::lookup(dest)?;
 
+			if TransferApprovalRequired::<T, I>::get(collection) {
+				return Self::do_request_transfer(origin, collection, item, dest)
+			}
+
 			Self::do_transfer(collection, item, dest, |_, details| {
 				if details.owner != origin {
 					let deadline =
@@ -1563,7 +1815,18 @@ pub mod pallet {
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(24)]
-		#[pallet::weight(T::WeightInfo::set_metadata())]
+		#[pallet::weight(T::WeightInfo::set_metadata().saturating_add(
+			T::DbWeight::get().reads_writes(
+				2 * T::MaxMetadataIndexKeys::get() as u64,
+				2 * T::MaxMetadataIndexKeys::get() as u64,
+			)
+		).saturating_add(
+			Pallet::<T, I>::metadata_validation_weight()
+		).saturating_add(
+			T::WeightInfo::force_set_attribute()
+				.saturating_add(T::WeightInfo::clear_attribute())
+				.saturating_mul(T::MaxAttributesPerCall::get() as u64)
+		))]
 		pub fn set_metadata(
 			origin: OriginFor<T>,
 			collection: T::CollectionId,
@@ -1590,7 +1853,11 @@ pub mod pallet {
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(25)]
-		#[pallet::weight(T::WeightInfo::clear_metadata())]
+		#[pallet::weight(T::WeightInfo::clear_metadata().saturating_add(
+			T::DbWeight::get().writes(T::MaxMetadataIndexKeys::get() as u64)
+		).saturating_add(
+			T::WeightInfo::clear_attribute().saturating_mul(T::MaxAttributesPerCall::get() as u64)
+		))]
 		pub fn clear_metadata(
 			origin: OriginFor<T>,
 			collection: T::CollectionId,
@@ -1887,7 +2154,8 @@ pub mod pallet {
 		/// Emits `AttributeSet` if the attributes were provided.
 		/// Emits `ItemMetadataSet` if the metadata was not empty.
 		#[pallet::call_index(37)]
-		#[pallet::weight(T::WeightInfo::mint_pre_signed(mint_data.attributes.len() as u32))]
+		#[pallet::weight(T::WeightInfo::mint_pre_signed(mint_data.attributes.len() as u32)
+			.saturating_add(Pallet::<T, I>::metadata_validation_weight()))]
 		pub fn mint_pre_signed(
 			origin: OriginFor<T>,
 			mint_data: Box<PreSignedMintOf<T, I>>,
@@ -1952,6 +2220,340 @@ pub mod pallet {
 				maybe_check_owner,
 			)
 		}
+
+		/// Set whether transfers of items in collection need to be approved.
+		///
+		/// The origin must conform to `ForceOrigin` or must be `Signed` and the sender must be the
+		/// owner of the `collection`.
+		///
+		/// - `collection`: The collection to set requirement for
+		/// - `required`: If true, `transfer` only creates a pending transfer, which must be
+		///   approved with `approve_pending_transfer` by the collection owner. Other ways of
+		///   transferring items (buying, swaps) are disabled.
+		///
+		/// Emits `TransferApprovalRequiredSet`.
+		///
+		/// Added by Liberland
+		#[pallet::call_index(100)]
+		#[pallet::weight(T::WeightInfo::set_transfer_approval_required())]
+		pub fn set_transfer_approval_required(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			required: bool,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_set_transfer_approval_required(collection, required, maybe_check_owner)
+		}
+
+		/// Approve and execute pending transfer of an item.
+		///
+		/// The origin must conform to `ForceOrigin` or must be `Signed` and the sender must be the
+		/// owner of the `collection`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item with pending transfer.
+		///
+		/// Transfer is recorded in item's title history.
+		///
+		/// Emits `TransferRequestApproved` and `Transferred`.
+		///
+		/// Added by Liberland
+		#[pallet::call_index(101)]
+		#[pallet::weight(T::WeightInfo::approve_pending_transfer())]
+		pub fn approve_pending_transfer(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_approve_pending_transfer(collection, item, maybe_check_owner)
+		}
+
+		/// Cancel pending transfer of an item.
+		///
+		/// The origin must conform to `ForceOrigin` or must be `Signed` and the sender must be the
+		/// owner of the `collection`, the owner of the `item` or the requested recipient.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item with pending transfer.
+		///
+		/// Emits `TransferRequestCancelled`.
+		///
+		/// Added by Liberland
+		#[pallet::call_index(102)]
+		#[pallet::weight(T::WeightInfo::cancel_pending_transfer())]
+		pub fn cancel_pending_transfer(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_cancel_pending_transfer(collection, item, maybe_check_origin)
+		}
+
+		/// Subdivide an item into new items that exactly cover it.
+		///
+		/// The origin must conform to `ForceOrigin` or must be `Signed` and the sender must be the
+		/// Admin of the `collection`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to subdivide. Must have metadata.
+		/// - `parts`: Ids and metadata of new items. Checked with `MetadataValidator` to exactly
+		///   cover the `item`.
+		///
+		/// New items are minted to the owner of `item`. The `item` itself is kept for
+		/// provenance, but its transfers are disabled, metadata is locked and it's removed from
+		/// `MetadataIndex`. Links are recorded as `Children` and `Parents` pallet attributes.
+		///
+		/// Emits `ItemSubdivided`.
+		///
+		/// Added by Liberland
+		#[pallet::call_index(103)]
+		#[pallet::weight(Pallet::<T, I>::restructure_weight(parts.len() as u32))]
+		pub fn subdivide(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			parts: BoundedVec<(T::ItemId, BoundedVec<u8, T::StringLimit>), T::MaxItemParts>,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_subdivide(maybe_check_origin, collection, item, parts.into_inner())
+		}
+
+		/// Merge items with the same owner into a new item that exactly covers them.
+		///
+		/// The origin must conform to `ForceOrigin` or must be `Signed` and the sender must be the
+		/// Admin of the `collection`.
+		///
+		/// - `collection`: The collection of the items.
+		/// - `items`: The items to merge. Must have metadata.
+		/// - `new_item`: Id of the new item.
+		/// - `metadata`: Metadata of the new item. Checked with `MetadataValidator` to exactly
+		///   cover `items`.
+		///
+		/// New item is minted to the owner of `items`. The `items` are kept for provenance, but
+		/// their transfers are disabled, metadata is locked and they're removed from
+		/// `MetadataIndex`. Links are recorded as `Children` and `Parents` pallet attributes.
+		///
+		/// Emits `ItemsMerged`.
+		///
+		/// Added by Liberland
+		#[pallet::call_index(104)]
+		#[pallet::weight(Pallet::<T, I>::restructure_weight(items.len() as u32))]
+		pub fn merge(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			items: BoundedVec<T::ItemId, T::MaxItemParts>,
+			new_item: T::ItemId,
+			metadata: BoundedVec<u8, T::StringLimit>,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_merge(maybe_check_origin, collection, items.into_inner(), new_item, metadata)
+		}
+
+		/// Lock an item and issue fungible shares representing it.
+		///
+		/// The origin must be Signed and the sender must be the owner of the `item`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to fractionalize.
+		/// - `asset`: Id of the new share asset. Must not exist yet.
+		/// - `beneficiary`: The account receiving the shares.
+		/// - `shares`: Number of shares to mint.
+		///
+		/// Item stays with its owner, but its transfers are disabled until it's unified. If the
+		/// collection requires citizenship, so do the shares. Items in collections with
+		/// `TransferApprovalRequired` set and leased items can't be fractionalized.
+		///
+		/// Emits `ItemFractionalized`.
+		///
+		/// Added by Liberland
+		#[pallet::call_index(105)]
+		#[pallet::weight(T::WeightInfo::lock_item_transfer()
+			.saturating_add(<T::Shares as traits::Shares<T::AccountId>>::issue_weight())
+			// `TransferApprovalRequired` and `Leases`
+			.saturating_add(T::DbWeight::get().reads(2))
+		)]
+		pub fn fractionalize(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			asset: ShareAssetIdOf<T, I>,
+			beneficiary: AccountIdLookupOf<T>,
+			shares: ShareBalanceOf<T, I>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			Self::do_fractionalize(origin, collection, item, asset, beneficiary, shares)
+		}
+
+		/// Burn all shares of an item and take it over.
+		///
+		/// The origin must be Signed and the sender must hold all shares of the `item`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The fractionalized item.
+		/// - `beneficiary`: The account receiving the item.
+		///
+		/// Share asset is destroyed and item transfers are enabled again. Fails if the collection
+		/// started requiring transfer approval since the item was fractionalized, unless
+		/// `beneficiary` is the item's owner.
+		///
+		/// Emits `ItemUnified`.
+		///
+		/// Added by Liberland
+		#[pallet::call_index(106)]
+		#[pallet::weight(T::WeightInfo::unlock_item_transfer()
+			.saturating_add(T::WeightInfo::transfer())
+			.saturating_add(<T::Shares as traits::Shares<T::AccountId>>::redeem_weight())
+		)]
+		pub fn unify(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			beneficiary: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			Self::do_unify(origin, collection, item, beneficiary)
+		}
+
+		/// Offer a lease of an item to `lessee`.
+		///
+		/// The origin must be Signed and the sender must be the owner of the `item`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to be leased.
+		/// - `lessee`: The account that may accept the lease.
+		/// - `rent`: Rent due for every period, in the native currency or an asset.
+		/// - `period`: Length of a single lease period, in blocks.
+		/// - `periods`: Number of periods the lease lasts.
+		///
+		/// Replaces any previous offer for the item. Fails if the item is currently leased.
+		/// Leased items can't be transferred, fractionalized, subdivided or merged.
+		///
+		/// Emits `LeaseOffered`.
+		///
+		/// Added by Liberland
+		#[pallet::call_index(107)]
+		#[pallet::weight(T::WeightInfo::offer_lease())]
+		pub fn offer_lease(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			lessee: AccountIdLookupOf<T>,
+			rent: RentOf<T, I>,
+			period: BlockNumberFor<T>,
+			periods: u32,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let lessee = T::Lookup::lookup(lessee)?;
+			Self::do_offer_lease(origin, collection, item, lessee, rent, period, periods)
+		}
+
+		/// Cancel a lease offer.
+		///
+		/// The origin must be Signed and the sender must be the owner of the `item`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item with the lease offer.
+		///
+		/// Emits `LeaseOfferCancelled`.
+		///
+		/// Added by Liberland
+		#[pallet::call_index(108)]
+		#[pallet::weight(T::WeightInfo::cancel_lease_offer())]
+		pub fn cancel_lease_offer(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_cancel_lease_offer(origin, collection, item)
+		}
+
+		/// Accept a lease offer and pay the rent for the first period.
+		///
+		/// The origin must be Signed and the sender must be the lessee of the offer. If the
+		/// collection requires citizenship, so does the lessee.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The offered item.
+		///
+		/// Emits `LeaseStarted`.
+		///
+		/// Added by Liberland
+		#[pallet::call_index(109)]
+		#[pallet::weight(T::WeightInfo::accept_lease()
+			.saturating_add(<T::RentAssets as traits::RentAssets<T::AccountId>>::transfer_weight())
+		)]
+		pub fn accept_lease(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_accept_lease(origin, collection, item)
+		}
+
+		/// Pay rent for further periods of an active lease. Rent is paid to the current owner.
+		///
+		/// The origin must be Signed.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The leased item.
+		/// - `periods`: Number of periods to pay for. Can't extend past the end of the lease.
+		///
+		/// If rent isn't paid in time, the lessee loses the user role until the lease ends.
+		///
+		/// Emits `RentPaid`.
+		///
+		/// Added by Liberland
+		#[pallet::call_index(110)]
+		#[pallet::weight(T::WeightInfo::pay_rent()
+			.saturating_add(<T::RentAssets as traits::RentAssets<T::AccountId>>::transfer_weight())
+		)]
+		pub fn pay_rent(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			periods: u32,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_pay_rent(origin, collection, item, periods)
+		}
+
+		/// End a lease.
+		///
+		/// The origin must be Signed. Active leases can only be surrendered by the lessee, lapsed
+		/// ones can be removed by anyone.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The leased item.
+		///
+		/// Emits `LeaseEnded`.
+		///
+		/// Added by Liberland
+		#[pallet::call_index(111)]
+		#[pallet::weight(T::WeightInfo::end_lease())]
+		pub fn end_lease(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_end_lease(origin, collection, item)
+		}
 	}
 }
 
@@ -125,3 +125,69 @@ pub mod v1 {
 		}
 	}
 }
+
+pub mod v2 {
+	use frame_support::{pallet_prelude::*, traits::GetDefault, weights::Weight};
+
+	use super::*;
+
+	/// Requires transfer approval in existing collections provided by `Collections`, so that
+	/// their items can't be moved without the owner recording it in title history.
+	pub struct MigrateToV2<T, I = (), Collections = GetDefault>(
+		sp_std::marker::PhantomData<(T, I, Collections)>,
+	);
+	impl<T: Config<I>, I: 'static, Collections: Get<Vec<T::CollectionId>>> OnRuntimeUpgrade
+		for MigrateToV2<T, I, Collections>
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let current_version = Pallet::<T, I>::current_storage_version();
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version == 1 && current_version >= 2 {
+				let collections = Collections::get();
+				let checked = collections.len() as u64;
+				let mut updated = 0u64;
+				for collection in collections {
+					if Collection::<T, I>::contains_key(collection) {
+						TransferApprovalRequired::<T, I>::insert(collection, true);
+						updated.saturating_inc();
+					}
+				}
+				StorageVersion::new(2).put::<Pallet<T, I>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Required transfer approval in {} collections, storage to version 2",
+					updated,
+				);
+				T::DbWeight::get().reads_writes(checked + 1, updated + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			ensure!(Pallet::<T, I>::on_chain_storage_version() == 1, "must upgrade linearly");
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			for collection in Collections::get() {
+				ensure!(
+					!Collection::<T, I>::contains_key(collection)
+						|| TransferApprovalRequired::<T, I>::get(collection),
+					"every existing provided collection should require transfer approval"
+				);
+			}
+			ensure!(
+				Pallet::<T, I>::on_chain_storage_version() == 2,
+				"after migration, the onchain_version should be 2"
+			);
+			Ok(())
+		}
+	}
+}
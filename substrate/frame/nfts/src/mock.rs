@@ -29,12 +29,14 @@ use frame_support::{
 	construct_runtime, parameter_types,
 	BoundedVec,
 	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64},
+	weights::Weight,
 };
+use sp_std::collections::btree_map::BTreeMap;
 use sp_core::H256;
 use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
-	BuildStorage, MultiSignature,
+	BuildStorage, DispatchError, DispatchResult, MultiSignature,
 };
 
 pub struct DummyMetadataValidator;
@@ -43,7 +45,109 @@ impl<StringLimit> crate::traits::MetadataValidator<u32, u32, StringLimit> for Du
     fn validate_metadata(_: u32, i: u32, _: &BoundedVec<u8, StringLimit>) -> bool {
 		i != 9991999
     }
+
+	fn within_validation_limits(_: u32, i: u32, _: &BoundedVec<u8, StringLimit>) -> bool {
+		i != 9991998
+	}
+
+	// metadata starting with `idx` is indexed under each of the following bytes
+	fn index_keys(_: u32, _: u32, metadata: &BoundedVec<u8, StringLimit>) -> Vec<u64> {
+		match metadata.strip_prefix(b"idx") {
+			Some(keys) => keys.iter().map(|k| *k as u64).collect(),
+			None => Vec::new(),
+		}
+	}
+
+	// metadata length is treated as the area of an item
+	fn validate_tiling(
+		_: u32,
+		whole: &BoundedVec<u8, StringLimit>,
+		parts: &[BoundedVec<u8, StringLimit>],
+	) -> bool {
+		whole.len() == parts.iter().map(|p| p.len()).sum::<usize>()
+	}
+
+	// metadata starting with `area` gets its length as `area` attribute
+	fn derived_attributes(
+		_: u32,
+		_: u32,
+		metadata: &BoundedVec<u8, StringLimit>,
+	) -> Vec<(Vec<u8>, Vec<u8>)> {
+		if metadata.starts_with(b"area") {
+			vec![(b"area".to_vec(), (metadata.len() as u32).encode())]
+		} else {
+			Vec::new()
+		}
+	}
 }
+
+parameter_types! {
+	pub static SharesBalances: BTreeMap<(u32, AccountId), u128> = BTreeMap::new();
+	pub static SharesCitizenshipRequired: BTreeMap<u32, bool> = BTreeMap::new();
+}
+
+pub struct MockShares;
+
+impl crate::traits::Shares<AccountId> for MockShares {
+	type AssetId = u32;
+	type Balance = u128;
+
+	fn issue(
+		asset: u32,
+		beneficiary: &AccountId,
+		amount: u128,
+		citizenship_required: bool,
+	) -> DispatchResult {
+		if SharesCitizenshipRequired::get().contains_key(&asset) {
+			return Err(DispatchError::Other("AssetExists"))
+		}
+		SharesCitizenshipRequired::mutate(|r| r.insert(asset, citizenship_required));
+		SharesBalances::mutate(|b| b.insert((asset, beneficiary.clone()), amount));
+		Ok(())
+	}
+
+	fn holds_all(asset: u32, who: &AccountId) -> bool {
+		SharesBalances::get()
+			.iter()
+			.filter(|((a, _), balance)| *a == asset && **balance > 0)
+			.all(|((_, holder), _)| holder == who)
+	}
+
+	fn redeem(asset: u32, _: &AccountId) -> DispatchResult {
+		SharesCitizenshipRequired::mutate(|r| r.remove(&asset));
+		SharesBalances::mutate(|b| b.retain(|(a, _), _| *a != asset));
+		Ok(())
+	}
+
+	fn issue_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn redeem_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl crate::traits::RentAssets<AccountId> for MockShares {
+	type AssetId = u32;
+	type Balance = u128;
+
+	fn transfer(asset: u32, from: &AccountId, to: &AccountId, amount: u128) -> DispatchResult {
+		let from_balance = SharesBalances::get().get(&(asset, from.clone())).copied().unwrap_or(0);
+		let from_balance =
+			from_balance.checked_sub(amount).ok_or(DispatchError::Other("BalanceLow"))?;
+		SharesBalances::mutate(|b| {
+			b.insert((asset, from.clone()), from_balance);
+			*b.entry((asset, to.clone())).or_default() += amount;
+		});
+		Ok(())
+	}
+
+	fn transfer_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 type Block = frame_system::mocking::MockBlock<Test>;
 
 construct_runtime!(
@@ -142,6 +246,11 @@ impl Config for Test {
 	type Helper = ();
 	type Citizenship = MockCitizenshipChecker<Self::AccountId, MockCitizenOne, MockCitizenTwo>;
 	type MetadataValidator = DummyMetadataValidator;
+	type TransferApprover = ();
+	type Shares = MockShares;
+	type RentAssets = MockShares;
+	type MaxMetadataIndexKeys = ConstU32<16>;
+	type MaxItemParts = ConstU32<4>;
 }
 
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
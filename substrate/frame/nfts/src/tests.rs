@@ -3729,6 +3729,404 @@ fn citizenship_is_checked_when_set() {
 	})
 }
 
+#[test]
+fn set_transfer_approval_required_verifies_origin() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), account(1), default_collection_config()));
+		assert_eq!(TransferApprovalRequired::<Test>::get(0), false);
+		assert_ok!(Nfts::set_transfer_approval_required(RuntimeOrigin::signed(account(1)), 0, true));
+		assert_eq!(TransferApprovalRequired::<Test>::get(0), true);
+		assert_ok!(Nfts::set_transfer_approval_required(RuntimeOrigin::root(), 0, false));
+		assert_eq!(TransferApprovalRequired::<Test>::get(0), false);
+		assert_noop!(
+			Nfts::set_transfer_approval_required(RuntimeOrigin::signed(account(2)), 0, true),
+			Error::<Test>::NoPermission
+		);
+		assert!(events().contains(&Event::<Test>::TransferApprovalRequiredSet {
+			collection: 0,
+			required: false
+		}));
+	})
+}
+
+#[test]
+fn transfer_creates_pending_transfer_when_approval_required() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), account(1), default_collection_config()));
+		assert_ok!(Nfts::set_transfer_approval_required(RuntimeOrigin::signed(account(1)), 0, true));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 42, account(3)),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(3)));
+		assert_eq!(Item::<Test>::get(0, 42).unwrap().owner, account(2));
+		assert_eq!(
+			PendingTransfers::<Test>::get(0, 42),
+			Some(PendingTransfer { from: account(2), to: account(3), requested_at: 1 })
+		);
+		assert!(events().contains(&Event::<Test>::TransferRequested {
+			collection: 0,
+			item: 42,
+			from: account(2),
+			to: account(3),
+		}));
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(4)),
+			Error::<Test>::TransferAlreadyPending
+		);
+		assert_noop!(
+			Nfts::do_transfer(0, 42, account(4), |_, _| Ok(())),
+			Error::<Test>::TransferApprovalRequired
+		);
+	})
+}
+
+#[test]
+fn approve_pending_transfer_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), account(1), default_collection_config()));
+		assert_ok!(Nfts::set_transfer_approval_required(RuntimeOrigin::signed(account(1)), 0, true));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_noop!(
+			Nfts::approve_pending_transfer(RuntimeOrigin::signed(account(1)), 0, 42),
+			Error::<Test>::NoPendingTransfer
+		);
+
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(3)));
+		System::set_block_number(5);
+		assert_noop!(
+			Nfts::approve_pending_transfer(RuntimeOrigin::signed(account(2)), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::approve_pending_transfer(RuntimeOrigin::signed(account(1)), 0, 42));
+		assert_eq!(Item::<Test>::get(0, 42).unwrap().owner, account(3));
+		assert_eq!(PendingTransfers::<Test>::get(0, 42), None);
+		assert!(events().contains(&Event::<Test>::TransferRequestApproved {
+			collection: 0,
+			item: 42,
+			approved_by: Some(account(1)),
+		}));
+
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 42, account(4)));
+		System::set_block_number(7);
+		assert_ok!(Nfts::approve_pending_transfer(RuntimeOrigin::root(), 0, 42));
+		assert_eq!(Item::<Test>::get(0, 42).unwrap().owner, account(4));
+
+		assert_eq!(
+			Nfts::title_history(0, 42),
+			vec![
+				TitleRecord {
+					from: account(2),
+					to: account(3),
+					requested_at: 1,
+					approved_at: 5,
+					approved_by: Some(account(1)),
+				},
+				TitleRecord {
+					from: account(3),
+					to: account(4),
+					requested_at: 5,
+					approved_at: 7,
+					approved_by: None,
+				},
+			]
+		);
+
+		// history is kept after burn
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(4)), 0, 42));
+		assert_eq!(Nfts::title_history(0, 42).len(), 2);
+	})
+}
+
+#[test]
+fn cancel_pending_transfer_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), account(1), default_collection_config()));
+		assert_ok!(Nfts::set_transfer_approval_required(RuntimeOrigin::signed(account(1)), 0, true));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(3)));
+
+		assert_noop!(
+			Nfts::cancel_pending_transfer(RuntimeOrigin::signed(account(4)), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::cancel_pending_transfer(RuntimeOrigin::signed(account(3)), 0, 42));
+		assert_eq!(PendingTransfers::<Test>::get(0, 42), None);
+		assert!(events()
+			.contains(&Event::<Test>::TransferRequestCancelled { collection: 0, item: 42 }));
+		assert_noop!(
+			Nfts::cancel_pending_transfer(RuntimeOrigin::signed(account(1)), 0, 42),
+			Error::<Test>::NoPendingTransfer
+		);
+
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(3)));
+		assert_ok!(Nfts::cancel_pending_transfer(RuntimeOrigin::signed(account(1)), 0, 42));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(3)));
+		assert_ok!(Nfts::cancel_pending_transfer(RuntimeOrigin::signed(account(2)), 0, 42));
+		assert_eq!(Item::<Test>::get(0, 42).unwrap().owner, account(2));
+		assert_eq!(Nfts::title_history(0, 42), vec![]);
+	})
+}
+
+#[test]
+fn metadata_index_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 43, account(1), None));
+		assert_eq!(Balances::free_balance(&account(1)), 98);
+
+		// index keys are paid for by the deposit
+		assert_ok!(Nfts::set_metadata(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			bvec![b'i', b'd', b'x', 1, 2]
+		));
+		assert_eq!(Balances::free_balance(&account(1)), 76);
+		assert_ok!(Nfts::set_metadata(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			43,
+			bvec![b'i', b'd', b'x', 2]
+		));
+		assert_eq!(Nfts::indexed_items(0, 1), vec![42]);
+		assert_eq!(Nfts::indexed_items(0, 2).len(), 2);
+
+		// updating metadata replaces keys
+		assert_ok!(Nfts::set_metadata(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			bvec![b'i', b'd', b'x', 3]
+		));
+		assert_eq!(Nfts::indexed_items(0, 1), vec![]);
+		assert_eq!(Nfts::indexed_items(0, 2), vec![43]);
+		assert_eq!(Nfts::indexed_items(0, 3), vec![42]);
+
+		assert_noop!(
+			Nfts::set_metadata(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				42,
+				bvec![b'i', b'd', b'x', 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17]
+			),
+			Error::<Test>::TooManyMetadataIndexKeys
+		);
+
+		assert_ok!(Nfts::clear_metadata(RuntimeOrigin::signed(account(1)), 0, 42));
+		assert_eq!(Nfts::indexed_items(0, 3), vec![]);
+		assert_eq!(ItemMetadataIndexKeys::<Test>::get(0, 42).len(), 0);
+
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 43));
+		assert_eq!(Nfts::indexed_items(0, 2), vec![]);
+		assert_eq!(ItemMetadataIndexKeys::<Test>::get(0, 43).len(), 0);
+	})
+}
+
+#[test]
+fn derived_attributes_follow_metadata() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
+
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 10]));
+		assert_eq!(Nfts::derived_attribute(0, 42, b"area".to_vec()), None);
+		let free_balance = Balances::free_balance(&account(1));
+
+		// derived attributes don't require a deposit
+		assert_ok!(Nfts::set_metadata(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			bvec![b'a', b'r', b'e', b'a', 0, 0, 0, 0, 0, 0]
+		));
+		assert_eq!(Nfts::derived_attribute(0, 42, b"area".to_vec()), Some(10u32.encode()));
+		assert_eq!(Balances::free_balance(&account(1)), free_balance);
+		assert!(events().contains(&Event::<Test>::AttributeSet {
+			collection: 0,
+			maybe_item: Some(42),
+			key: bvec![b'a', b'r', b'e', b'a'],
+			value: 10u32.encode().try_into().unwrap(),
+			namespace: AttributeNamespace::Pallet,
+		}));
+
+		// updated with metadata
+		assert_ok!(Nfts::set_metadata(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			bvec![b'a', b'r', b'e', b'a', 0]
+		));
+		assert_eq!(Nfts::derived_attribute(0, 42, b"area".to_vec()), Some(5u32.encode()));
+
+		// removed when no longer derived
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 10]));
+		assert_eq!(Nfts::derived_attribute(0, 42, b"area".to_vec()), None);
+
+		// removed with metadata
+		assert_ok!(Nfts::set_metadata(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			bvec![b'a', b'r', b'e', b'a']
+		));
+		assert_eq!(Nfts::derived_attribute(0, 42, b"area".to_vec()), Some(4u32.encode()));
+		assert_ok!(Nfts::clear_metadata(RuntimeOrigin::signed(account(1)), 0, 42));
+		assert_eq!(Nfts::derived_attribute(0, 42, b"area".to_vec()), None);
+		assert_eq!(Collection::<Test>::get(0).unwrap().attributes, 0);
+	})
+}
+
+fn item_links(item: u32, attribute: PalletAttributes<u32>) -> Option<Vec<u32>> {
+	let key = Nfts::construct_attribute_key(attribute.encode()).unwrap();
+	Attribute::<Test>::get((0, Some(item), AttributeNamespace::Pallet, key))
+		.map(|(value, _)| Decode::decode(&mut &value[..]).unwrap())
+}
+
+#[test]
+fn subdivide_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 4]));
+
+		assert_noop!(
+			Nfts::subdivide(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				42,
+				bvec![(43, bvec![0u8; 1]), (44, bvec![0u8; 3])]
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::subdivide(RuntimeOrigin::signed(account(1)), 0, 42, bvec![(43, bvec![0u8; 4])]),
+			Error::<Test>::NotEnoughParts
+		);
+		assert_noop!(
+			Nfts::subdivide(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				42,
+				bvec![(43, bvec![0u8; 1]), (44, bvec![0u8; 2])]
+			),
+			Error::<Test>::InvalidTiling
+		);
+
+		assert_ok!(Nfts::subdivide(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			bvec![(43, bvec![0u8; 1]), (44, bvec![0u8; 3])]
+		));
+		assert!(events().contains(&Event::<Test>::ItemSubdivided {
+			collection: 0,
+			item: 42,
+			parts: vec![43, 44]
+		}));
+		assert_eq!(Nfts::owner(0, 43), Some(account(2)));
+		assert_eq!(Nfts::owner(0, 44), Some(account(2)));
+		assert_eq!(Nfts::item_metadata(0, 44).unwrap().len(), 3);
+		assert_eq!(item_links(42, PalletAttributes::Children), Some(vec![43, 44]));
+		assert_eq!(item_links(43, PalletAttributes::Parents), Some(vec![42]));
+		assert_eq!(item_links(44, PalletAttributes::Parents), Some(vec![42]));
+
+		// original is kept, but can't be used anymore
+		assert_eq!(Nfts::owner(0, 42), Some(account(2)));
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(3)),
+			Error::<Test>::ItemLocked
+		);
+		assert_noop!(
+			Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 4]),
+			Error::<Test>::LockedItemMetadata
+		);
+		assert_noop!(
+			Nfts::subdivide(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				42,
+				bvec![(45, bvec![0u8; 1]), (46, bvec![0u8; 3])]
+			),
+			Error::<Test>::ItemLocked
+		);
+	})
+}
+
+#[test]
+fn merge_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 43, account(2), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 44, account(3), None));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 2]));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 43, bvec![0u8; 3]));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 44, bvec![0u8; 1]));
+
+		assert_noop!(
+			Nfts::merge(RuntimeOrigin::signed(account(2)), 0, bvec![42, 43], 45, bvec![0u8; 5]),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::merge(RuntimeOrigin::signed(account(1)), 0, bvec![42, 44], 45, bvec![0u8; 3]),
+			Error::<Test>::WrongOwner
+		);
+		assert_noop!(
+			Nfts::merge(RuntimeOrigin::signed(account(1)), 0, bvec![42, 42], 45, bvec![0u8; 4]),
+			Error::<Test>::NotEnoughParts
+		);
+		assert_noop!(
+			Nfts::merge(RuntimeOrigin::signed(account(1)), 0, bvec![42, 43], 45, bvec![0u8; 4]),
+			Error::<Test>::InvalidTiling
+		);
+
+		assert_ok!(Nfts::merge(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			bvec![42, 43],
+			45,
+			bvec![0u8; 5]
+		));
+		assert!(events().contains(&Event::<Test>::ItemsMerged {
+			collection: 0,
+			items: vec![42, 43],
+			new_item: 45
+		}));
+		assert_eq!(Nfts::owner(0, 45), Some(account(2)));
+		assert_eq!(item_links(45, PalletAttributes::Parents), Some(vec![42, 43]));
+		assert_eq!(item_links(42, PalletAttributes::Children), Some(vec![45]));
+		assert_eq!(item_links(43, PalletAttributes::Children), Some(vec![45]));
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 43, account(3)),
+			Error::<Test>::ItemLocked
+		);
+	})
+}
+
 #[test]
 fn metadata_validator_works() {
 	new_test_ext().execute_with(|| {
@@ -3745,6 +4143,11 @@ fn metadata_validator_works() {
 			Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 9991999, bvec![0u8; 20]),
 			Error::<Test>::IncorrectData,
 		);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 9991998, account(1), None));
+		assert_noop!(
+			Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 9991998, bvec![0u8; 20]),
+			Error::<Test>::TooManyPlotsInCell,
+		);
 		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 20]));
 	})
 }
@@ -3786,3 +4189,297 @@ fn basic_create_collection_with_id_should_work() {
 		);
 	});
 }
+
+#[test]
+fn fractionalize_and_unify_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), account(1), default_collection_config()));
+		assert_ok!(Nfts::set_citizenship_required(RuntimeOrigin::signed(account(1)), 0, true));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(100), None));
+
+		assert_noop!(
+			Nfts::fractionalize(RuntimeOrigin::signed(account(101)), 0, 42, 7, account(101), 100),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::fractionalize(RuntimeOrigin::signed(account(100)), 0, 42, 7, account(101), 0),
+			Error::<Test>::ZeroShares
+		);
+		assert_ok!(Nfts::fractionalize(
+			RuntimeOrigin::signed(account(100)),
+			0,
+			42,
+			7,
+			account(101),
+			100
+		));
+		assert!(events().contains(&Event::<Test>::ItemFractionalized {
+			collection: 0,
+			item: 42,
+			asset: 7,
+			shares: 100,
+			beneficiary: account(101),
+		}));
+		assert_eq!(Nfts::item_shares(0, 42), Some(7));
+		assert_eq!(Nfts::shares_item(7), Some((0, 42)));
+		assert_eq!(SharesBalances::get().get(&(7, account(101))), Some(&100));
+		// shares inherit the citizenship requirement
+		assert_eq!(SharesCitizenshipRequired::get().get(&7), Some(&true));
+
+		// item is locked
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(account(100)), 0, 42, account(101)),
+			Error::<Test>::ItemLocked
+		);
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(account(100)), 0, 42),
+			Error::<Test>::ItemLocked
+		);
+		assert_noop!(
+			Nfts::fractionalize(RuntimeOrigin::signed(account(100)), 0, 42, 8, account(100), 1),
+			Error::<Test>::ItemLocked
+		);
+
+		// only holder of all shares can unify
+		SharesBalances::mutate(|b| {
+			b.insert((7, account(101)), 60);
+			b.insert((7, account(100)), 40);
+		});
+		assert_noop!(
+			Nfts::unify(RuntimeOrigin::signed(account(101)), 0, 42, account(101)),
+			Error::<Test>::NotAllShares
+		);
+		SharesBalances::mutate(|b| {
+			b.insert((7, account(101)), 100);
+			b.insert((7, account(100)), 0);
+		});
+		assert_ok!(Nfts::unify(RuntimeOrigin::signed(account(101)), 0, 42, account(101)));
+		assert!(events().contains(&Event::<Test>::ItemUnified {
+			collection: 0,
+			item: 42,
+			asset: 7,
+			beneficiary: account(101),
+		}));
+		assert_eq!(Nfts::owner(0, 42), Some(account(101)));
+		assert_eq!(Nfts::item_shares(0, 42), None);
+		assert_eq!(Nfts::shares_item(7), None);
+		assert!(SharesBalances::get().is_empty());
+		assert_noop!(
+			Nfts::unify(RuntimeOrigin::signed(account(101)), 0, 42, account(101)),
+			Error::<Test>::NotFractionalized
+		);
+
+		// transferable again
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(101)), 0, 42, account(100)));
+	})
+}
+
+#[test]
+fn fractionalization_respects_transfer_approval() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), account(1), default_collection_config()));
+		assert_ok!(Nfts::set_transfer_approval_required(RuntimeOrigin::signed(account(1)), 0, true));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_noop!(
+			Nfts::fractionalize(RuntimeOrigin::signed(account(2)), 0, 42, 7, account(3), 10),
+			Error::<Test>::TransferApprovalRequired
+		);
+
+		assert_ok!(Nfts::set_transfer_approval_required(RuntimeOrigin::signed(account(1)), 0, false));
+		assert_ok!(Nfts::fractionalize(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			42,
+			7,
+			account(3),
+			10
+		));
+		assert_eq!(SharesCitizenshipRequired::get().get(&7), Some(&false));
+
+		// approval required since fractionalization, so the item can only be unified in place
+		assert_ok!(Nfts::set_transfer_approval_required(RuntimeOrigin::signed(account(1)), 0, true));
+		assert_noop!(
+			Nfts::unify(RuntimeOrigin::signed(account(3)), 0, 42, account(3)),
+			Error::<Test>::TransferApprovalRequired
+		);
+		assert_ok!(Nfts::unify(RuntimeOrigin::signed(account(3)), 0, 42, account(2)));
+		assert_eq!(Nfts::owner(0, 42), Some(account(2)));
+		assert!(Nfts::title_history(0, 42).is_empty());
+	})
+}
+
+#[test]
+fn lease_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(101), 100);
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), account(1), default_collection_config()));
+		assert_ok!(Nfts::set_citizenship_required(RuntimeOrigin::signed(account(1)), 0, true));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(100), None));
+
+		assert_noop!(
+			Nfts::offer_lease(RuntimeOrigin::signed(account(101)), 0, 42, account(101), Rent::Native(10), 10, 3),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::offer_lease(RuntimeOrigin::signed(account(100)), 0, 42, account(101), Rent::Native(10), 0, 3),
+			Error::<Test>::InvalidLeaseTerms
+		);
+
+		// lessee must be a citizen
+		assert_ok!(Nfts::offer_lease(RuntimeOrigin::signed(account(100)), 0, 42, account(1), Rent::Native(10), 10, 3));
+		assert_noop!(
+			Nfts::accept_lease(RuntimeOrigin::signed(account(1)), 0, 42),
+			DispatchError::Other("NotCitizen")
+		);
+		assert_ok!(Nfts::cancel_lease_offer(RuntimeOrigin::signed(account(100)), 0, 42));
+		assert_eq!(Nfts::lease_offer(0, 42), None);
+
+		assert_ok!(Nfts::offer_lease(RuntimeOrigin::signed(account(100)), 0, 42, account(101), Rent::Native(10), 10, 3));
+		assert!(events().contains(&Event::<Test>::LeaseOffered {
+			collection: 0,
+			item: 42,
+			lessee: account(101),
+			rent: Rent::Native(10),
+			period: 10,
+			periods: 3,
+		}));
+		assert_noop!(
+			Nfts::accept_lease(RuntimeOrigin::signed(account(102)), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::accept_lease(RuntimeOrigin::signed(account(101)), 0, 42));
+		assert!(events().contains(&Event::<Test>::LeaseStarted {
+			collection: 0,
+			item: 42,
+			lessee: account(101),
+			paid_until: 11,
+			expires_at: 31,
+		}));
+		assert_eq!(Balances::free_balance(&account(101)), 90);
+		assert_eq!(Balances::free_balance(&account(100)), 10);
+		assert_eq!(Nfts::item_user(0, 42), Some(account(101)));
+		assert_eq!(Nfts::owner(0, 42), Some(account(100)));
+
+		// leased items can't be burned, fractionalized, restructured or leased again
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(account(100)), 0, 42),
+			Error::<Test>::ItemLeased
+		);
+		assert_noop!(
+			Nfts::subdivide(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				42,
+				bvec![(43, bvec![0u8; 1]), (44, bvec![0u8; 1])]
+			),
+			Error::<Test>::ItemLeased
+		);
+		assert_noop!(
+			Nfts::merge(RuntimeOrigin::signed(account(1)), 0, bvec![42, 43], 45, bvec![0u8; 2]),
+			Error::<Test>::ItemLeased
+		);
+		assert_noop!(
+			Nfts::fractionalize(RuntimeOrigin::signed(account(100)), 0, 42, 7, account(100), 10),
+			Error::<Test>::ItemLeased
+		);
+		assert_noop!(
+			Nfts::offer_lease(RuntimeOrigin::signed(account(100)), 0, 42, account(102), Rent::Native(10), 10, 3),
+			Error::<Test>::ItemLeased
+		);
+		// only lessee can end an active lease
+		assert_noop!(
+			Nfts::end_lease(RuntimeOrigin::signed(account(100)), 0, 42),
+			Error::<Test>::NoPermission
+		);
+
+		// rent can't be paid past the end of the lease
+		assert_noop!(
+			Nfts::pay_rent(RuntimeOrigin::signed(account(101)), 0, 42, 3),
+			Error::<Test>::InvalidLeaseTerms
+		);
+		assert_ok!(Nfts::pay_rent(RuntimeOrigin::signed(account(101)), 0, 42, 1));
+		assert!(events().contains(&Event::<Test>::RentPaid {
+			collection: 0,
+			item: 42,
+			payer: account(101),
+			periods: 1,
+			paid_until: 21,
+		}));
+		assert_eq!(Balances::free_balance(&account(100)), 20);
+
+		// user role lapses when rent isn't paid
+		System::set_block_number(20);
+		assert_eq!(Nfts::item_user(0, 42), Some(account(101)));
+		System::set_block_number(21);
+		assert_eq!(Nfts::item_user(0, 42), None);
+		assert_noop!(
+			Nfts::pay_rent(RuntimeOrigin::signed(account(101)), 0, 42, 1),
+			Error::<Test>::NotLeased
+		);
+
+		// lapsed lease can be ended by anyone
+		assert_ok!(Nfts::end_lease(RuntimeOrigin::signed(account(102)), 0, 42));
+		assert!(events().contains(&Event::<Test>::LeaseEnded {
+			collection: 0,
+			item: 42,
+			lessee: account(101),
+		}));
+		assert_eq!(Nfts::lease(0, 42), None);
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(100)), 0, 42));
+	});
+}
+
+#[test]
+fn lease_offer_is_dropped_on_transfer() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(101), 100);
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), account(1), default_collection_config()));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(100), None));
+
+		assert_ok!(Nfts::offer_lease(RuntimeOrigin::signed(account(100)), 0, 42, account(101), Rent::Native(10), 10, 3));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(100)), 0, 42, account(102)));
+		assert_eq!(Nfts::lease_offer(0, 42), None);
+		assert_noop!(
+			Nfts::accept_lease(RuntimeOrigin::signed(account(101)), 0, 42),
+			Error::<Test>::NoLeaseOffer
+		);
+		assert_eq!(Balances::free_balance(&account(101)), 100);
+		assert_eq!(Nfts::item_user(0, 42), None);
+
+		// leased items can't be transferred, lapsed leases are dropped on transfer
+		assert_ok!(Nfts::offer_lease(RuntimeOrigin::signed(account(102)), 0, 42, account(101), Rent::Native(10), 10, 3));
+		assert_ok!(Nfts::accept_lease(RuntimeOrigin::signed(account(101)), 0, 42));
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(account(102)), 0, 42, account(100)),
+			Error::<Test>::ItemLeased
+		);
+		System::set_block_number(System::block_number() + 10);
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(102)), 0, 42, account(100)));
+		assert_eq!(Nfts::lease(0, 42), None);
+	});
+}
+
+#[test]
+fn lease_rent_in_asset_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), account(1), default_collection_config()));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(100), None));
+		SharesBalances::mutate(|b| b.insert((7, account(101)), 25));
+
+		assert_ok!(Nfts::offer_lease(RuntimeOrigin::signed(account(100)), 0, 42, account(101), Rent::Asset(7, 10), 10, 3));
+		assert_ok!(Nfts::accept_lease(RuntimeOrigin::signed(account(101)), 0, 42));
+		assert_eq!(SharesBalances::get().get(&(7, account(100))), Some(&10));
+
+		assert_noop!(
+			Nfts::pay_rent(RuntimeOrigin::signed(account(101)), 0, 42, 2),
+			DispatchError::Other("BalanceLow")
+		);
+		assert_ok!(Nfts::pay_rent(RuntimeOrigin::signed(account(101)), 0, 42, 1));
+		assert_eq!(SharesBalances::get().get(&(7, account(100))), Some(&20));
+		assert_eq!(SharesBalances::get().get(&(7, account(101))), Some(&5));
+
+		// lessee can surrender the lease
+		assert_ok!(Nfts::end_lease(RuntimeOrigin::signed(account(101)), 0, 42));
+		assert_eq!(Nfts::item_user(0, 42), None);
+	});
+}
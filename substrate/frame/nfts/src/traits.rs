@@ -1,4 +1,9 @@
-use frame_support::BoundedVec;
+use frame_support::{
+	pallet_prelude::{DispatchError, DispatchResult, MaxEncodedLen, Parameter, Weight},
+	BoundedVec,
+};
+use sp_runtime::traits::AtLeast32BitUnsigned;
+use sp_std::vec::Vec;
 
 pub trait MetadataValidator<CollectionId, ItemId, StringLimit> {
 	fn validate_metadata(
@@ -6,6 +11,52 @@ pub trait MetadataValidator<CollectionId, ItemId, StringLimit> {
 		item: ItemId,
 		metadata: &BoundedVec<u8, StringLimit>,
 	) -> bool;
+
+	/// Checks if the item's metadata can be validated within `max_validation_reads`, e.g. if
+	/// the item doesn't have too many indexed neighbors. Checked before `validate_metadata`.
+	fn within_validation_limits(
+		_collection: CollectionId,
+		_item: ItemId,
+		_metadata: &BoundedVec<u8, StringLimit>,
+	) -> bool {
+		true
+	}
+
+	/// Keys under which the item should be indexed in `MetadataIndex`, based on its (already
+	/// validated) metadata. Each key is paid for by the metadata deposit.
+	fn index_keys(
+		_collection: CollectionId,
+		_item: ItemId,
+		_metadata: &BoundedVec<u8, StringLimit>,
+	) -> Vec<u64> {
+		Vec::new()
+	}
+
+	/// Checks if items with `parts` metadata exactly cover an item with `whole` metadata. Used
+	/// when subdividing and merging items.
+	fn validate_tiling(
+		_collection: CollectionId,
+		_whole: &BoundedVec<u8, StringLimit>,
+		_parts: &[BoundedVec<u8, StringLimit>],
+	) -> bool {
+		true
+	}
+
+	/// System attributes derived from the item's (already validated) metadata, as `(key, value)`
+	/// pairs. They're kept in sync with the metadata and don't require a deposit.
+	fn derived_attributes(
+		_collection: CollectionId,
+		_item: ItemId,
+		_metadata: &BoundedVec<u8, StringLimit>,
+	) -> Vec<(Vec<u8>, Vec<u8>)> {
+		Vec::new()
+	}
+
+	/// Upper bound of storage reads done by `validate_metadata`. Charged by calls that set
+	/// metadata.
+	fn max_validation_reads() -> u32 {
+		0
+	}
 }
 
 impl<CollectionId, ItemId, StringLimit> MetadataValidator<CollectionId, ItemId, StringLimit>
@@ -15,3 +66,99 @@ impl<CollectionId, ItemId, StringLimit> MetadataValidator<CollectionId, ItemId,
 		true
 	}
 }
+
+/// Resolves the account recorded as approver of a transfer in the item's title history.
+///
+/// Used when the approving origin is a shared account (e.g. an office) that acts on behalf of
+/// an individual clerk.
+pub trait TransferApprover<AccountId> {
+	fn approver(origin: &AccountId) -> AccountId;
+}
+
+impl<AccountId: Clone> TransferApprover<AccountId> for () {
+	fn approver(origin: &AccountId) -> AccountId {
+		origin.clone()
+	}
+}
+
+/// Fungible assets representing shares of fractionalized items.
+pub trait Shares<AccountId> {
+	type AssetId: Parameter + MaxEncodedLen + Copy;
+	type Balance: Parameter + MaxEncodedLen + Copy + AtLeast32BitUnsigned;
+
+	/// Create `asset` and mint `amount` of it to `beneficiary`. If `citizenship_required`, only
+	/// accounts allowed to own land NFTs can hold the asset.
+	fn issue(
+		asset: Self::AssetId,
+		beneficiary: &AccountId,
+		amount: Self::Balance,
+		citizenship_required: bool,
+	) -> DispatchResult;
+
+	/// Checks if `who` holds the whole supply of `asset`.
+	fn holds_all(asset: Self::AssetId, who: &AccountId) -> bool;
+
+	/// Burn the whole supply of `asset`, held by `who`, and destroy the asset.
+	fn redeem(asset: Self::AssetId, who: &AccountId) -> DispatchResult;
+
+	/// Weight of `issue`.
+	fn issue_weight() -> Weight;
+
+	/// Weight of `holds_all` and `redeem`.
+	fn redeem_weight() -> Weight;
+}
+
+impl<AccountId> Shares<AccountId> for () {
+	type AssetId = u32;
+	type Balance = u128;
+
+	fn issue(_: u32, _: &AccountId, _: u128, _: bool) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn holds_all(_: u32, _: &AccountId) -> bool {
+		false
+	}
+
+	fn redeem(_: u32, _: &AccountId) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn issue_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn redeem_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Fungible assets, other than `Currency`, that can be used to pay rent for leased items.
+pub trait RentAssets<AccountId> {
+	type AssetId: Parameter + MaxEncodedLen + Copy;
+	type Balance: Parameter + MaxEncodedLen + Copy + AtLeast32BitUnsigned;
+
+	/// Transfer `amount` of `asset` from `from` to `to`.
+	fn transfer(
+		asset: Self::AssetId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
+	/// Weight of `transfer`.
+	fn transfer_weight() -> Weight;
+}
+
+impl<AccountId> RentAssets<AccountId> for () {
+	type AssetId = u32;
+	type Balance = u128;
+
+	fn transfer(_: u32, _: &AccountId, _: &AccountId, _: u128) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn transfer_weight() -> Weight {
+		Weight::zero()
+	}
+}
@@ -18,7 +18,10 @@
 //! This module contains various basic types and data structures used in the NFTs pallet.
 
 use super::*;
-use crate::macros::*;
+use crate::{
+	macros::*,
+	traits::{RentAssets, Shares},
+};
 use codec::EncodeLike;
 use enumflags2::{bitflags, BitFlags};
 use frame_support::{
@@ -41,6 +44,35 @@ pub(super) type ApprovalsOf<T, I = ()> = BoundedBTreeMap<
 	Option<BlockNumberFor<T>>,
 	<T as Config<I>>::ApprovalsLimit,
 >;
+/// A type alias for a transfer awaiting approval.
+pub(super) type PendingTransferOf<T> =
+	PendingTransfer<<T as SystemConfig>::AccountId, BlockNumberFor<T>>;
+/// A type alias for a single entry in an item's title history.
+pub type TitleRecordOf<T> = TitleRecord<<T as SystemConfig>::AccountId, BlockNumberFor<T>>;
+/// A type alias for the asset representing shares of fractionalized items.
+pub type ShareAssetIdOf<T, I = ()> =
+	<<T as Config<I>>::Shares as Shares<<T as SystemConfig>::AccountId>>::AssetId;
+/// A type alias for the balance of shares of fractionalized items.
+pub type ShareBalanceOf<T, I = ()> =
+	<<T as Config<I>>::Shares as Shares<<T as SystemConfig>::AccountId>>::Balance;
+/// A type alias for the fractionalization details of an item.
+pub(super) type FractionalizationOf<T, I = ()> =
+	Fractionalization<ShareAssetIdOf<T, I>, ShareBalanceOf<T, I>>;
+/// A type alias for the assets in which rent can be paid.
+pub type RentAssetIdOf<T, I = ()> =
+	<<T as Config<I>>::RentAssets as RentAssets<<T as SystemConfig>::AccountId>>::AssetId;
+/// A type alias for the balance of assets in which rent can be paid.
+pub type RentAssetBalanceOf<T, I = ()> =
+	<<T as Config<I>>::RentAssets as RentAssets<<T as SystemConfig>::AccountId>>::Balance;
+/// A type alias for the rent of a leased item.
+pub(super) type RentOf<T, I = ()> =
+	Rent<BalanceOf<T, I>, RentAssetIdOf<T, I>, RentAssetBalanceOf<T, I>>;
+/// A type alias for a lease offered by an item's owner.
+pub(super) type LeaseOfferOf<T, I = ()> =
+	LeaseOffer<<T as SystemConfig>::AccountId, RentOf<T, I>, BlockNumberFor<T>>;
+/// A type alias for an accepted lease of an item.
+pub(super) type LeaseOf<T, I = ()> =
+	Lease<<T as SystemConfig>::AccountId, RentOf<T, I>, BlockNumberFor<T>>;
 /// A type alias for keeping track of approvals for an item's attributes.
 pub(super) type ItemAttributesApprovals<T, I = ()> =
 	BoundedBTreeSet<<T as SystemConfig>::AccountId, <T as Config<I>>::ItemAttributesApprovalsLimit>;
@@ -357,6 +389,13 @@ pub enum PalletAttributes<CollectionId> {
 	UsedToClaim(CollectionId),
 	/// Marks an item as being restricted from transferring.
 	TransferDisabled,
+	/// Items this item was subdivided or merged into. Value is an encoded `Vec<ItemId>`.
+	/// Added by Liberland
+	Children,
+	/// Items this item was created from by subdivision or merge. Value is an encoded
+	/// `Vec<ItemId>`.
+	/// Added by Liberland
+	Parents,
 }
 
 /// Collection's configuration.
@@ -545,3 +584,88 @@ pub struct PreSignedAttributes<CollectionId, ItemId, AccountId, Deadline> {
 	/// A deadline for the signature.
 	pub(super) deadline: Deadline,
 }
+
+/// Transfer of an item in a collection with `TransferApprovalRequired` set, awaiting approval.
+///
+/// Added by Liberland
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingTransfer<AccountId, BlockNumber> {
+	/// Owner of the item at the time the transfer was requested.
+	pub from: AccountId,
+	/// Account that will receive the item once the transfer is approved.
+	pub to: AccountId,
+	/// Block at which the transfer was requested.
+	pub requested_at: BlockNumber,
+}
+
+/// An approved change of ownership of an item.
+///
+/// Added by Liberland
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TitleRecord<AccountId, BlockNumber> {
+	/// Previous owner.
+	pub from: AccountId,
+	/// New owner.
+	pub to: AccountId,
+	/// Block at which the transfer was requested.
+	pub requested_at: BlockNumber,
+	/// Block at which the transfer was approved and executed.
+	pub approved_at: BlockNumber,
+	/// Account that approved the transfer. `None` if approved by `ForceOrigin`.
+	pub approved_by: Option<AccountId>,
+}
+
+/// Asset representing shares of a fractionalized item.
+///
+/// Added by Liberland
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Fractionalization<AssetId, Balance> {
+	/// The asset.
+	pub asset: AssetId,
+	/// Number of shares minted when the item was fractionalized.
+	pub shares: Balance,
+}
+
+/// Rent due for a single lease period.
+///
+/// Added by Liberland
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum Rent<Balance, AssetId, AssetBalance> {
+	/// Paid in the native currency.
+	Native(Balance),
+	/// Paid in the given asset.
+	Asset(AssetId, AssetBalance),
+}
+
+/// Lease terms offered by an item's owner.
+///
+/// Added by Liberland
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LeaseOffer<AccountId, Rent, BlockNumber> {
+	/// Account that may accept the lease.
+	pub lessee: AccountId,
+	/// Rent due for every period, paid to the item's owner.
+	pub rent: Rent,
+	/// Length of a single lease period.
+	pub period: BlockNumber,
+	/// Number of periods the lease lasts.
+	pub periods: u32,
+}
+
+/// An accepted lease, giving the lessee the user role of an item.
+///
+/// Added by Liberland
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Lease<AccountId, Rent, BlockNumber> {
+	/// The user of the item.
+	pub lessee: AccountId,
+	/// Rent due for every period, paid to the item's owner.
+	pub rent: Rent,
+	/// Length of a single lease period.
+	pub period: BlockNumber,
+	/// Block until which the rent is paid. The user role lapses at this block if no further
+	/// rent is paid.
+	pub paid_until: BlockNumber,
+	/// Block at which the lease ends.
+	pub expires_at: BlockNumber,
+}
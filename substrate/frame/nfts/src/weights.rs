@@ -90,6 +90,14 @@ pub trait WeightInfo {
 	fn claim_swap() -> Weight;
 	fn mint_pre_signed(n: u32, ) -> Weight;
 	fn set_attributes_pre_signed(n: u32, ) -> Weight;
+	fn set_transfer_approval_required() -> Weight;
+	fn approve_pending_transfer() -> Weight;
+	fn cancel_pending_transfer() -> Weight;
+	fn offer_lease() -> Weight;
+	fn cancel_lease_offer() -> Weight;
+	fn accept_lease() -> Weight;
+	fn pay_rent() -> Weight;
+	fn end_lease() -> Weight;
 }
 
 /// Weights for pallet_nfts using the Substrate node and recommended hardware.
@@ -774,6 +782,100 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2921).saturating_mul(n.into()))
 	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts TransferApprovalRequired (r:0 w:1)
+	/// Proof: Nfts TransferApprovalRequired (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	fn set_transfer_approval_required() -> Weight {
+		// Estimated from `set_collection_max_supply`, not benchmarked yet.
+		Weight::from_parts(18_775_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingTransfers (r:1 w:1)
+	/// Proof: Nfts PendingTransfers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: Nfts TitleHistoryLength (r:1 w:1)
+	/// Proof: Nfts TitleHistoryLength (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Nfts TitleHistory (r:0 w:1)
+	/// Proof: Nfts TitleHistory (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	fn approve_pending_transfer() -> Weight {
+		// Estimated from `transfer` plus the storage above, not benchmarked yet.
+		Weight::from_parts(55_000_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: Nfts PendingTransfers (r:1 w:1)
+	/// Proof: Nfts PendingTransfers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn cancel_pending_transfer() -> Weight {
+		// Estimated from `cancel_swap`, not benchmarked yet.
+		Weight::from_parts(22_065_000, 3573)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts Leases (r:1 w:0)
+	/// Proof: Nfts Leases (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: Nfts LeaseOffers (r:0 w:1)
+	/// Proof: Nfts LeaseOffers (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	fn offer_lease() -> Weight {
+		// Estimated from `set_price`, not benchmarked yet.
+		Weight::from_parts(24_128_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts LeaseOffers (r:1 w:1)
+	/// Proof: Nfts LeaseOffers (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	fn cancel_lease_offer() -> Weight {
+		// Estimated from `cancel_swap`, not benchmarked yet.
+		Weight::from_parts(22_065_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts LeaseOffers (r:1 w:1)
+	/// Proof: Nfts LeaseOffers (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Nfts Leases (r:1 w:1)
+	/// Proof: Nfts Leases (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: Nfts CitizenshipRequired (r:1 w:0)
+	/// Proof: Nfts CitizenshipRequired (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn accept_lease() -> Weight {
+		// Estimated from `buy_item`, not benchmarked yet.
+		Weight::from_parts(54_614_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts Leases (r:1 w:1)
+	/// Proof: Nfts Leases (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: Nfts CitizenshipRequired (r:1 w:0)
+	/// Proof: Nfts CitizenshipRequired (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn pay_rent() -> Weight {
+		// Estimated from `buy_item`, not benchmarked yet.
+		Weight::from_parts(50_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Nfts Leases (r:1 w:1)
+	/// Proof: Nfts Leases (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	fn end_lease() -> Weight {
+		// Estimated from `cancel_swap`, not benchmarked yet.
+		Weight::from_parts(18_000_000, 3578)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -1457,4 +1559,98 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2921).saturating_mul(n.into()))
 	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts TransferApprovalRequired (r:0 w:1)
+	/// Proof: Nfts TransferApprovalRequired (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	fn set_transfer_approval_required() -> Weight {
+		// Estimated from `set_collection_max_supply`, not benchmarked yet.
+		Weight::from_parts(18_775_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingTransfers (r:1 w:1)
+	/// Proof: Nfts PendingTransfers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: Nfts TitleHistoryLength (r:1 w:1)
+	/// Proof: Nfts TitleHistoryLength (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Nfts TitleHistory (r:0 w:1)
+	/// Proof: Nfts TitleHistory (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	fn approve_pending_transfer() -> Weight {
+		// Estimated from `transfer` plus the storage above, not benchmarked yet.
+		Weight::from_parts(55_000_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: Nfts PendingTransfers (r:1 w:1)
+	/// Proof: Nfts PendingTransfers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn cancel_pending_transfer() -> Weight {
+		// Estimated from `cancel_swap`, not benchmarked yet.
+		Weight::from_parts(22_065_000, 3573)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts Leases (r:1 w:0)
+	/// Proof: Nfts Leases (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: Nfts LeaseOffers (r:0 w:1)
+	/// Proof: Nfts LeaseOffers (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	fn offer_lease() -> Weight {
+		// Estimated from `set_price`, not benchmarked yet.
+		Weight::from_parts(24_128_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts LeaseOffers (r:1 w:1)
+	/// Proof: Nfts LeaseOffers (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	fn cancel_lease_offer() -> Weight {
+		// Estimated from `cancel_swap`, not benchmarked yet.
+		Weight::from_parts(22_065_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts LeaseOffers (r:1 w:1)
+	/// Proof: Nfts LeaseOffers (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Nfts Leases (r:1 w:1)
+	/// Proof: Nfts Leases (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: Nfts CitizenshipRequired (r:1 w:0)
+	/// Proof: Nfts CitizenshipRequired (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn accept_lease() -> Weight {
+		// Estimated from `buy_item`, not benchmarked yet.
+		Weight::from_parts(54_614_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts Leases (r:1 w:1)
+	/// Proof: Nfts Leases (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: Nfts CitizenshipRequired (r:1 w:0)
+	/// Proof: Nfts CitizenshipRequired (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn pay_rent() -> Weight {
+		// Estimated from `buy_item`, not benchmarked yet.
+		Weight::from_parts(50_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Nfts Leases (r:1 w:1)
+	/// Proof: Nfts Leases (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	fn end_lease() -> Weight {
+		// Estimated from `cancel_swap`, not benchmarked yet.
+		Weight::from_parts(18_000_000, 3578)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
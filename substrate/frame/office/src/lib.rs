@@ -142,6 +142,12 @@ pub mod pallet {
 	pub(super) type Clerks<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, T::CallFilter, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn executing_clerk)]
+	/// Clerk whose call is currently being dispatched by `execute`. Only set during dispatch.
+	pub(super) type ExecutingClerk<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::AccountId, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
 		/// Initial admin
@@ -256,7 +262,11 @@ pub mod pallet {
 		) -> DispatchResult {
 			let clerk = ensure_signed(origin)?;
 			let call_filter = Self::clerks(&clerk).ok_or(Error::<T, I>::NoPermission)?;
-			Self::do_execute(*call, call_filter)
+			let previous_clerk = ExecutingClerk::<T, I>::get();
+			ExecutingClerk::<T, I>::put(clerk);
+			let res = Self::do_execute(*call, call_filter);
+			ExecutingClerk::<T, I>::set(previous_clerk);
+			res
 		}
 	}
 
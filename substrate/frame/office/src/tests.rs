@@ -164,6 +164,18 @@ fn execute_works() {
 	});
 }
 
+#[test]
+fn execute_clears_executing_clerk() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Office::set_clerk(RuntimeOrigin::signed(0), 1, OfficeCallFilter::Any));
+		assert_ok!(Office::execute(
+			RuntimeOrigin::signed(1),
+			Box::new(frame_system::Call::remark { remark: vec![] }.into())
+		));
+		assert_eq!(Office::executing_clerk(), None);
+	});
+}
+
 #[test]
 fn genesis_config_works() {
 	new_test_ext().execute_with(|| {
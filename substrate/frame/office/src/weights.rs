@@ -78,13 +78,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: IdentityOffice Clerks (r:1 w:0)
 	/// Proof: IdentityOffice Clerks (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: IdentityOffice ExecutingClerk (r:1 w:2)
+	/// Proof: IdentityOffice ExecutingClerk (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
 	fn execute() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `222`
 		//  Estimated: `3514`
 		// Minimum execution time: 22_543_000 picoseconds.
 		Weight::from_parts(22_843_000, 3514)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 }
 
@@ -129,12 +132,15 @@ impl WeightInfo for () {
 	}
 	/// Storage: IdentityOffice Clerks (r:1 w:0)
 	/// Proof: IdentityOffice Clerks (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: IdentityOffice ExecutingClerk (r:1 w:2)
+	/// Proof: IdentityOffice ExecutingClerk (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
 	fn execute() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `222`
 		//  Estimated: `3514`
 		// Minimum execution time: 22_543_000 picoseconds.
 		Weight::from_parts(22_843_000, 3514)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 }